@@ -91,6 +91,7 @@ fn collect_fees_for_factory(
                         &white_whale::vault_network::vault_factory::QueryMsg::Vaults {
                             start_after,
                             limit,
+                            filter: None,
                         },
                     )?,
                 }))?;
@@ -201,6 +202,7 @@ pub fn aggregate_fees(
                                 &white_whale::vault_network::vault_factory::QueryMsg::Vaults {
                                     start_after,
                                     limit,
+                                    filter: None,
                                 },
                             )?,
                         }))?;
@@ -227,8 +229,14 @@ pub fn aggregate_fees(
 
     let asset_infos: Vec<AssetInfo> = read_temporal_asset_infos(&mut deps)?;
 
+    // while forwarding fees, the ones in any of the assets to forward are kept as they are
+    let forward_fees_as = TMP_EPOCH
+        .may_load(deps.storage)?
+        .map(|(_, forward_fees_as)| forward_fees_as)
+        .unwrap_or_default();
+
     for offer_asset_info in asset_infos {
-        if offer_asset_info == ask_asset_info {
+        if offer_asset_info == ask_asset_info || forward_fees_as.contains(&offer_asset_info) {
             continue;
         }
 
@@ -334,7 +342,7 @@ pub fn forward_fees(
     info: MessageInfo,
     env: Env,
     epoch: Epoch,
-    forward_fees_as: AssetInfo,
+    forward_fees_as: Vec<AssetInfo>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -343,6 +351,12 @@ pub fn forward_fees(
         return Err(ContractError::Unauthorized {});
     }
 
+    // the fees in other assets are aggregated into the first one
+    let aggregate_fees_as = forward_fees_as
+        .first()
+        .ok_or(ContractError::NoForwardFeesAssets {})?
+        .clone();
+
     let mut messages = vec![];
 
     // trigger fee collection
@@ -391,7 +405,7 @@ pub fn forward_fees(
             contract_addr: env.contract.address.to_string(),
             funds: vec![],
             msg: to_binary(&ExecuteMsg::AggregateFees {
-                asset_info: forward_fees_as.clone(),
+                asset_info: aggregate_fees_as.clone(),
                 aggregate_fees_for: FeesFor::Factory {
                     factory_addr: config.vault_factory.to_string(),
                     factory_type: FactoryType::Vault {
@@ -411,7 +425,7 @@ pub fn forward_fees(
             contract_addr: env.contract.address.to_string(),
             funds: vec![],
             msg: to_binary(&ExecuteMsg::AggregateFees {
-                asset_info: forward_fees_as.clone(),
+                asset_info: aggregate_fees_as.clone(),
                 aggregate_fees_for: FeesFor::Factory {
                     factory_addr: config.pool_factory.to_string(),
                     factory_type: FactoryType::Pool {
@@ -430,7 +444,7 @@ pub fn forward_fees(
     messages.push(vaults_fee_aggregation_msg);
     messages.push(pools_fee_aggregation_msg);
 
-    // saving the epoch and the asset info to forward the fees as in temp storage
+    // saving the epoch and the asset infos to forward the fees as in temp storage
     TMP_EPOCH.save(deps.storage, &(epoch, forward_fees_as))?;
 
     Ok(Response::new()
@@ -1,8 +1,7 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, BalanceResponse, BankMsg, BankQuery, Binary, CosmosMsg, Deps, DepsMut, Env,
-    MessageInfo, QueryRequest, Reply, Response, StdResult, Uint128,
+    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
 };
 use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
@@ -10,7 +9,7 @@ use semver::Version;
 use white_whale::fee_collector::{
     Config, ExecuteMsg, ForwardFeesResponse, InstantiateMsg, MigrateMsg, QueryMsg,
 };
-use white_whale::pool_network::asset::{Asset, AssetInfo, ToCoins};
+use white_whale::pool_network::asset::Asset;
 
 use crate::error::ContractError;
 use crate::state::{CONFIG, TMP_EPOCH};
@@ -49,42 +48,35 @@ pub fn instantiate(
 #[entry_point]
 pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     if msg.id == FEES_AGGREGATION_REPLY_ID {
-        let (mut epoch, asset_info) = TMP_EPOCH
+        let (mut epoch, asset_infos) = TMP_EPOCH
             .may_load(deps.storage)?
             .ok_or(ContractError::CannotReadEpoch {})?;
 
-        let token_balance: Uint128 = match asset_info.clone() {
-            AssetInfo::Token { .. } => {
-                return Err(ContractError::InvalidContractsFeeAggregation {})
+        let mut fees = vec![];
+        for asset_info in asset_infos {
+            let token_balance =
+                asset_info.query_pool(&deps.querier, deps.api, env.contract.address.clone())?;
+
+            // if not zero, it means there were fees aggregated
+            if !token_balance.is_zero() {
+                fees.push(Asset {
+                    info: asset_info,
+                    amount: token_balance,
+                });
             }
-            AssetInfo::NativeToken { denom } => {
-                let balance_response: BalanceResponse =
-                    deps.querier.query(&QueryRequest::Bank(BankQuery::Balance {
-                        address: env.contract.address.to_string(),
-                        denom,
-                    }))?;
-                balance_response.amount.amount
-            }
-        };
+        }
 
         let mut messages = vec![];
 
-        // if not zero, it means there were fees aggregated
-        if !token_balance.is_zero() {
-            let fees = vec![Asset {
-                info: asset_info,
-                amount: token_balance,
-            }];
-
+        if !fees.is_empty() {
             epoch.total = fees.clone();
             epoch.available = fees.clone();
 
             // send tokens to fee distributor
             let config = CONFIG.load(deps.storage)?;
-            messages.push(CosmosMsg::Bank(BankMsg::Send {
-                to_address: config.fee_distributor.to_string(),
-                amount: fees.to_coins()?,
-            }));
+            for fee in fees {
+                messages.push(fee.into_msg(config.fee_distributor.clone())?);
+            }
         }
 
         TMP_EPOCH.remove(deps.storage);
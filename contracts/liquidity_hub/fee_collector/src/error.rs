@@ -23,6 +23,9 @@ pub enum ContractError {
         current_version: Version,
     },
 
+    #[error("There must be at least one asset to forward the fees as.")]
+    NoForwardFeesAssets {},
+
     #[error("Couldn't read data for new epoch.")]
     CannotReadEpoch {},
 
@@ -118,6 +118,7 @@ fn query_fees_for_factory(
                         &white_whale::vault_network::vault_factory::QueryMsg::Vaults {
                             start_after,
                             limit,
+                            filter: None,
                         },
                     )?,
                 }))?;
@@ -1,7 +1,17 @@
 use cosmwasm_std::testing::mock_info;
-use cosmwasm_std::{Addr, Coin, MessageInfo, Uint128};
+use cosmwasm_std::{coins, to_binary, Addr, Coin, Decimal, MessageInfo, Uint128, Uint64};
 use cw_multi_test::{App, AppBuilder, BankKeeper, ContractWrapper, Executor};
 
+use white_whale::fee::Fee;
+use white_whale::fee_collector::ExecuteMsg::UpdateConfig;
+use white_whale::fee_collector::InstantiateMsg;
+use white_whale::fee_distributor::EpochConfig;
+use white_whale::pool_network::asset::{Asset, AssetInfo, PairType};
+use white_whale::pool_network::factory::ExecuteMsg::{AddNativeTokenDecimals, CreatePair};
+use white_whale::pool_network::pair::PoolFee;
+use white_whale::whale_lair::BondingAsset;
+use white_whale::{pool_network, vault_network};
+
 use crate::contract::{execute, instantiate, migrate, query, reply};
 
 use super::dummy_contract::create_dummy_flash_loan_contract;
@@ -173,3 +183,304 @@ pub fn increase_allowance(app: &mut App, sender: Addr, contract_addr: Addr, spen
     )
     .unwrap();
 }
+
+/// The contracts instantiated by [setup_fee_distribution].
+pub struct FeeDistributionSetup {
+    pub fee_collector: Addr,
+    pub fee_distributor: Addr,
+}
+
+/// Instantiates the fee collector, the fee distributor distributing the given assets, the whale
+/// lair and the pool and vault factories, and creates a uwhale pool for each of the given assets.
+/// Liquidity is provided to the pools and swaps are performed both ways, so that they have fees to
+/// collect. The creator must hold enough uwhale and of each of the assets.
+pub fn setup_fee_distribution(
+    app: &mut App,
+    creator: &MessageInfo,
+    pool_assets: Vec<AssetInfo>,
+    distribution_assets: Vec<AssetInfo>,
+) -> FeeDistributionSetup {
+    let fee_collector_id = store_fee_collector_code(app);
+    let fee_distributor_id = store_fee_distributor_code(app);
+    let whale_lair_id = store_whale_lair_code(app);
+    let pool_factory_id = store_pool_factory_code(app);
+    let pool_router_id = store_pool_router_code(app);
+    let pair_id = store_pair_code(app);
+    let token_id = store_token_code(app);
+    let vault_factory_id = store_vault_factory_code(app);
+    let vault_id = store_vault_code(app);
+
+    let fee_collector = app
+        .instantiate_contract(
+            fee_collector_id,
+            creator.sender.clone(),
+            &InstantiateMsg {},
+            &[],
+            "fee_collector",
+            None,
+        )
+        .unwrap();
+
+    let pool_factory = app
+        .instantiate_contract(
+            pool_factory_id,
+            creator.sender.clone(),
+            &pool_network::factory::InstantiateMsg {
+                pair_code_id: pair_id,
+                token_code_id: token_id,
+                fee_collector_addr: fee_collector.to_string(),
+            },
+            &[],
+            "pool_factory",
+            None,
+        )
+        .unwrap();
+
+    let pool_router = app
+        .instantiate_contract(
+            pool_router_id,
+            creator.sender.clone(),
+            &pool_network::router::InstantiateMsg {
+                terraswap_factory: pool_factory.to_string(),
+            },
+            &[],
+            "pool_router",
+            None,
+        )
+        .unwrap();
+
+    let vault_factory = app
+        .instantiate_contract(
+            vault_factory_id,
+            creator.sender.clone(),
+            &vault_network::vault_factory::InstantiateMsg {
+                owner: creator.sender.to_string(),
+                vault_id,
+                token_id,
+                fee_collector_addr: fee_collector.to_string(),
+            },
+            &[],
+            "vault_factory",
+            None,
+        )
+        .unwrap();
+
+    let whale_lair = app
+        .instantiate_contract(
+            whale_lair_id,
+            creator.sender.clone(),
+            &white_whale::whale_lair::InstantiateMsg {
+                unbonding_period: Uint64::new(1_000_000_000_000u64),
+                growth_rate: Decimal::one(),
+                bonding_assets: vec![
+                    BondingAsset {
+                        info: AssetInfo::NativeToken {
+                            denom: "ampWHALE".to_string(),
+                        },
+                        weight_factor: Decimal::one(),
+                        exchange_rate: Decimal::one(),
+                        exchange_rate_oracle: None,
+                    },
+                    BondingAsset {
+                        info: AssetInfo::NativeToken {
+                            denom: "bWHALE".to_string(),
+                        },
+                        weight_factor: Decimal::one(),
+                        exchange_rate: Decimal::one(),
+                        exchange_rate_oracle: None,
+                    },
+                ],
+                instant_unbond_penalty: Decimal::percent(10),
+                penalty_recipient: white_whale::whale_lair::PenaltyRecipient::Burn,
+            },
+            &[],
+            "whale_lair",
+            None,
+        )
+        .unwrap();
+
+    let fee_distributor = app
+        .instantiate_contract(
+            fee_distributor_id,
+            creator.sender.clone(),
+            &white_whale::fee_distributor::InstantiateMsg {
+                bonding_contract_addr: whale_lair.to_string(),
+                fee_collector_addr: fee_collector.to_string(),
+                grace_period: Uint64::new(2),
+                epoch_config: EpochConfig {
+                    duration: Uint64::new(86_400_000_000_000u64), // a day
+                    genesis_epoch: Uint64::new(1_678_802_400_000_000_000u64), // March 14, 2023 2:00:00 PM
+                },
+                distribution_assets,
+                funding_whitelist: vec![],
+            },
+            &[],
+            "fee_distributor",
+            None,
+        )
+        .unwrap();
+
+    // add the other contracts to the fee collector to be able to collect and aggregate fees
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_collector.clone(),
+        &UpdateConfig {
+            owner: None,
+            pool_router: Some(pool_router.to_string()),
+            fee_distributor: Some(fee_distributor.to_string()),
+            pool_factory: Some(pool_factory.to_string()),
+            vault_factory: Some(vault_factory.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // add native tokens to the factory
+    let uwhale = AssetInfo::NativeToken {
+        denom: "uwhale".to_string(),
+    };
+    for asset_info in std::iter::once(&uwhale).chain(pool_assets.iter()) {
+        if let AssetInfo::NativeToken { denom } = asset_info {
+            app.execute_contract(
+                creator.sender.clone(),
+                pool_factory.clone(),
+                &AddNativeTokenDecimals {
+                    denom: denom.clone(),
+                    decimals: 6,
+                },
+                &coins(1u128, denom),
+            )
+            .unwrap();
+        }
+    }
+
+    for asset_info in pool_assets {
+        let res = app
+            .execute_contract(
+                creator.sender.clone(),
+                pool_factory.clone(),
+                &CreatePair {
+                    asset_infos: [uwhale.clone(), asset_info.clone()],
+                    pool_fees: Some(PoolFee {
+                        protocol_fee: Fee {
+                            share: Decimal::percent(5u64),
+                        },
+                        swap_fee: Fee {
+                            share: Decimal::percent(7u64),
+                        },
+                        burn_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                    }),
+                    pair_type: PairType::ConstantProduct,
+                    token_factory_lp: false,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let pair = Addr::unchecked(
+            res.events
+                .last()
+                .unwrap()
+                .attributes
+                .get(1)
+                .unwrap()
+                .value
+                .clone(),
+        );
+
+        // provide liquidity into the pool
+        let mut funds = coins(500_000u128, "uwhale");
+        match &asset_info {
+            AssetInfo::NativeToken { denom } => funds.push(Coin {
+                denom: denom.clone(),
+                amount: Uint128::new(500_000u128),
+            }),
+            AssetInfo::Token { contract_addr } => increase_allowance(
+                app,
+                creator.sender.clone(),
+                Addr::unchecked(contract_addr),
+                pair.clone(),
+            ),
+        }
+        funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        app.execute_contract(
+            creator.sender.clone(),
+            pair.clone(),
+            &pool_network::pair::ExecuteMsg::ProvideLiquidity {
+                assets: [
+                    Asset {
+                        info: uwhale.clone(),
+                        amount: Uint128::new(500_000u128),
+                    },
+                    Asset {
+                        info: asset_info.clone(),
+                        amount: Uint128::new(500_000u128),
+                    },
+                ],
+                slippage_tolerance: None,
+                receiver: None,
+            },
+            &funds,
+        )
+        .unwrap();
+
+        // uwhale -> asset
+        app.execute_contract(
+            creator.sender.clone(),
+            pair.clone(),
+            &pool_network::pair::ExecuteMsg::Swap {
+                offer_asset: Asset {
+                    info: uwhale.clone(),
+                    amount: Uint128::new(200_000_000u128),
+                },
+                belief_price: None,
+                max_spread: None,
+                to: None,
+            },
+            &coins(200_000_000u128, "uwhale"),
+        )
+        .unwrap();
+
+        // asset -> uwhale
+        match &asset_info {
+            AssetInfo::NativeToken { denom } => app.execute_contract(
+                creator.sender.clone(),
+                pair.clone(),
+                &pool_network::pair::ExecuteMsg::Swap {
+                    offer_asset: Asset {
+                        info: asset_info.clone(),
+                        amount: Uint128::new(200_000_000u128),
+                    },
+                    belief_price: None,
+                    max_spread: None,
+                    to: None,
+                },
+                &coins(200_000_000u128, denom),
+            ),
+            AssetInfo::Token { contract_addr } => app.execute_contract(
+                creator.sender.clone(),
+                Addr::unchecked(contract_addr),
+                &cw20::Cw20ExecuteMsg::Send {
+                    contract: pair.to_string(),
+                    amount: Uint128::new(200_000_000u128),
+                    msg: to_binary(&pool_network::pair::Cw20HookMsg::Swap {
+                        belief_price: None,
+                        max_spread: None,
+                        to: None,
+                    })
+                    .unwrap(),
+                },
+                &[],
+            ),
+        }
+        .unwrap();
+    }
+
+    FeeDistributionSetup {
+        fee_collector,
+        fee_distributor,
+    }
+}
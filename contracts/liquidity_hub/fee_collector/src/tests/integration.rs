@@ -22,13 +22,14 @@ use white_whale::pool_network::factory::PairsResponse;
 use white_whale::pool_network::pair::{PoolFee, PoolResponse, ProtocolFeesResponse};
 use white_whale::pool_network::router::{SwapOperation, SwapRoute};
 use white_whale::vault_network::vault_factory::ExecuteMsg;
+use white_whale::whale_lair::BondingAsset;
 use white_whale::{pool_network, vault_network};
 
 use crate::tests::common_integration::{
-    increase_allowance, mock_app, mock_app_with_balance, mock_creator,
+    increase_allowance, mock_app, mock_app_with_balance, mock_creator, setup_fee_distribution,
     store_dummy_flash_loan_contract, store_fee_collector_code, store_fee_distributor_code,
     store_pair_code, store_pool_factory_code, store_pool_router_code, store_token_code,
-    store_vault_code, store_vault_factory_code, store_whale_lair_code,
+    store_vault_code, store_vault_factory_code, store_whale_lair_code, FeeDistributionSetup,
 };
 use crate::ContractError;
 
@@ -145,7 +146,7 @@ fn collect_all_factories_cw20_fees_successfully() {
                             contract_addr: cw20_tokens[i as usize + 1].to_string(),
                         },
                     ],
-                    pool_fees: PoolFee {
+                    pool_fees: Some(PoolFee {
                         protocol_fee: Fee {
                             share: Decimal::percent(5u64),
                         },
@@ -155,7 +156,7 @@ fn collect_all_factories_cw20_fees_successfully() {
                         burn_fee: Fee {
                             share: Decimal::zero(),
                         },
-                    },
+                    }),
                     pair_type: PairType::ConstantProduct,
                     token_factory_lp: false,
                 },
@@ -581,7 +582,7 @@ fn collect_cw20_fees_for_specific_contracts_successfully() {
                             contract_addr: cw20_tokens[i + 1].to_string(),
                         },
                     ],
-                    pool_fees: PoolFee {
+                    pool_fees: Some(PoolFee {
                         protocol_fee: Fee {
                             share: Decimal::percent(5u64),
                         },
@@ -591,7 +592,7 @@ fn collect_cw20_fees_for_specific_contracts_successfully() {
                         burn_fee: Fee {
                             share: Decimal::zero(),
                         },
-                    },
+                    }),
                     pair_type: PairType::ConstantProduct,
                     token_factory_lp: false,
                 },
@@ -1054,7 +1055,7 @@ fn collect_pools_native_fees_successfully() {
                             contract_addr: cw20_token.to_string(),
                         },
                     ],
-                    pool_fees: PoolFee {
+                    pool_fees: Some(PoolFee {
                         protocol_fee: Fee {
                             share: Decimal::percent(5u64),
                         },
@@ -1064,7 +1065,7 @@ fn collect_pools_native_fees_successfully() {
                         burn_fee: Fee {
                             share: Decimal::zero(),
                         },
-                    },
+                    }),
                     pair_type: PairType::ConstantProduct,
                     token_factory_lp: false,
                 },
@@ -1581,7 +1582,7 @@ fn collect_fees_with_pagination_successfully() {
                             contract_addr: cw20_token.to_string(),
                         },
                     ],
-                    pool_fees: PoolFee {
+                    pool_fees: Some(PoolFee {
                         protocol_fee: Fee {
                             share: Decimal::percent(5u64),
                         },
@@ -1591,7 +1592,7 @@ fn collect_fees_with_pagination_successfully() {
                         burn_fee: Fee {
                             share: Decimal::zero(),
                         },
-                    },
+                    }),
                     pair_type: PairType::ConstantProduct,
                     token_factory_lp: false,
                 },
@@ -1909,7 +1910,7 @@ fn collect_fees_for_vault() {
                     asset_info: AssetInfo::NativeToken {
                         denom: coin.clone().denom.to_string(),
                     },
-                    fees: VaultFee {
+                    fees: Some(VaultFee {
                         flash_loan_fee: Fee {
                             share: Decimal::from_ratio(100u128, 3000u128),
                         },
@@ -1917,7 +1918,8 @@ fn collect_fees_for_vault() {
                         burn_fee: Fee {
                             share: Decimal::zero(),
                         },
-                    },
+                    }),
+                    metadata: None,
                 },
                 &[],
             )
@@ -1940,6 +1942,8 @@ fn collect_fees_for_vault() {
             vaults[i].clone(),
             &white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(400_000_000u128),
+                receiver: None,
+                min_lp_out: None,
             },
             &[Coin {
                 denom: coin.clone().denom,
@@ -2273,7 +2277,7 @@ fn aggregate_fees_for_vault() {
                     asset_info: AssetInfo::NativeToken {
                         denom: coin.clone().denom.to_string(),
                     },
-                    fees: VaultFee {
+                    fees: Some(VaultFee {
                         flash_loan_fee: Fee {
                             share: Decimal::from_ratio(100u128, 3000u128),
                         },
@@ -2281,7 +2285,8 @@ fn aggregate_fees_for_vault() {
                         burn_fee: Fee {
                             share: Decimal::zero(),
                         },
-                    },
+                    }),
+                    metadata: None,
                 },
                 &[],
             )
@@ -2304,6 +2309,8 @@ fn aggregate_fees_for_vault() {
             vaults[i].clone(),
             &white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(400_000_000u128),
+                receiver: None,
+                min_lp_out: None,
             },
             &[Coin {
                 denom: coin.clone().denom,
@@ -2541,7 +2548,7 @@ fn aggregate_fees_for_vault() {
                         denom: "ujuno".to_string(),
                     },
                 ],
-                pool_fees: PoolFee {
+                pool_fees: Some(PoolFee {
                     protocol_fee: Fee {
                         share: Decimal::percent(5u64),
                     },
@@ -2551,7 +2558,7 @@ fn aggregate_fees_for_vault() {
                     burn_fee: Fee {
                         share: Decimal::zero(),
                     },
-                },
+                }),
                 pair_type: PairType::ConstantProduct,
                 token_factory_lp: false,
             },
@@ -2811,13 +2818,25 @@ fn collect_and_distribute_fees_successfully() {
                 unbonding_period: Uint64::new(1_000_000_000_000u64),
                 growth_rate: Decimal::one(),
                 bonding_assets: vec![
-                    AssetInfo::NativeToken {
-                        denom: "ampWHALE".to_string(),
+                    BondingAsset {
+                        info: AssetInfo::NativeToken {
+                            denom: "ampWHALE".to_string(),
+                        },
+                        weight_factor: Decimal::one(),
+                        exchange_rate: Decimal::one(),
+                        exchange_rate_oracle: None,
                     },
-                    AssetInfo::NativeToken {
-                        denom: "bWHALE".to_string(),
+                    BondingAsset {
+                        info: AssetInfo::NativeToken {
+                            denom: "bWHALE".to_string(),
+                        },
+                        weight_factor: Decimal::one(),
+                        exchange_rate: Decimal::one(),
+                        exchange_rate_oracle: None,
                     },
                 ],
+                instant_unbond_penalty: Decimal::percent(10),
+                penalty_recipient: white_whale::whale_lair::PenaltyRecipient::Burn,
             },
             &[],
             "whale_lair",
@@ -2837,9 +2856,10 @@ fn collect_and_distribute_fees_successfully() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
+                distribution_assets: vec![AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
-                },
+                }],
+                funding_whitelist: vec![],
             },
             &[],
             "fee_distributor",
@@ -2936,7 +2956,7 @@ fn collect_and_distribute_fees_successfully() {
                             denom: native_token.clone().to_string(),
                         },
                     ],
-                    pool_fees: PoolFee {
+                    pool_fees: Some(PoolFee {
                         protocol_fee: Fee {
                             share: Decimal::percent(5u64),
                         },
@@ -2946,7 +2966,7 @@ fn collect_and_distribute_fees_successfully() {
                         burn_fee: Fee {
                             share: Decimal::zero(),
                         },
-                    },
+                    }),
                     pair_type: PairType::ConstantProduct,
                     token_factory_lp: false,
                 },
@@ -3084,6 +3104,126 @@ fn collect_and_distribute_fees_successfully() {
     assert!(!fee_distributor_current_epoch_query.epoch.total.is_empty());
 }
 
+#[test]
+fn collect_and_distribute_fees_in_several_assets_successfully() {
+    let creator = mock_creator();
+    let balances = vec![(
+        creator.clone().sender,
+        vec![
+            coin(1_000_000_000, "usdc"),
+            coin(1_000_000_000, "uwhale"),
+            coin(1_000_000_000, "ampWHALE"),
+            coin(1_000_000_000, "bWHALE"),
+        ],
+    )];
+
+    let mut app = mock_app_with_balance(balances);
+
+    // a cw20 token distributed as it is, along with uwhale and usdc
+    let token_id = store_token_code(&mut app);
+    let cw20_token = app
+        .instantiate_contract(
+            token_id,
+            creator.clone().sender,
+            &pool_network::token::InstantiateMsg {
+                name: "token".to_string(),
+                symbol: "tokena".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: creator.clone().sender.to_string(),
+                    amount: Uint128::new(1_000_000_000_000u128),
+                }],
+                mint: None,
+            },
+            &[],
+            "cw20 token",
+            None,
+        )
+        .unwrap();
+
+    let uwhale = AssetInfo::NativeToken {
+        denom: "uwhale".to_string(),
+    };
+    let usdc = AssetInfo::NativeToken {
+        denom: "usdc".to_string(),
+    };
+    let cw20 = AssetInfo::Token {
+        contract_addr: cw20_token.to_string(),
+    };
+
+    let FeeDistributionSetup {
+        fee_collector,
+        fee_distributor,
+    } = setup_fee_distribution(
+        &mut app,
+        &creator,
+        vec![
+            usdc.clone(),
+            AssetInfo::NativeToken {
+                denom: "ampWHALE".to_string(),
+            },
+            AssetInfo::NativeToken {
+                denom: "bWHALE".to_string(),
+            },
+            cw20.clone(),
+        ],
+        vec![uwhale.clone(), usdc.clone(), cw20.clone()],
+    );
+
+    // Create new epoch, which triggers fee collection, aggregation and distribution
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_distributor.clone(),
+        &NewEpoch {},
+        &[],
+    )
+    .unwrap();
+
+    let fee_distributor_current_epoch_query: EpochResponse = app
+        .wrap()
+        .query_wasm_smart(
+            fee_distributor.clone(),
+            &white_whale::fee_distributor::QueryMsg::CurrentEpoch {},
+        )
+        .unwrap();
+    assert_eq!(fee_distributor_current_epoch_query.epoch.id, Uint64::one());
+
+    // the usdc and cw20 fees are distributed as they are, the rest are swapped to uwhale
+    let epoch_total = fee_distributor_current_epoch_query.epoch.total;
+    assert_eq!(
+        epoch_total
+            .iter()
+            .map(|fee| fee.info.clone())
+            .collect::<Vec<_>>(),
+        vec![uwhale, usdc, cw20]
+    );
+
+    for fee in epoch_total {
+        assert!(fee.amount > Uint128::zero());
+
+        let balance = |address: &Addr| match &fee.info {
+            AssetInfo::NativeToken { denom } => {
+                app.wrap().query_balance(address, denom).unwrap().amount
+            }
+            AssetInfo::Token { contract_addr } => {
+                let balance_response: BalanceResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        contract_addr,
+                        &cw20::Cw20QueryMsg::Balance {
+                            address: address.to_string(),
+                        },
+                    )
+                    .unwrap();
+                balance_response.balance
+            }
+        };
+
+        assert_eq!(balance(&fee_distributor), fee.amount);
+        assert_eq!(balance(&fee_collector), Uint128::zero());
+    }
+}
+
 #[test]
 fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
     let creator = mock_creator();
@@ -3183,13 +3323,25 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
                 unbonding_period: Uint64::new(1_000_000_000_000u64),
                 growth_rate: Decimal::one(),
                 bonding_assets: vec![
-                    AssetInfo::NativeToken {
-                        denom: "ampWHALE".to_string(),
+                    BondingAsset {
+                        info: AssetInfo::NativeToken {
+                            denom: "ampWHALE".to_string(),
+                        },
+                        weight_factor: Decimal::one(),
+                        exchange_rate: Decimal::one(),
+                        exchange_rate_oracle: None,
                     },
-                    AssetInfo::NativeToken {
-                        denom: "bWHALE".to_string(),
+                    BondingAsset {
+                        info: AssetInfo::NativeToken {
+                            denom: "bWHALE".to_string(),
+                        },
+                        weight_factor: Decimal::one(),
+                        exchange_rate: Decimal::one(),
+                        exchange_rate_oracle: None,
                     },
                 ],
+                instant_unbond_penalty: Decimal::percent(10),
+                penalty_recipient: white_whale::whale_lair::PenaltyRecipient::Burn,
             },
             &[],
             "whale_lair",
@@ -3209,9 +3361,10 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
+                distribution_assets: vec![AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
-                },
+                }],
+                funding_whitelist: vec![],
             },
             &[],
             "fee_distributor",
@@ -3308,7 +3461,7 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
                             denom: native_token.clone().to_string(),
                         },
                     ],
-                    pool_fees: PoolFee {
+                    pool_fees: Some(PoolFee {
                         protocol_fee: Fee {
                             share: Decimal::percent(5u64),
                         },
@@ -3318,7 +3471,7 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
                         burn_fee: Fee {
                             share: Decimal::zero(),
                         },
-                    },
+                    }),
                     pair_type: PairType::ConstantProduct,
                     token_factory_lp: false,
                 },
@@ -3550,7 +3703,7 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
     app.execute_contract(
         creator.sender.clone(),
         fee_distributor_address.clone(),
-        &white_whale::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale::fee_distributor::ExecuteMsg::Claim { delegator: None },
         &[],
     )
     .unwrap();
@@ -3568,7 +3721,7 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
         .execute_contract(
             creator.sender.clone(),
             fee_distributor_address.clone(),
-            &white_whale::fee_distributor::ExecuteMsg::Claim {},
+            &white_whale::fee_distributor::ExecuteMsg::Claim { delegator: None },
             &[],
         )
         .unwrap_err();
@@ -3599,7 +3752,7 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
     app.execute_contract(
         Addr::unchecked("other"),
         fee_distributor_address.clone(),
-        &white_whale::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale::fee_distributor::ExecuteMsg::Claim { delegator: None },
         &[],
     )
     .unwrap();
@@ -3710,13 +3863,25 @@ fn create_epoch_unsuccessfully() {
                 unbonding_period: Uint64::new(1_000_000_000_000u64),
                 growth_rate: Decimal::one(),
                 bonding_assets: vec![
-                    AssetInfo::NativeToken {
-                        denom: "ampWHALE".to_string(),
+                    BondingAsset {
+                        info: AssetInfo::NativeToken {
+                            denom: "ampWHALE".to_string(),
+                        },
+                        weight_factor: Decimal::one(),
+                        exchange_rate: Decimal::one(),
+                        exchange_rate_oracle: None,
                     },
-                    AssetInfo::NativeToken {
-                        denom: "bWHALE".to_string(),
+                    BondingAsset {
+                        info: AssetInfo::NativeToken {
+                            denom: "bWHALE".to_string(),
+                        },
+                        weight_factor: Decimal::one(),
+                        exchange_rate: Decimal::one(),
+                        exchange_rate_oracle: None,
                     },
                 ],
+                instant_unbond_penalty: Decimal::percent(10),
+                penalty_recipient: white_whale::whale_lair::PenaltyRecipient::Burn,
             },
             &[],
             "whale_lair",
@@ -3736,9 +3901,10 @@ fn create_epoch_unsuccessfully() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
+                distribution_assets: vec![AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
-                },
+                }],
+                funding_whitelist: vec![],
             },
             &[],
             "fee_distributor",
@@ -3835,7 +4001,7 @@ fn create_epoch_unsuccessfully() {
                             denom: native_token.clone().to_string(),
                         },
                     ],
-                    pool_fees: PoolFee {
+                    pool_fees: Some(PoolFee {
                         protocol_fee: Fee {
                             share: Decimal::percent(5u64),
                         },
@@ -3845,7 +4011,7 @@ fn create_epoch_unsuccessfully() {
                         burn_fee: Fee {
                             share: Decimal::zero(),
                         },
-                    },
+                    }),
                     pair_type: PairType::ConstantProduct,
                     token_factory_lp: false,
                 },
@@ -4052,9 +4218,9 @@ fn forward_fees_unsuccessfully() {
             fee_collector_address.clone(),
             &ForwardFees {
                 epoch: Default::default(),
-                forward_fees_as: AssetInfo::NativeToken {
+                forward_fees_as: vec![AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
-                },
+                }],
             },
             &[],
         )
@@ -4154,13 +4320,25 @@ fn decrease_grace_period_fee_distributor() {
                 unbonding_period: Uint64::new(1_000_000_000_000u64),
                 growth_rate: Decimal::one(),
                 bonding_assets: vec![
-                    AssetInfo::NativeToken {
-                        denom: "ampWHALE".to_string(),
+                    BondingAsset {
+                        info: AssetInfo::NativeToken {
+                            denom: "ampWHALE".to_string(),
+                        },
+                        weight_factor: Decimal::one(),
+                        exchange_rate: Decimal::one(),
+                        exchange_rate_oracle: None,
                     },
-                    AssetInfo::NativeToken {
-                        denom: "bWHALE".to_string(),
+                    BondingAsset {
+                        info: AssetInfo::NativeToken {
+                            denom: "bWHALE".to_string(),
+                        },
+                        weight_factor: Decimal::one(),
+                        exchange_rate: Decimal::one(),
+                        exchange_rate_oracle: None,
                     },
                 ],
+                instant_unbond_penalty: Decimal::percent(10),
+                penalty_recipient: white_whale::whale_lair::PenaltyRecipient::Burn,
             },
             &[],
             "whale_lair",
@@ -4180,9 +4358,10 @@ fn decrease_grace_period_fee_distributor() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
+                distribution_assets: vec![AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
-                },
+                }],
+                funding_whitelist: vec![],
             },
             &[],
             "fee_distributor",
@@ -4279,7 +4458,7 @@ fn decrease_grace_period_fee_distributor() {
                             denom: native_token.clone().to_string(),
                         },
                     ],
-                    pool_fees: PoolFee {
+                    pool_fees: Some(PoolFee {
                         protocol_fee: Fee {
                             share: Decimal::percent(5u64),
                         },
@@ -4289,7 +4468,7 @@ fn decrease_grace_period_fee_distributor() {
                         burn_fee: Fee {
                             share: Decimal::zero(),
                         },
-                    },
+                    }),
                     pair_type: PairType::ConstantProduct,
                     token_factory_lp: false,
                 },
@@ -4429,7 +4608,8 @@ fn decrease_grace_period_fee_distributor() {
                 bonding_contract_addr: None,
                 fee_collector_addr: None,
                 grace_period: Some(Uint64::one()),
-                distribution_asset: None,
+                distribution_assets: None,
+                funding_whitelist: None,
             },
             &[],
         )
@@ -1,16 +1,24 @@
 use cosmwasm_std::{
-    to_binary, CosmosMsg, DepsMut, Env, MessageInfo, QueryRequest, ReplyOn, Response, StdError,
-    SubMsg, Timestamp, Uint64, WasmMsg, WasmQuery,
+    from_binary, to_binary, Addr, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    QueryRequest, ReplyOn, Response, StdError, StdResult, SubMsg, Timestamp, Uint64, WasmMsg,
+    WasmQuery,
 };
+use cw20::Cw20ReceiveMsg;
 
-use white_whale::fee_distributor::Epoch;
+use white_whale::fee_distributor::{Cw20HookMsg, Epoch};
 use white_whale::pool_network::asset;
 use white_whale::pool_network::asset::{Asset, AssetInfo};
-use white_whale::whale_lair::{BondingWeightResponse, QueryMsg};
+use white_whale::whale_lair::{
+    BondingWeightResponse, Delegation, DelegationResponse, DelegationType, QueryMsg,
+    TotalWeightResponse,
+};
 
 use crate::contract::EPOCH_CREATION_REPLY_ID;
-use crate::helpers::validate_grace_period;
-use crate::state::{get_current_epoch, query_claimable, CONFIG, EPOCHS, LAST_CLAIMED_EPOCH};
+use crate::helpers::{validate_distribution_assets, validate_grace_period};
+use crate::state::{
+    get_current_epoch, query_claimable, EpochWeight, CLAIMED_EPOCHS, CONFIG, EPOCHS,
+    EPOCH_SPONSORED_FUNDS, EPOCH_WEIGHTS, LAST_CLAIMED_EPOCH, MAX_EPOCHS_AHEAD_TO_FUND,
+};
 use crate::ContractError;
 
 /// Creates a new epoch, forwarding available tokens from epochs that are past the grace period.
@@ -46,6 +54,23 @@ pub fn create_new_epoch(deps: DepsMut, env: Env) -> Result<Response, ContractErr
         claimed: vec![],
     };
 
+    // record the global weight the fees of the new epoch are shared by
+    let total_weight_response: TotalWeightResponse =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: config.bonding_contract_addr.to_string(),
+            msg: to_binary(&QueryMsg::TotalWeightAt {
+                timestamp: env.block.time,
+            })?,
+        }))?;
+    EPOCH_WEIGHTS.save(
+        deps.storage,
+        &new_epoch.id.to_be_bytes(),
+        &EpochWeight {
+            timestamp: env.block.time,
+            global_weight: total_weight_response.weight,
+        },
+    )?;
+
     Ok(Response::new()
         .add_submessage(SubMsg {
             id: EPOCH_CREATION_REPLY_ID,
@@ -53,7 +78,7 @@ pub fn create_new_epoch(deps: DepsMut, env: Env) -> Result<Response, ContractErr
                 contract_addr: config.fee_collector_addr.to_string(),
                 msg: to_binary(&white_whale::fee_collector::ExecuteMsg::ForwardFees {
                     epoch: new_epoch.clone(),
-                    forward_fees_as: config.distribution_asset,
+                    forward_fees_as: config.distribution_assets,
                 })?,
                 funds: vec![],
             }),
@@ -66,26 +91,77 @@ pub fn create_new_epoch(deps: DepsMut, env: Env) -> Result<Response, ContractErr
         ]))
 }
 
-pub fn claim(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
-    // Query the fee share of the sender based on the ratio of his weight and the global weight at the current moment
+pub fn claim(
+    deps: DepsMut,
+    info: MessageInfo,
+    delegator: Option<String>,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let bonding_weight_response: BondingWeightResponse =
+
+    // the rewards are claimed for the sender, or for the delegator the sender claims on behalf of
+    let address = match delegator {
+        Some(delegator) => deps.api.addr_validate(&delegator)?,
+        None => info.sender.clone(),
+    };
+
+    // the rewards are paid to the delegate if the address delegated its claim rights
+    let delegation_response: DelegationResponse =
         deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
             contract_addr: config.bonding_contract_addr.to_string(),
-            msg: to_binary(&QueryMsg::Weight {
-                address: info.sender.to_string(),
+            msg: to_binary(&QueryMsg::Delegation {
+                delegator: address.to_string(),
             })?,
         }))?;
 
-    let fee_share = bonding_weight_response.share;
+    // the weight delegated to an address is already part of its own weight, so its delegators
+    // don't have anything to claim for the time they delegated it
+    let recipient = match delegation_response.delegation {
+        // the bonding contract claims the rewards of the bonders who opted in to auto-compounding
+        _ if info.sender == config.bonding_contract_addr => info.sender.clone(),
+        Some(Delegation {
+            delegate,
+            delegation_type: DelegationType::Claim,
+            ..
+        }) => delegate,
+        _ => address.clone(),
+    };
+
+    if info.sender != address && info.sender != recipient {
+        return Err(ContractError::Unauthorized {});
+    }
 
-    let claimable_epochs = query_claimable(deps.as_ref(), &info.sender)?.epochs;
+    let claimable_epochs = query_claimable(deps.as_ref(), &address)?.epochs;
     if claimable_epochs.is_empty() {
         return Err(ContractError::NothingToClaim {});
     }
 
     let mut claimable_fees = vec![];
+    let mut current_fee_share = None;
     for mut epoch in claimable_epochs.clone() {
+        CLAIMED_EPOCHS.save(deps.storage, &epoch.id.to_be_bytes(), &true)?;
+
+        let fee_share = match EPOCH_WEIGHTS.may_load(deps.storage, &epoch.id.to_be_bytes())? {
+            Some(epoch_weight) => get_epoch_fee_share(
+                deps.as_ref(),
+                &config.bonding_contract_addr,
+                &address,
+                epoch_weight,
+            )?,
+            // the epochs created before the weights were recorded are shared by the current weight
+            None => match current_fee_share {
+                Some(fee_share) => fee_share,
+                None => {
+                    let fee_share = get_current_fee_share(
+                        deps.as_ref(),
+                        &config.bonding_contract_addr,
+                        &address,
+                    )?;
+                    current_fee_share = Some(fee_share);
+                    fee_share
+                }
+            },
+        };
+
         for fee in epoch.total.iter() {
             let reward = fee.amount * fee_share;
 
@@ -118,37 +194,199 @@ pub fn claim(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError
                 }
             }
 
-            if epoch.claimed.is_empty() {
-                epoch.claimed = vec![Asset {
+            epoch.claimed = asset::aggregate_assets(
+                epoch.claimed,
+                vec![Asset {
                     info: fee.info.clone(),
                     amount: reward,
-                }];
-            } else {
-                for claimed_fee in epoch.claimed.iter_mut() {
-                    if claimed_fee.info == fee.info {
-                        claimed_fee.amount = claimed_fee.amount.checked_add(reward)?;
-                    }
-                }
-            }
+                }],
+            )?;
 
             EPOCHS.save(deps.storage, &epoch.id.to_be_bytes(), &epoch)?;
         }
     }
 
     // update the last claimed epoch for the user
-    LAST_CLAIMED_EPOCH.save(deps.storage, &info.sender, &claimable_epochs[0].id)?;
+    LAST_CLAIMED_EPOCH.save(deps.storage, &address, &claimable_epochs[0].id)?;
 
-    // send funds to the user
+    // send funds to the user, or its delegate
     let mut messages = vec![];
     for fee in claimable_fees {
-        messages.push(fee.into_msg(info.sender.clone())?);
+        if !fee.amount.is_zero() {
+            messages.push(fee.into_msg(recipient.clone())?);
+        }
     }
 
     Ok(Response::new()
-        .add_attributes(vec![("action", "claim")])
+        .add_attributes(vec![
+            ("action", "claim".to_string()),
+            ("address", address.to_string()),
+            ("recipient", recipient.to_string()),
+        ])
         .add_messages(messages))
 }
 
+/// Gets the fee share of the given address for an epoch, i.e. the ratio of its weight and the global
+/// weight when the epoch was created.
+fn get_epoch_fee_share(
+    deps: Deps,
+    bonding_contract_addr: &Addr,
+    address: &Addr,
+    epoch_weight: EpochWeight,
+) -> StdResult<Decimal> {
+    if epoch_weight.global_weight.is_zero() {
+        return Ok(Decimal::zero());
+    }
+
+    let bonding_weight_response: BondingWeightResponse =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: bonding_contract_addr.to_string(),
+            msg: to_binary(&QueryMsg::WeightAt {
+                address: address.to_string(),
+                timestamp: epoch_weight.timestamp,
+            })?,
+        }))?;
+
+    Ok(Decimal::from_ratio(
+        bonding_weight_response.weight,
+        epoch_weight.global_weight,
+    ))
+}
+
+/// Gets the current fee share of the given address, i.e. the ratio of its weight and the global
+/// weight at the current moment.
+fn get_current_fee_share(
+    deps: Deps,
+    bonding_contract_addr: &Addr,
+    address: &Addr,
+) -> StdResult<Decimal> {
+    let bonding_weight_response: BondingWeightResponse =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: bonding_contract_addr.to_string(),
+            msg: to_binary(&QueryMsg::Weight {
+                address: address.to_string(),
+            })?,
+        }))?;
+
+    Ok(bonding_weight_response.share)
+}
+
+/// Receives cw20 tokens sent to the contract through the cw20 Receive hook.
+pub fn receive_cw20(
+    deps: DepsMut,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::FundEpoch { epoch_id } => {
+            let funder = deps.api.addr_validate(&cw20_msg.sender)?;
+            let funds = vec![Asset {
+                info: AssetInfo::Token {
+                    contract_addr: info.sender.to_string(),
+                },
+                amount: cw20_msg.amount,
+            }];
+
+            add_epoch_funds(deps, funder, epoch_id, funds)
+        }
+    }
+}
+
+/// Funds the given epoch, or the current one if none is provided, with the native tokens sent.
+pub fn fund_epoch(
+    deps: DepsMut,
+    info: MessageInfo,
+    epoch_id: Option<Uint64>,
+) -> Result<Response, ContractError> {
+    let funds = info
+        .funds
+        .into_iter()
+        .map(|coin| Asset {
+            info: AssetInfo::NativeToken { denom: coin.denom },
+            amount: coin.amount,
+        })
+        .collect();
+
+    add_epoch_funds(deps, info.sender, epoch_id, funds)
+}
+
+/// Adds the given funds to the fees of the given epoch, or of the current one if none is provided.
+/// The funds for future epochs are added when they are created.
+fn add_epoch_funds(
+    deps: DepsMut,
+    funder: Addr,
+    epoch_id: Option<Uint64>,
+    funds: Vec<Asset>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !config.funding_whitelist.is_empty() && !config.funding_whitelist.contains(&funder) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // only the distribution assets can be funded, so they can be compounded in the bonding contract
+    if funds.is_empty()
+        || funds
+            .iter()
+            .any(|fund| fund.amount.is_zero() || !config.distribution_assets.contains(&fund.info))
+    {
+        return Err(ContractError::AssetMismatch {});
+    }
+
+    let current_epoch = get_current_epoch(deps.as_ref())?.epoch;
+    let epoch_id = epoch_id.unwrap_or(current_epoch.id);
+    if epoch_id.is_zero()
+        || epoch_id < current_epoch.id
+        || epoch_id.u64() - current_epoch.id.u64() > MAX_EPOCHS_AHEAD_TO_FUND
+    {
+        return Err(ContractError::InvalidEpochToFund(epoch_id));
+    }
+
+    // the addresses that already claimed the current epoch wouldn't get a share of the funds. The
+    // claims made before they were recorded are found through the claimed fees
+    if epoch_id == current_epoch.id
+        && (CLAIMED_EPOCHS.has(deps.storage, &epoch_id.to_be_bytes())
+            || current_epoch
+                .claimed
+                .iter()
+                .any(|claimed| !claimed.amount.is_zero()))
+    {
+        return Err(ContractError::EpochAlreadyClaimed(epoch_id));
+    }
+
+    let sponsored_funds = EPOCH_SPONSORED_FUNDS
+        .may_load(deps.storage, &epoch_id.to_be_bytes())?
+        .unwrap_or_default();
+    EPOCH_SPONSORED_FUNDS.save(
+        deps.storage,
+        &epoch_id.to_be_bytes(),
+        &asset::aggregate_assets(sponsored_funds, funds.clone())?,
+    )?;
+
+    if epoch_id == current_epoch.id {
+        let epoch = Epoch {
+            total: asset::aggregate_assets(current_epoch.total, funds.clone())?,
+            available: asset::aggregate_assets(current_epoch.available, funds.clone())?,
+            ..current_epoch
+        };
+        EPOCHS.save(deps.storage, &epoch.id.to_be_bytes(), &epoch)?;
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "fund_epoch".to_string()),
+        ("funder", funder.to_string()),
+        ("epoch_id", epoch_id.to_string()),
+        (
+            "funds",
+            funds
+                .iter()
+                .map(|fund| fund.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+    ]))
+}
+
 /// Updates the [Config] of the contract
 #[allow(clippy::too_many_arguments)]
 pub fn update_config(
@@ -158,7 +396,8 @@ pub fn update_config(
     bonding_contract_addr: Option<String>,
     fee_collector_addr: Option<String>,
     grace_period: Option<Uint64>,
-    distribution_asset: Option<AssetInfo>,
+    distribution_assets: Option<Vec<AssetInfo>>,
+    funding_whitelist: Option<Vec<String>>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
@@ -178,8 +417,16 @@ pub fn update_config(
         config.fee_collector_addr = deps.api.addr_validate(&fee_collector_addr)?;
     }
 
-    if let Some(distribution_asset) = distribution_asset {
-        config.distribution_asset = distribution_asset;
+    if let Some(distribution_assets) = distribution_assets {
+        validate_distribution_assets(&distribution_assets)?;
+        config.distribution_assets = distribution_assets;
+    }
+
+    if let Some(funding_whitelist) = funding_whitelist {
+        config.funding_whitelist = funding_whitelist
+            .iter()
+            .map(|address| deps.api.addr_validate(address))
+            .collect::<StdResult<Vec<Addr>>>()?;
     }
 
     if let Some(grace_period) = grace_period {
@@ -203,6 +450,26 @@ pub fn update_config(
         ),
         ("fee_collector_addr", config.fee_collector_addr.to_string()),
         ("grace_period", config.grace_period.to_string()),
-        ("distribution_asset", config.distribution_asset.to_string()),
+        (
+            "distribution_assets",
+            config
+                .distribution_assets
+                .iter()
+                .map(|asset_info| asset_info.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        (
+            "funding_whitelist",
+            format!(
+                "[{}]",
+                config
+                    .funding_whitelist
+                    .iter()
+                    .map(|address| address.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ),
     ]))
 }
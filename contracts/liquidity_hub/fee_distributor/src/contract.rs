@@ -1,7 +1,8 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    from_binary, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
+    from_binary, to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response,
+    StdResult,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw_utils::parse_reply_execute_data;
@@ -12,9 +13,9 @@ use white_whale::fee_distributor::{
 };
 
 use crate::error::ContractError;
-use crate::helpers::{validate_epoch_config, validate_grace_period};
-use crate::state::{get_expiring_epoch, CONFIG, EPOCHS};
-use crate::{commands, queries, state};
+use crate::helpers::{validate_distribution_assets, validate_epoch_config, validate_grace_period};
+use crate::state::{get_expiring_epoch, CONFIG, EPOCHS, EPOCH_SPONSORED_FUNDS};
+use crate::{commands, migrations, queries, state};
 use semver::Version;
 use white_whale::pool_network::asset;
 
@@ -34,6 +35,7 @@ pub fn instantiate(
 
     validate_grace_period(&msg.grace_period)?;
     validate_epoch_config(&msg.epoch_config)?;
+    validate_distribution_assets(&msg.distribution_assets)?;
 
     let config = Config {
         owner: deps.api.addr_validate(info.sender.as_str())?,
@@ -41,7 +43,12 @@ pub fn instantiate(
         fee_collector_addr: deps.api.addr_validate(msg.fee_collector_addr.as_str())?,
         grace_period: msg.grace_period,
         epoch_config: msg.epoch_config,
-        distribution_asset: msg.distribution_asset,
+        distribution_assets: msg.distribution_assets,
+        funding_whitelist: msg
+            .funding_whitelist
+            .iter()
+            .map(|address| deps.api.addr_validate(address))
+            .collect::<StdResult<Vec<Addr>>>()?,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -56,7 +63,27 @@ pub fn instantiate(
         .add_attribute("fee_collector_addr", config.fee_collector_addr.as_str())
         .add_attribute("grace_period", config.grace_period.to_string())
         .add_attribute("epoch_config", config.epoch_config.to_string())
-        .add_attribute("distribution_asset", config.distribution_asset.to_string()))
+        .add_attribute(
+            "distribution_assets",
+            config
+                .distribution_assets
+                .iter()
+                .map(|asset_info| asset_info.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+        .add_attribute(
+            "funding_whitelist",
+            format!(
+                "[{}]",
+                config
+                    .funding_whitelist
+                    .iter()
+                    .map(|address| address.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -93,6 +120,17 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             )?;
         }
 
+        // add the funds sponsored for the new epoch
+        if let Some(sponsored_funds) =
+            EPOCH_SPONSORED_FUNDS.may_load(deps.storage, &new_epoch.id.to_be_bytes())?
+        {
+            new_epoch = Epoch {
+                total: asset::aggregate_assets(new_epoch.total, sponsored_funds.clone())?,
+                available: asset::aggregate_assets(new_epoch.available, sponsored_funds)?,
+                ..new_epoch
+            };
+        }
+
         // save the new epoch
         EPOCHS.save(deps.storage, &new_epoch.id.to_be_bytes(), &new_epoch)?;
 
@@ -116,14 +154,16 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
+        ExecuteMsg::Receive(msg) => commands::receive_cw20(deps, info, msg),
         ExecuteMsg::NewEpoch {} => commands::create_new_epoch(deps, env),
-        ExecuteMsg::Claim {} => commands::claim(deps, info),
+        ExecuteMsg::Claim { delegator } => commands::claim(deps, info, delegator),
         ExecuteMsg::UpdateConfig {
             owner,
             bonding_contract_addr,
             fee_collector_addr,
             grace_period,
-            distribution_asset,
+            distribution_assets,
+            funding_whitelist,
         } => commands::update_config(
             deps,
             info,
@@ -131,8 +171,10 @@ pub fn execute(
             bonding_contract_addr,
             fee_collector_addr,
             grace_period,
-            distribution_asset,
+            distribution_assets,
+            funding_whitelist,
         ),
+        ExecuteMsg::FundEpoch { epoch_id } => commands::fund_epoch(deps, info, epoch_id),
     }
 }
 
@@ -147,12 +189,15 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             deps,
             &deps.api.addr_validate(&address)?,
         )?)?),
+        QueryMsg::SponsoredFunds { epoch_id } => {
+            Ok(to_binary(&queries::query_sponsored_funds(deps, epoch_id)?)?)
+        }
     }
 }
 
 #[cfg(not(tarpaulin_include))]
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let version: Version = CONTRACT_VERSION.parse()?;
     let storage_version: Version = get_contract_version(deps.storage)?.version.parse()?;
 
@@ -163,6 +208,10 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
         });
     }
 
+    if storage_version < Version::parse("1.1.0")? {
+        migrations::migrate_to_v110(deps.branch())?;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
 }
@@ -3,6 +3,8 @@ use cw_utils::ParseReplyError;
 use semver::Version;
 use thiserror::Error;
 
+use crate::state::MAX_EPOCHS_AHEAD_TO_FUND;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
     #[error("{0}")]
@@ -38,6 +40,20 @@ pub enum ContractError {
     #[error("Couldn't read data for new epoch.")]
     CannotReadEpoch {},
 
+    #[error("The distribution assets must be at least one, without duplicates.")]
+    InvalidDistributionAssets {},
+
+    #[error(
+        "Can't fund epoch {0}. Only the current epoch and the next {max} ones can be funded.",
+        max = MAX_EPOCHS_AHEAD_TO_FUND
+    )]
+    InvalidEpochToFund(Uint64),
+
+    #[error(
+        "Can't fund epoch {0}, its fees are already being claimed. Fund a future epoch instead."
+    )]
+    EpochAlreadyClaimed(Uint64),
+
     #[error("Can't lower the grace period.")]
     GracePeriodDecrease {},
 
@@ -2,6 +2,7 @@ use cosmwasm_std::Uint64;
 
 use crate::ContractError;
 use white_whale::fee_distributor::EpochConfig;
+use white_whale::pool_network::asset::AssetInfo;
 
 const MAX_GRACE_PERIOD: u64 = 10u64;
 pub const DAY_IN_NANOSECONDS: u64 = 86_400_000_000_000u64;
@@ -15,6 +16,22 @@ pub fn validate_grace_period(grace_period: &Uint64) -> Result<(), ContractError>
     Ok(())
 }
 
+/// Validates the distribution assets, which can't be empty nor contain duplicates.
+pub fn validate_distribution_assets(
+    distribution_assets: &[AssetInfo],
+) -> Result<(), ContractError> {
+    if distribution_assets.is_empty()
+        || distribution_assets
+            .iter()
+            .enumerate()
+            .any(|(i, asset_info)| distribution_assets[..i].contains(asset_info))
+    {
+        return Err(ContractError::InvalidDistributionAssets {});
+    }
+
+    Ok(())
+}
+
 /// Validates the epoch duration.
 pub fn validate_epoch_config(epoch_config: &EpochConfig) -> Result<(), ContractError> {
     if epoch_config.duration < Uint64::new(DAY_IN_NANOSECONDS) {
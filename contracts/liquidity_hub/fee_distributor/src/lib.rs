@@ -2,6 +2,7 @@ mod commands;
 pub mod contract;
 mod error;
 pub mod helpers;
+mod migrations;
 mod queries;
 pub mod state;
 
@@ -0,0 +1,40 @@
+#![cfg(not(tarpaulin_include))]
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, DepsMut, StdError, Uint64};
+use cw_storage_plus::Item;
+use white_whale::fee_distributor::{Config, EpochConfig};
+use white_whale::pool_network::asset::AssetInfo;
+
+use crate::state::CONFIG;
+
+/// Migrates state from v1.0.0 to v1.1.0, which allows distributing several assets and funding
+/// epochs. The existing distribution asset becomes the only one in the Config, and anyone can fund
+/// epochs.
+pub fn migrate_to_v110(deps: DepsMut) -> Result<(), StdError> {
+    #[cw_serde]
+    struct ConfigV100 {
+        pub owner: Addr,
+        pub bonding_contract_addr: Addr,
+        pub fee_collector_addr: Addr,
+        pub grace_period: Uint64,
+        pub epoch_config: EpochConfig,
+        pub distribution_asset: AssetInfo,
+    }
+
+    const CONFIGV100: Item<ConfigV100> = Item::new("config");
+    let config_v100 = CONFIGV100.load(deps.storage)?;
+
+    let config = Config {
+        owner: config_v100.owner,
+        bonding_contract_addr: config_v100.bonding_contract_addr,
+        fee_collector_addr: config_v100.fee_collector_addr,
+        grace_period: config_v100.grace_period,
+        epoch_config: config_v100.epoch_config,
+        distribution_assets: vec![config_v100.distribution_asset],
+        funding_whitelist: vec![],
+    };
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(())
+}
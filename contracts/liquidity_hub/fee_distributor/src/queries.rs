@@ -1,8 +1,17 @@
-use crate::state::CONFIG;
-use cosmwasm_std::{Deps, StdResult};
-use white_whale::fee_distributor::Config;
+use crate::state::{CONFIG, EPOCH_SPONSORED_FUNDS};
+use cosmwasm_std::{Deps, StdResult, Uint64};
+use white_whale::fee_distributor::{Config, SponsoredFundsResponse};
 
 /// Queries the [Config] of the contract
 pub fn query_config(deps: Deps) -> StdResult<Config> {
     CONFIG.load(deps.storage)
 }
+
+/// Queries the funds sponsored for the epoch with the given id.
+pub fn query_sponsored_funds(deps: Deps, epoch_id: Uint64) -> StdResult<SponsoredFundsResponse> {
+    let funds = EPOCH_SPONSORED_FUNDS
+        .may_load(deps.storage, &epoch_id.to_be_bytes())?
+        .unwrap_or_default();
+
+    Ok(SponsoredFundsResponse { epoch_id, funds })
+}
@@ -1,11 +1,35 @@
-use cosmwasm_std::{Addr, Deps, Order, StdResult, Uint64};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, Order, StdResult, Timestamp, Uint128, Uint64};
 use cw_storage_plus::{Item, Map};
 
 use white_whale::fee_distributor::{ClaimableEpochsResponse, Config, Epoch, EpochResponse};
+use white_whale::pool_network::asset::Asset;
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const LAST_CLAIMED_EPOCH: Map<&Addr, Uint64> = Map::new("last_claimed_epoch");
 pub const EPOCHS: Map<&[u8], Epoch> = Map::new("epochs");
+/// The weights the fees of each epoch are shared by, recorded when the epoch is created. The epochs
+/// created before the weights were recorded don't have an entry.
+pub const EPOCH_WEIGHTS: Map<&[u8], EpochWeight> = Map::new("epoch_weights");
+
+/// The funds sponsored for each epoch, which are added to its fees. The funds sponsored for future
+/// epochs are added once they are created.
+pub const EPOCH_SPONSORED_FUNDS: Map<&[u8], Vec<Asset>> = Map::new("epoch_sponsored_funds");
+
+/// The epochs claimed by at least one address, even if their rewards rounded to zero. A claimed
+/// epoch can't be funded anymore, as its claimers wouldn't get a share of the funds.
+pub const CLAIMED_EPOCHS: Map<&[u8], bool> = Map::new("claimed_epochs");
+
+/// The number of epochs after the current one that can be funded.
+pub const MAX_EPOCHS_AHEAD_TO_FUND: u64 = 30;
+
+/// The global weight of the bonding contract when an epoch was created.
+#[cw_serde]
+pub struct EpochWeight {
+    /// The timestamp the weights were taken at.
+    pub timestamp: Timestamp,
+    pub global_weight: Uint128,
+}
 
 /// Returns the current epoch, which is the last on the EPOCHS map.
 pub fn get_current_epoch(deps: Deps) -> StdResult<EpochResponse> {
@@ -1,8 +1,16 @@
-use cosmwasm_std::testing::{mock_dependencies, mock_env};
-use cosmwasm_std::{Addr, Uint64};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{
+    coins, to_binary, Addr, BankMsg, CosmosMsg, Decimal, Timestamp, Uint128, Uint64, WasmMsg,
+};
 
+use white_whale::fee_distributor::Epoch;
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::whale_lair::{Delegation, DelegationType};
+
+use crate::state::EpochWeight;
 use crate::tests::robot::TestingRobot;
 use crate::tests::test_helpers;
+use crate::ContractError;
 
 #[test]
 fn test_claimable_epochs() {
@@ -53,3 +61,255 @@ fn test_claimable_epochs_for_user() {
             assert!(epochs.is_empty());
         });
 }
+
+#[test]
+fn test_claim_with_delegated_claim_rights() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let epochs = test_helpers::get_epochs();
+
+    robot
+        .instantiate_default()
+        .add_epochs_to_state(epochs)
+        .mock_bonding_contract(
+            Decimal::percent(1),
+            vec![Delegation {
+                delegator: Addr::unchecked("owner"),
+                delegate: Addr::unchecked("delegate"),
+                delegation_type: DelegationType::Claim,
+            }],
+        )
+        .claim(mock_info("random", &[]), Some("owner".to_string()), |res| {
+            assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+        })
+        .claim(mock_info("owner", &[]), None, |res| {
+            let res = res.unwrap();
+
+            // the rewards of the delegator are paid to the delegate
+            assert!(!res.messages.is_empty());
+            for message in res.messages {
+                match message.msg {
+                    CosmosMsg::Bank(BankMsg::Send { to_address, .. }) => {
+                        assert_eq!(to_address, "delegate")
+                    }
+                    _ => panic!("unexpected message"),
+                }
+            }
+        })
+        .claim(
+            mock_info("delegate", &[]),
+            Some("owner".to_string()),
+            |res| {
+                assert_eq!(res.unwrap_err(), ContractError::NothingToClaim {});
+            },
+        );
+}
+
+#[test]
+fn test_claim_with_delegated_weight() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let epochs = test_helpers::get_epochs();
+
+    robot
+        .instantiate_default()
+        .add_epochs_to_state(epochs)
+        .mock_bonding_contract(
+            Decimal::percent(1),
+            vec![Delegation {
+                delegator: Addr::unchecked("owner"),
+                delegate: Addr::unchecked("delegate"),
+                delegation_type: DelegationType::Weight,
+            }],
+        )
+        .claim(
+            mock_info("delegate", &[]),
+            Some("owner".to_string()),
+            |res| {
+                assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+            },
+        )
+        .claim(mock_info("owner", &[]), None, |res| {
+            let res = res.unwrap();
+
+            // the delegated weight is already part of the weight of the delegate, whatever is left
+            // to the delegator is paid to itself
+            assert!(!res.messages.is_empty());
+            for message in res.messages {
+                match message.msg {
+                    CosmosMsg::Bank(BankMsg::Send { to_address, .. }) => {
+                        assert_eq!(to_address, "owner")
+                    }
+                    _ => panic!("unexpected message"),
+                }
+            }
+        });
+}
+
+#[test]
+fn test_claim_by_bonding_contract_for_auto_compounding() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let epochs = test_helpers::get_epochs();
+
+    robot
+        .instantiate_default()
+        .add_epochs_to_state(epochs)
+        .mock_bonding_contract(
+            Decimal::percent(1),
+            vec![Delegation {
+                delegator: Addr::unchecked("owner"),
+                delegate: Addr::unchecked("delegate"),
+                delegation_type: DelegationType::Claim,
+            }],
+        )
+        .claim(
+            mock_info("bonding_contract_addr", &[]),
+            Some("owner".to_string()),
+            |res| {
+                let res = res.unwrap();
+
+                // the rewards are paid to the bonding contract to be compounded, regardless of the
+                // claim delegation
+                assert!(!res.messages.is_empty());
+                for message in res.messages {
+                    match message.msg {
+                        CosmosMsg::Bank(BankMsg::Send { to_address, .. }) => {
+                            assert_eq!(to_address, "bonding_contract_addr")
+                        }
+                        _ => panic!("unexpected message"),
+                    }
+                }
+            },
+        );
+}
+
+#[test]
+fn test_claim_by_weight_at_epoch_creation() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let epochs = test_helpers::get_epochs();
+
+    robot
+        .instantiate_default()
+        .add_epochs_to_state(epochs)
+        .add_epoch_weights_to_state(vec![
+            (
+                Uint64::new(2u64),
+                EpochWeight {
+                    timestamp: Timestamp::from_seconds(1678813300),
+                    global_weight: Uint128::new(1_000u128),
+                },
+            ),
+            (
+                Uint64::new(3u64),
+                EpochWeight {
+                    timestamp: Timestamp::from_seconds(1678899700),
+                    global_weight: Uint128::new(2_000u128),
+                },
+            ),
+        ])
+        // the owner bonded after the second epoch was created
+        .mock_bonding_contract_weight_history(vec![(
+            "owner".to_string(),
+            Timestamp::from_seconds(1678899700),
+            Uint128::new(500u128),
+        )])
+        .claim(mock_info("owner", &[]), None, |res| {
+            let res = res.unwrap();
+
+            // 25% of the third epoch, nothing of the second one
+            assert_eq!(res.messages.len(), 2usize);
+            assert_eq!(
+                res.messages[0].msg,
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "owner".to_string(),
+                    amount: coins(1_250_000u128, "uatom"),
+                })
+            );
+            assert_eq!(
+                res.messages[1].msg,
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "owner".to_string(),
+                    amount: coins(1_250_000u128, "uwhale"),
+                })
+            );
+        })
+        .query_epoch(Uint64::new(2u64), |res| {
+            let (_, epoch) = res.unwrap();
+            assert_eq!(epoch.available, epoch.total);
+        });
+}
+
+#[test]
+fn test_claim_several_distribution_assets() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let fees = vec![
+        Asset {
+            info: AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            amount: Uint128::new(10_000_000u128),
+        },
+        Asset {
+            info: AssetInfo::Token {
+                contract_addr: "usdc_addr".to_string(),
+            },
+            amount: Uint128::new(2_000_000u128),
+        },
+    ];
+
+    robot
+        .instantiate_default()
+        .add_epochs_to_state(vec![Epoch {
+            id: Uint64::one(),
+            start_time: Timestamp::from_seconds(1678802400),
+            total: fees.clone(),
+            available: fees,
+            claimed: vec![],
+        }])
+        .mock_bonding_contract(Decimal::percent(10), vec![])
+        .claim(mock_info("owner", &[]), None, |res| {
+            let res = res.unwrap();
+
+            assert_eq!(res.messages.len(), 2usize);
+            assert_eq!(
+                res.messages[0].msg,
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "owner".to_string(),
+                    amount: coins(1_000_000u128, "uwhale"),
+                })
+            );
+            assert_eq!(
+                res.messages[1].msg,
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: "usdc_addr".to_string(),
+                    msg: to_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                        recipient: "owner".to_string(),
+                        amount: Uint128::new(200_000u128),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                })
+            );
+        })
+        .query_epoch(Uint64::one(), |res| {
+            let (_, epoch) = res.unwrap();
+
+            // both assets are accounted as claimed
+            assert_eq!(
+                epoch.claimed,
+                vec![
+                    Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "uwhale".to_string(),
+                        },
+                        amount: Uint128::new(1_000_000u128),
+                    },
+                    Asset {
+                        info: AssetInfo::Token {
+                            contract_addr: "usdc_addr".to_string(),
+                        },
+                        amount: Uint128::new(200_000u128),
+                    },
+                ]
+            );
+            assert_eq!(epoch.available[1].amount, Uint128::new(1_800_000u128));
+        });
+}
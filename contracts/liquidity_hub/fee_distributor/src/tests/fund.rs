@@ -0,0 +1,358 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{coin, coins, Decimal, Timestamp, Uint128, Uint64};
+
+use white_whale::fee_distributor::{Epoch, EpochConfig};
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+
+use crate::tests::robot::TestingRobot;
+use crate::tests::test_helpers;
+use crate::ContractError;
+
+fn uwhale(amount: u128) -> Asset {
+    Asset {
+        info: AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+        amount: Uint128::new(amount),
+    }
+}
+
+fn usdc(amount: u128) -> Asset {
+    Asset {
+        info: AssetInfo::Token {
+            contract_addr: "usdc_addr".to_string(),
+        },
+        amount: Uint128::new(amount),
+    }
+}
+
+fn instantiate_with_usdc(robot: &mut TestingRobot) -> &mut TestingRobot {
+    robot.instantiate(
+        mock_info("owner", &[]),
+        "bonding_contract_addr".to_string(),
+        "fee_collector_addr".to_string(),
+        Uint64::new(2),
+        EpochConfig {
+            duration: Uint64::new(86_400_000_000_000u64), // a day
+            genesis_epoch: Uint64::new(1_678_802_400_000_000_000u64), // March 14, 2023 2:00:00 PM
+        },
+        vec![uwhale(0).info, usdc(0).info],
+    )
+}
+
+#[test]
+fn test_fund_current_epoch() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let mut epochs = test_helpers::get_epochs();
+
+    // nobody claimed the current epoch yet
+    let current_epoch = epochs.last_mut().unwrap();
+    current_epoch.available = current_epoch.total.clone();
+    current_epoch.claimed = vec![];
+
+    instantiate_with_usdc(&mut robot)
+        .add_epochs_to_state(epochs)
+        .fund_epoch(
+            mock_info("sponsor", &coins(1_000_000u128, "uwhale")),
+            None,
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fund_epoch_with_cw20(
+            "usdc_addr",
+            "sponsor",
+            Uint128::new(500_000u128),
+            Some(Uint64::new(3u64)),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .query_epoch(Uint64::new(3u64), |res| {
+            let (_, epoch) = res.unwrap();
+            assert_eq!(
+                epoch.total,
+                vec![
+                    Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "uatom".to_string(),
+                        },
+                        amount: Uint128::new(5_000_000u128),
+                    },
+                    uwhale(6_000_000u128),
+                    usdc(500_000u128),
+                ]
+            );
+            assert_eq!(epoch.available, epoch.total);
+        })
+        .query_sponsored_funds(Uint64::new(3u64), |res| {
+            assert_eq!(res.unwrap(), vec![uwhale(1_000_000u128), usdc(500_000u128)]);
+        });
+}
+
+#[test]
+fn test_fund_future_epoch() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let epochs = test_helpers::get_epochs();
+
+    instantiate_with_usdc(&mut robot)
+        .add_epochs_to_state(epochs.clone())
+        .fund_epoch_with_cw20(
+            "usdc_addr",
+            "sponsor",
+            Uint128::new(500_000u128),
+            Some(Uint64::new(4u64)),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fund_epoch(
+            mock_info("sponsor", &coins(1_000_000u128, "uwhale")),
+            Some(Uint64::new(4u64)),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fund_epoch(
+            mock_info("another_sponsor", &coins(2_000_000u128, "uwhale")),
+            Some(Uint64::new(4u64)),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .query_sponsored_funds(Uint64::new(4u64), |res| {
+            assert_eq!(res.unwrap(), vec![usdc(500_000u128), uwhale(3_000_000u128)]);
+        })
+        // the current epoch is untouched
+        .assert_current_epoch(&epochs[2])
+        .reply_epoch_creation(
+            Epoch {
+                id: Uint64::new(4u64),
+                start_time: Timestamp::from_seconds(1678986000),
+                total: vec![uwhale(2_000_000u128)],
+                available: vec![uwhale(2_000_000u128)],
+                claimed: vec![],
+            },
+            |res| {
+                res.unwrap();
+            },
+        )
+        .query_current_epoch(|res| {
+            let epoch = res.unwrap();
+            assert_eq!(epoch.id, Uint64::new(4u64));
+
+            // the fees forwarded by the fee collector, plus the unclaimed fees of the expiring
+            // epoch and the sponsored funds
+            let fees = vec![uwhale(20_000_000u128), usdc(500_000u128)];
+            assert_eq!(epoch.total, fees);
+            assert_eq!(epoch.available, fees);
+        });
+}
+
+#[test]
+fn test_fund_epoch_unsuccessfully() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let epochs = test_helpers::get_epochs();
+
+    instantiate_with_usdc(&mut robot)
+        // there's no current epoch to fund
+        .fund_epoch(
+            mock_info("sponsor", &coins(1_000_000u128, "uwhale")),
+            None,
+            |res| {
+                assert_eq!(
+                    res.unwrap_err(),
+                    ContractError::InvalidEpochToFund(Uint64::zero())
+                );
+            },
+        )
+        .add_epochs_to_state(epochs)
+        .fund_epoch(
+            mock_info("sponsor", &coins(1_000_000u128, "uwhale")),
+            Some(Uint64::new(2u64)),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err(),
+                    ContractError::InvalidEpochToFund(Uint64::new(2u64))
+                );
+            },
+        )
+        // the epoch is too far ahead of the current one
+        .fund_epoch(
+            mock_info("sponsor", &coins(1_000_000u128, "uwhale")),
+            Some(Uint64::new(34u64)),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err(),
+                    ContractError::InvalidEpochToFund(Uint64::new(34u64))
+                );
+            },
+        )
+        .fund_epoch(
+            mock_info("sponsor", &coins(1_000_000u128, "uwhale")),
+            Some(Uint64::new(33u64)),
+            |res| {
+                res.unwrap();
+            },
+        )
+        // the fees of the current epoch are already being claimed
+        .fund_epoch(
+            mock_info("sponsor", &coins(1_000_000u128, "uwhale")),
+            None,
+            |res| {
+                assert_eq!(
+                    res.unwrap_err(),
+                    ContractError::EpochAlreadyClaimed(Uint64::new(3u64))
+                );
+            },
+        )
+        .fund_epoch_with_cw20(
+            "usdc_addr",
+            "sponsor",
+            Uint128::new(500_000u128),
+            Some(Uint64::new(3u64)),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err(),
+                    ContractError::EpochAlreadyClaimed(Uint64::new(3u64))
+                );
+            },
+        )
+        .query_sponsored_funds(Uint64::new(3u64), |res| {
+            assert!(res.unwrap().is_empty());
+        })
+        .fund_epoch(mock_info("sponsor", &[]), None, |res| {
+            assert_eq!(res.unwrap_err(), ContractError::AssetMismatch {});
+        })
+        .fund_epoch(
+            mock_info(
+                "sponsor",
+                &[coin(1_000_000u128, "uwhale"), coin(1_000_000u128, "uatom")],
+            ),
+            None,
+            |res| {
+                assert_eq!(res.unwrap_err(), ContractError::AssetMismatch {});
+            },
+        )
+        .fund_epoch_with_cw20(
+            "other_token_addr",
+            "sponsor",
+            Uint128::new(500_000u128),
+            None,
+            |res| {
+                assert_eq!(res.unwrap_err(), ContractError::AssetMismatch {});
+            },
+        )
+        .fund_epoch_with_cw20("usdc_addr", "sponsor", Uint128::zero(), None, |res| {
+            assert_eq!(res.unwrap_err(), ContractError::AssetMismatch {});
+        });
+}
+
+#[test]
+fn test_fund_epoch_claimed_without_rewards() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let fees = vec![uwhale(10u128)];
+
+    robot
+        .instantiate_default()
+        .add_epochs_to_state(vec![Epoch {
+            id: Uint64::one(),
+            start_time: Timestamp::from_seconds(1678802400),
+            total: fees.clone(),
+            available: fees,
+            claimed: vec![],
+        }])
+        .mock_bonding_contract(Decimal::percent(1), vec![])
+        // the reward rounds to zero, so nothing is recorded as claimed
+        .claim(mock_info("owner", &[]), None, |res| {
+            assert!(res.unwrap().messages.is_empty());
+        })
+        .query_epoch(Uint64::one(), |res| {
+            assert!(res
+                .unwrap()
+                .1
+                .claimed
+                .iter()
+                .all(|fee| fee.amount.is_zero()));
+        })
+        // the claimer wouldn't get a share of the funds
+        .fund_epoch(
+            mock_info("sponsor", &coins(1_000_000u128, "uwhale")),
+            None,
+            |res| {
+                assert_eq!(
+                    res.unwrap_err(),
+                    ContractError::EpochAlreadyClaimed(Uint64::one())
+                );
+            },
+        );
+}
+
+#[test]
+fn test_fund_epoch_with_funding_whitelist() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let epochs = test_helpers::get_epochs();
+
+    instantiate_with_usdc(&mut robot)
+        .add_epochs_to_state(epochs)
+        .update_funding_whitelist(
+            mock_info("unauthorized", &[]),
+            vec!["sponsor".to_string()],
+            |res| {
+                assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+            },
+        )
+        .update_funding_whitelist(
+            mock_info("owner", &[]),
+            vec!["sponsor".to_string()],
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fund_epoch(
+            mock_info("random", &coins(1_000_000u128, "uwhale")),
+            Some(Uint64::new(4u64)),
+            |res| {
+                assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+            },
+        )
+        .fund_epoch_with_cw20(
+            "usdc_addr",
+            "random",
+            Uint128::new(500_000u128),
+            Some(Uint64::new(4u64)),
+            |res| {
+                assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+            },
+        )
+        .fund_epoch(
+            mock_info("sponsor", &coins(1_000_000u128, "uwhale")),
+            Some(Uint64::new(4u64)),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fund_epoch_with_cw20(
+            "usdc_addr",
+            "sponsor",
+            Uint128::new(500_000u128),
+            Some(Uint64::new(4u64)),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .query_sponsored_funds(Uint64::new(4u64), |res| {
+            assert_eq!(res.unwrap(), vec![uwhale(1_000_000u128), usdc(500_000u128)]);
+        })
+        // anyone can fund epochs once the whitelist is cleared
+        .update_funding_whitelist(mock_info("owner", &[]), vec![], |res| {
+            res.unwrap();
+        })
+        .fund_epoch(
+            mock_info("random", &coins(1_000_000u128, "uwhale")),
+            Some(Uint64::new(4u64)),
+            |res| {
+                res.unwrap();
+            },
+        );
+}
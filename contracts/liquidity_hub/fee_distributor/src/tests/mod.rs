@@ -1,5 +1,6 @@
 mod claim;
 mod epoch;
+mod fund;
 mod helpers;
 mod robot;
 mod test_helpers;
@@ -1,16 +1,21 @@
 use cosmwasm_std::testing::{mock_info, MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
-    from_binary, Addr, Empty, Env, MessageInfo, OwnedDeps, Response, StdResult, Uint64,
+    from_binary, to_binary, Addr, Binary, ContractResult, Decimal, Empty, Env, MessageInfo,
+    OwnedDeps, Reply, Response, StdResult, SubMsgResponse, SubMsgResult, SystemResult, Timestamp,
+    Uint128, Uint64, WasmQuery,
 };
+use cw20::Cw20ReceiveMsg;
 
+use white_whale::fee_collector::ForwardFeesResponse;
 use white_whale::fee_distributor::{
-    ClaimableEpochsResponse, Config, Epoch, EpochConfig, EpochResponse, ExecuteMsg, InstantiateMsg,
-    QueryMsg,
+    ClaimableEpochsResponse, Config, Cw20HookMsg, Epoch, EpochConfig, EpochResponse, ExecuteMsg,
+    InstantiateMsg, QueryMsg, SponsoredFundsResponse,
 };
-use white_whale::pool_network::asset::AssetInfo;
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::whale_lair::{BondingWeightResponse, Delegation, DelegationResponse};
 
-use crate::contract::{execute, instantiate, query};
-use crate::state::{get_expiring_epoch, EPOCHS, LAST_CLAIMED_EPOCH};
+use crate::contract::{execute, instantiate, query, reply, EPOCH_CREATION_REPLY_ID};
+use crate::state::{get_expiring_epoch, EpochWeight, EPOCHS, EPOCH_WEIGHTS, LAST_CLAIMED_EPOCH};
 use crate::ContractError;
 
 pub struct TestingRobot {
@@ -35,9 +40,10 @@ impl TestingRobot {
                 duration: Uint64::new(86_400_000_000_000u64), // a day
                 genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
             },
-            distribution_asset: AssetInfo::NativeToken {
+            distribution_assets: vec![AssetInfo::NativeToken {
                 denom: "uwhale".to_string(),
-            },
+            }],
+            funding_whitelist: vec![],
         };
 
         instantiate(
@@ -58,14 +64,15 @@ impl TestingRobot {
         fee_collector_addr: String,
         grace_period: Uint64,
         epoch_config: EpochConfig,
-        distribution_asset: AssetInfo,
+        distribution_assets: Vec<AssetInfo>,
     ) -> &mut Self {
         let msg = InstantiateMsg {
             bonding_contract_addr,
             fee_collector_addr,
             grace_period,
             epoch_config,
-            distribution_asset,
+            distribution_assets,
+            funding_whitelist: vec![],
         };
 
         instantiate(self.owned_deps.as_mut(), self.env.clone(), info, msg).unwrap();
@@ -80,14 +87,15 @@ impl TestingRobot {
         fee_collector_addr: String,
         grace_period: Uint64,
         epoch_config: EpochConfig,
-        distribution_asset: AssetInfo,
+        distribution_assets: Vec<AssetInfo>,
     ) -> &mut Self {
         let msg = InstantiateMsg {
             bonding_contract_addr,
             fee_collector_addr,
             grace_period,
             epoch_config,
-            distribution_asset,
+            distribution_assets,
+            funding_whitelist: vec![],
         };
 
         instantiate(self.owned_deps.as_mut(), self.env.clone(), info, msg).unwrap_err();
@@ -109,6 +117,23 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn add_epoch_weights_to_state(
+        &mut self,
+        epoch_weights: Vec<(Uint64, EpochWeight)>,
+    ) -> &mut Self {
+        for (epoch_id, epoch_weight) in epoch_weights {
+            EPOCH_WEIGHTS
+                .save(
+                    &mut self.owned_deps.storage,
+                    &epoch_id.to_be_bytes(),
+                    &epoch_weight,
+                )
+                .unwrap();
+        }
+
+        self
+    }
+
     pub(crate) fn add_last_claimed_epoch_to_state(
         &mut self,
         address: Addr,
@@ -120,6 +145,106 @@ impl TestingRobot {
         self
     }
 
+    /// Mocks the bonding contract, giving every address the same share and the given delegations.
+    pub(crate) fn mock_bonding_contract(
+        &mut self,
+        share: Decimal,
+        delegations: Vec<Delegation>,
+    ) -> &mut Self {
+        let timestamp = self.env.block.time;
+        self.owned_deps
+            .querier
+            .update_wasm(move |request| match request {
+                WasmQuery::Smart { msg, .. } => match from_binary(msg).unwrap() {
+                    white_whale::whale_lair::QueryMsg::Weight { address } => {
+                        SystemResult::Ok(ContractResult::Ok(
+                            to_binary(&BondingWeightResponse {
+                                address,
+                                weight: Uint128::zero(),
+                                delegated_weight: Uint128::zero(),
+                                global_weight: Uint128::zero(),
+                                share,
+                                timestamp,
+                            })
+                            .unwrap(),
+                        ))
+                    }
+                    white_whale::whale_lair::QueryMsg::Delegation { delegator } => {
+                        SystemResult::Ok(ContractResult::Ok(
+                            to_binary(&DelegationResponse {
+                                delegation: delegations
+                                    .iter()
+                                    .find(|delegation| delegation.delegator == delegator)
+                                    .cloned(),
+                            })
+                            .unwrap(),
+                        ))
+                    }
+                    _ => panic!("unexpected bonding contract query"),
+                },
+                _ => panic!("unexpected wasm query"),
+            });
+
+        self
+    }
+
+    /// Mocks the bonding contract with the given weights of the addresses at past timestamps, which
+    /// are zero otherwise. No address delegated anything.
+    pub(crate) fn mock_bonding_contract_weight_history(
+        &mut self,
+        weights: Vec<(String, Timestamp, Uint128)>,
+    ) -> &mut Self {
+        self.owned_deps
+            .querier
+            .update_wasm(move |request| match request {
+                WasmQuery::Smart { msg, .. } => match from_binary(msg).unwrap() {
+                    white_whale::whale_lair::QueryMsg::WeightAt { address, timestamp } => {
+                        let weight = weights
+                            .iter()
+                            .find(|(a, t, _)| a == &address && t == &timestamp)
+                            .map_or(Uint128::zero(), |(_, _, weight)| *weight);
+
+                        SystemResult::Ok(ContractResult::Ok(
+                            to_binary(&BondingWeightResponse {
+                                address,
+                                weight,
+                                delegated_weight: Uint128::zero(),
+                                global_weight: Uint128::zero(),
+                                share: Decimal::zero(),
+                                timestamp,
+                            })
+                            .unwrap(),
+                        ))
+                    }
+                    white_whale::whale_lair::QueryMsg::Delegation { .. } => {
+                        SystemResult::Ok(ContractResult::Ok(
+                            to_binary(&DelegationResponse { delegation: None }).unwrap(),
+                        ))
+                    }
+                    _ => panic!("unexpected bonding contract query"),
+                },
+                _ => panic!("unexpected wasm query"),
+            });
+
+        self
+    }
+
+    pub(crate) fn claim(
+        &mut self,
+        info: MessageInfo,
+        delegator: Option<String>,
+        response: impl Fn(Result<Response, ContractError>),
+    ) -> &mut Self {
+        response(execute(
+            self.owned_deps.as_mut(),
+            self.env.clone(),
+            info,
+            ExecuteMsg::Claim { delegator },
+        ));
+
+        self
+    }
+
     pub(crate) fn update_config(
         &mut self,
         info: MessageInfo,
@@ -131,7 +256,39 @@ impl TestingRobot {
             bonding_contract_addr: Some(config.bonding_contract_addr.to_string()),
             fee_collector_addr: Some(config.fee_collector_addr.to_string()),
             grace_period: Some(config.grace_period),
-            distribution_asset: Some(config.distribution_asset),
+            distribution_assets: Some(config.distribution_assets),
+            funding_whitelist: Some(
+                config
+                    .funding_whitelist
+                    .iter()
+                    .map(|address| address.to_string())
+                    .collect(),
+            ),
+        };
+
+        response(execute(
+            self.owned_deps.as_mut(),
+            self.env.clone(),
+            info,
+            msg,
+        ));
+
+        self
+    }
+
+    pub(crate) fn update_funding_whitelist(
+        &mut self,
+        info: MessageInfo,
+        funding_whitelist: Vec<String>,
+        response: impl Fn(Result<Response, ContractError>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::UpdateConfig {
+            owner: None,
+            bonding_contract_addr: None,
+            fee_collector_addr: None,
+            grace_period: None,
+            distribution_assets: None,
+            funding_whitelist: Some(funding_whitelist),
         };
 
         response(execute(
@@ -143,6 +300,77 @@ impl TestingRobot {
 
         self
     }
+
+    pub(crate) fn fund_epoch(
+        &mut self,
+        info: MessageInfo,
+        epoch_id: Option<Uint64>,
+        response: impl Fn(Result<Response, ContractError>),
+    ) -> &mut Self {
+        response(execute(
+            self.owned_deps.as_mut(),
+            self.env.clone(),
+            info,
+            ExecuteMsg::FundEpoch { epoch_id },
+        ));
+
+        self
+    }
+
+    pub(crate) fn fund_epoch_with_cw20(
+        &mut self,
+        token: &str,
+        sender: &str,
+        amount: Uint128,
+        epoch_id: Option<Uint64>,
+        response: impl Fn(Result<Response, ContractError>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: sender.to_string(),
+            amount,
+            msg: to_binary(&Cw20HookMsg::FundEpoch { epoch_id }).unwrap(),
+        });
+
+        response(execute(
+            self.owned_deps.as_mut(),
+            self.env.clone(),
+            mock_info(token, &[]),
+            msg,
+        ));
+
+        self
+    }
+
+    /// Replies to the epoch creation with the given epoch, as the fee collector does after
+    /// forwarding the fees.
+    pub(crate) fn reply_epoch_creation(
+        &mut self,
+        epoch: Epoch,
+        response: impl Fn(Result<Response, ContractError>),
+    ) -> &mut Self {
+        // the data is wrapped in a protobuf encoded MsgExecuteContractResponse
+        let forward_fees_response = to_binary(&ForwardFeesResponse { epoch }).unwrap().to_vec();
+        let mut data = vec![0x0a];
+        let mut len = forward_fees_response.len();
+        while len >= 0x80 {
+            data.push((len as u8) | 0x80);
+            len >>= 7;
+        }
+        data.push(len as u8);
+        data.extend(forward_fees_response);
+
+        let msg = Reply {
+            id: EPOCH_CREATION_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(Binary::from(data)),
+            }),
+        };
+
+        response(reply(self.owned_deps.as_mut(), self.env.clone(), msg));
+
+        self
+    }
 }
 
 /// Queries
@@ -209,6 +437,24 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn query_sponsored_funds(
+        &mut self,
+        epoch_id: Uint64,
+        response: impl Fn(StdResult<Vec<Asset>>),
+    ) -> &mut Self {
+        let query_res = query(
+            self.owned_deps.as_ref(),
+            self.env.clone(),
+            QueryMsg::SponsoredFunds { epoch_id },
+        )
+        .unwrap();
+        let res: SponsoredFundsResponse = from_binary(&query_res).unwrap();
+
+        response(Ok(res.funds));
+
+        self
+    }
+
     pub(crate) fn query_config(
         &mut self,
         response: impl Fn(StdResult<(&mut Self, Config)>),
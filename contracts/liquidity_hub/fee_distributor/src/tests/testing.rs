@@ -12,9 +12,9 @@ use white_whale::pool_network::asset::AssetInfo;
 fn instantiate_successfully() {
     let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
     let grace_period = Uint64::new(2);
-    let distribution_asset = AssetInfo::NativeToken {
+    let distribution_assets = vec![AssetInfo::NativeToken {
         denom: "uwhale".to_string(),
-    };
+    }];
     let epoch_config = EpochConfig {
         duration: Uint64::new(86_400_000_000_000u64), // a day
         genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
@@ -27,7 +27,7 @@ fn instantiate_successfully() {
             "fee_collector_addr".to_string(),
             grace_period,
             epoch_config.clone(),
-            distribution_asset.clone(),
+            distribution_assets.clone(),
         )
         .asset_config(Config {
             owner: Addr::unchecked("owner"),
@@ -35,7 +35,8 @@ fn instantiate_successfully() {
             bonding_contract_addr: Addr::unchecked("bonding_contract_addr"),
             fee_collector_addr: Addr::unchecked("fee_collector_addr"),
             epoch_config,
-            distribution_asset,
+            distribution_assets,
+            funding_whitelist: vec![],
         });
 }
 
@@ -43,9 +44,9 @@ fn instantiate_successfully() {
 fn instantiate_unsuccessfully() {
     let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
     let invalid_grace_period = Uint64::zero();
-    let distribution_asset = AssetInfo::NativeToken {
+    let distribution_assets = vec![AssetInfo::NativeToken {
         denom: "uwhale".to_string(),
-    };
+    }];
     let epoch_config = EpochConfig {
         duration: Uint64::new(86_400_000_000_000u64), // a day
         genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
@@ -57,7 +58,7 @@ fn instantiate_unsuccessfully() {
         "fee_collector_addr".to_string(),
         invalid_grace_period,
         epoch_config.clone(),
-        distribution_asset.clone(),
+        distribution_assets.clone(),
     );
 
     let invalid_grace_period = Uint64::new(11);
@@ -67,7 +68,7 @@ fn instantiate_unsuccessfully() {
         "fee_collector_addr".to_string(),
         invalid_grace_period,
         epoch_config.clone(),
-        distribution_asset.clone(),
+        distribution_assets.clone(),
     );
 
     let invalid_epoch_duration = Uint64::new(3600u64);
@@ -80,7 +81,28 @@ fn instantiate_unsuccessfully() {
             duration: invalid_epoch_duration,                    // a day
             genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
         },
-        distribution_asset.clone(),
+        distribution_assets.clone(),
+    );
+
+    robot.instantiate_err(
+        mock_info("owner", &[]),
+        "bonding_contract_addr".to_string(),
+        "fee_collector_addr".to_string(),
+        Uint64::one(),
+        epoch_config.clone(),
+        vec![],
+    );
+
+    robot.instantiate_err(
+        mock_info("owner", &[]),
+        "bonding_contract_addr".to_string(),
+        "fee_collector_addr".to_string(),
+        Uint64::one(),
+        epoch_config,
+        vec![
+            distribution_assets[0].clone(),
+            distribution_assets[0].clone(),
+        ],
     );
 }
 
@@ -97,9 +119,15 @@ fn test_update_config() {
             duration: Uint64::new(86_400_000_000_000u64), // a day
             genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
         },
-        distribution_asset: AssetInfo::NativeToken {
-            denom: "uwhale".to_string(),
-        },
+        distribution_assets: vec![
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "usdc_addr".to_string(),
+            },
+        ],
+        funding_whitelist: vec![Addr::unchecked("sponsor")],
     };
 
     robot
@@ -149,6 +177,18 @@ fn test_update_config() {
                 _ => panic!("should have returned ContractError::GracePeriodDecrease"),
             },
         )
+        .update_config(
+            mock_info("owner", &[]),
+            Config {
+                distribution_assets: vec![],
+                ..new_config.clone()
+            },
+            |res| match res {
+                Ok(_) => panic!("should have returned ContractError::InvalidDistributionAssets"),
+                Err(ContractError::InvalidDistributionAssets {}) => (),
+                _ => panic!("should have returned ContractError::InvalidDistributionAssets"),
+            },
+        )
         .update_config(mock_info("owner", &[]), new_config.clone(), |_| {})
         .asset_config(new_config.clone());
 }
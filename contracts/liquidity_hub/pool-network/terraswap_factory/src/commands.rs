@@ -1,20 +1,29 @@
 use cosmwasm_std::{
-    to_binary, wasm_execute, CosmosMsg, DepsMut, Env, MessageInfo, ReplyOn, Response, SubMsg,
-    WasmMsg,
+    to_binary, wasm_execute, Addr, Coin, ContractInfoResponse, CosmosMsg, Deps, DepsMut, Env,
+    MessageInfo, QueryRequest, Reply, ReplyOn, Response, StdError, SubMsg, Uint128, WasmMsg,
+    WasmQuery,
 };
+use cw20::Cw20ExecuteMsg;
 
 use white_whale::pool_network;
-use white_whale::pool_network::asset::{AssetInfo, PairType};
+use white_whale::pool_network::asset::{Asset, AssetInfo, PairInfoRaw, PairType};
+use white_whale::pool_network::factory::{PairsFilter, PoolFeeBounds};
 use white_whale::pool_network::pair::{
     FeatureToggle, InstantiateMsg as PairInstantiateMsg, MigrateMsg as PairMigrateMsg, PoolFee,
 };
-use white_whale::pool_network::querier::query_balance;
+use white_whale::pool_network::querier::{query_balance, query_pair_info_from_pair};
 
 use crate::error::ContractError;
 use crate::state::{
-    add_allow_native_token, pair_key, Config, TmpPairInfo, CONFIG, PAIRS, TMP_PAIR_INFO,
+    add_allow_native_token, pair_key, pairs, read_pairs, read_pairs_by_asset, read_pairs_by_type,
+    Config, TmpPairInfo, CONFIG, DEFAULT_POOL_FEES, DEPRECATED_PAIRS, PAIR_CODE_IDS,
+    TMP_MIGRATING_PAIR, TMP_PAIR_INFO,
 };
 
+pub const CREATE_PAIR_REPLY_ID: u64 = 1;
+pub const MIGRATE_TO_TOKEN_FACTORY_LP_REPLY_ID: u64 = 2;
+pub const MIGRATE_PAIR_REPLY_ID: u64 = 3;
+
 /// Updates the contract's [Config]
 pub fn update_config(
     deps: DepsMut,
@@ -78,12 +87,58 @@ pub fn create_pair(
     env: Env,
     info: MessageInfo,
     asset_infos: [AssetInfo; 2],
-    pool_fees: PoolFee,
+    pool_fees: Option<PoolFee>,
     pair_type: PairType,
     token_factory_lp: bool,
 ) -> Result<Response, ContractError> {
     let config: Config = CONFIG.load(deps.storage)?;
 
+    let pool_fees = match pool_fees {
+        Some(pool_fees) => pool_fees,
+        None => DEFAULT_POOL_FEES
+            .may_load(deps.storage, pair_type.get_label())?
+            .ok_or_else(|| ContractError::NoPoolFees {
+                pair_type: pair_type.get_label().to_string(),
+            })?,
+    };
+
+    // third parties pay the pair creation fee, and are restricted to the allowed pair types and
+    // pool fees
+    let mut funds = info.funds;
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if deps.api.addr_canonicalize(info.sender.as_str())? != config.owner {
+        let Some(pair_creation_fee) = config.pair_creation_fee.clone() else {
+            return Err(ContractError::Unauthorized {});
+        };
+
+        if let Some(allowed_pair_types) = &config.allowed_pair_types {
+            if !allowed_pair_types
+                .iter()
+                .any(|allowed_pair_type| allowed_pair_type.get_label() == pair_type.get_label())
+            {
+                return Err(ContractError::PairTypeNotAllowed {
+                    pair_type: pair_type.get_label().to_string(),
+                });
+            }
+        }
+
+        if let Some(pool_fee_bounds) = &config.pool_fee_bounds {
+            if !pool_fee_bounds.contains(&pool_fees) {
+                return Err(ContractError::PoolFeesOutOfBounds {});
+            }
+        }
+
+        // there is nothing to collect if the creation fee is zero
+        if !pair_creation_fee.amount.is_zero() {
+            messages.push(collect_pair_creation_fee(
+                &config,
+                pair_creation_fee,
+                &info.sender,
+                &mut funds,
+            )?);
+        }
+    }
+
     if asset_infos[0] == asset_infos[1] {
         return Err(ContractError::SameAsset {});
     }
@@ -116,7 +171,7 @@ pub fn create_pair(
     let asset_decimals = [asset_1_decimal, asset_2_decimal];
 
     let pair_key = pair_key(&raw_infos);
-    if let Ok(Some(_)) = PAIRS.may_load(deps.storage, &pair_key) {
+    if let Ok(Some(_)) = pairs().may_load(deps.storage, &pair_key) {
         return Err(ContractError::ExistingPair {});
     }
 
@@ -136,6 +191,7 @@ pub fn create_pair(
     let pair_label = format!("{asset0_label}-{asset1_label} pair");
 
     Ok(Response::new()
+        .add_messages(messages)
         .add_attributes(vec![
             ("action", "create_pair"),
             ("pair", &format!("{asset0_label}-{asset1_label}")),
@@ -143,11 +199,11 @@ pub fn create_pair(
             ("pair_type", pair_type.get_label()),
         ])
         .add_submessage(SubMsg {
-            id: 1,
+            id: CREATE_PAIR_REPLY_ID,
             gas_limit: None,
             msg: CosmosMsg::Wasm(WasmMsg::Instantiate {
                 code_id: config.pair_code_id,
-                funds: info.funds,
+                funds,
                 admin: Some(env.contract.address.to_string()),
                 label: pair_label,
                 msg: to_binary(&PairInstantiateMsg {
@@ -164,34 +220,390 @@ pub fn create_pair(
         }))
 }
 
+/// Checks the pair creation fee was paid and builds the message sending it to the fee collector.
+/// Native fees are deducted from the funds sent along with the message, which are forwarded to the
+/// pair, while cw20 fees are taken from the sender using its allowance.
+fn collect_pair_creation_fee(
+    config: &Config,
+    pair_creation_fee: Asset,
+    sender: &Addr,
+    funds: &mut Vec<Coin>,
+) -> Result<CosmosMsg, ContractError> {
+    match &pair_creation_fee.info {
+        AssetInfo::NativeToken { denom } => {
+            match funds.iter_mut().find(|coin| coin.denom == *denom) {
+                Some(coin) if coin.amount >= pair_creation_fee.amount => {
+                    coin.amount -= pair_creation_fee.amount;
+                }
+                paid_fee => {
+                    return Err(ContractError::InvalidPairCreationFee {
+                        amount: paid_fee.map_or(Uint128::zero(), |coin| coin.amount),
+                        expected: pair_creation_fee.amount,
+                    });
+                }
+            }
+            funds.retain(|coin| !coin.amount.is_zero());
+
+            Ok(pair_creation_fee.into_msg(config.fee_collector_addr.clone())?)
+        }
+        AssetInfo::Token { contract_addr } => Ok(wasm_execute(
+            contract_addr,
+            &Cw20ExecuteMsg::TransferFrom {
+                owner: sender.to_string(),
+                recipient: config.fee_collector_addr.to_string(),
+                amount: pair_creation_fee.amount,
+            },
+            vec![],
+        )?
+        .into()),
+    }
+}
+
+/// Sets or removes the default pool fees of the given pair type
+pub fn update_default_pool_fees(
+    deps: DepsMut,
+    pair_type: PairType,
+    pool_fees: Option<PoolFee>,
+) -> Result<Response, ContractError> {
+    match pool_fees {
+        Some(pool_fees) => {
+            pool_fees.is_valid()?;
+            DEFAULT_POOL_FEES.save(deps.storage, pair_type.get_label(), &pool_fees)?;
+        }
+        None => DEFAULT_POOL_FEES.remove(deps.storage, pair_type.get_label()),
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_default_pool_fees"),
+        ("pair_type", pair_type.get_label()),
+    ]))
+}
+
+/// Updates the pool fees of a page of the pairs matching the filter
+pub fn bulk_update_pool_fees(
+    deps: DepsMut,
+    filter: PairsFilter,
+    pool_fees: PoolFee,
+    start_after: Option<[AssetInfo; 2]>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    pool_fees.is_valid()?;
+
+    let start_after = match start_after {
+        Some(start_after) => Some([
+            start_after[0].to_raw(deps.api)?,
+            start_after[1].to_raw(deps.api)?,
+        ]),
+        None => None,
+    };
+
+    let pairs = match (filter.asset_info, &filter.pair_type) {
+        (Some(asset_info), _) => read_pairs_by_asset(
+            deps.storage,
+            deps.api,
+            asset_info.to_raw(deps.api)?,
+            start_after,
+            limit,
+        )?,
+        (None, Some(pair_type)) => read_pairs_by_type(
+            deps.storage,
+            deps.api,
+            pair_type.clone(),
+            start_after,
+            limit,
+        )?,
+        (None, None) => read_pairs(deps.storage, deps.api, start_after, limit)?,
+    };
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut attributes = vec![("action", "bulk_update_pool_fees".to_string())];
+    for pair in pairs {
+        if let Some(pair_type) = &filter.pair_type {
+            if pair.pair_type.get_label() != pair_type.get_label() {
+                continue;
+            }
+        }
+
+        messages.push(
+            wasm_execute(
+                pair.contract_addr.clone(),
+                &pool_network::pair::ExecuteMsg::UpdateConfig {
+                    owner: None,
+                    fee_collector_addr: None,
+                    pool_fees: Some(pool_fees.clone()),
+                    feature_toggle: None,
+                },
+                vec![],
+            )?
+            .into(),
+        );
+        attributes.push(("pair", pair.contract_addr));
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attributes(attributes))
+}
+
+/// Updates the settings for permissionless pair creation
+pub fn update_pair_creation_config(
+    deps: DepsMut,
+    pair_creation_fee: Option<Asset>,
+    pool_fee_bounds: Option<PoolFeeBounds>,
+    allowed_pair_types: Option<Vec<PairType>>,
+) -> Result<Response, ContractError> {
+    if let Some(pool_fee_bounds) = &pool_fee_bounds {
+        pool_fee_bounds.max.is_valid()?;
+
+        if !pool_fee_bounds.contains(&pool_fee_bounds.min) {
+            return Err(ContractError::InvalidPoolFeeBounds {});
+        }
+    }
+
+    let pair_creation_fee_attr = pair_creation_fee
+        .as_ref()
+        .map_or_else(|| "None".to_string(), |fee| fee.to_string());
+
+    let mut config: Config = CONFIG.load(deps.storage)?;
+    config.pair_creation_fee = pair_creation_fee;
+    config.pool_fee_bounds = pool_fee_bounds;
+    config.allowed_pair_types = allowed_pair_types;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_pair_creation_config"),
+        ("pair_creation_fee", &pair_creation_fee_attr),
+    ]))
+}
+
+/// Disables deposits and swaps on a pair while keeping withdrawals open, so it can be removed
+pub fn deprecate_pair(
+    deps: DepsMut,
+    asset_infos: [AssetInfo; 2],
+) -> Result<Response, ContractError> {
+    let pair = load_pair(deps.as_ref(), &asset_infos)?;
+    let pair_addr = deps.api.addr_humanize(&pair.contract_addr)?;
+
+    Ok(Response::new()
+        .add_message(wasm_execute(
+            pair_addr.to_string(),
+            &pool_network::pair::ExecuteMsg::UpdateConfig {
+                owner: None,
+                fee_collector_addr: None,
+                pool_fees: None,
+                feature_toggle: Some(FeatureToggle {
+                    withdrawals_enabled: true,
+                    deposits_enabled: false,
+                    swaps_enabled: false,
+                }),
+            },
+            vec![],
+        )?)
+        .add_attributes(vec![
+            ("action", "deprecate_pair"),
+            ("pair_contract_addr", pair_addr.as_str()),
+        ]))
+}
+
+/// Removes a deprecated pair from the factory, recording it in [DEPRECATED_PAIRS]
 pub fn remove_pair(
     deps: DepsMut,
     _env: Env,
     asset_infos: [AssetInfo; 2],
 ) -> Result<Response, ContractError> {
+    let pair = load_pair(deps.as_ref(), &asset_infos)?;
+    let pair_addr = deps.api.addr_humanize(&pair.contract_addr)?;
+
+    // liquidity providers must not be able to add funds to, or trade against, a pair that is no
+    // longer listed
+    let pair_config: pool_network::pair::ConfigResponse = deps
+        .querier
+        .query_wasm_smart(&pair_addr, &pool_network::pair::QueryMsg::Config {})?;
+    if pair_config.feature_toggle.deposits_enabled || pair_config.feature_toggle.swaps_enabled {
+        return Err(ContractError::PairNotDeprecated {});
+    }
+
+    pairs().remove(deps.storage, &pair_key(&pair.asset_infos))?;
+    PAIR_CODE_IDS.remove(deps.storage, &pair_addr);
+    DEPRECATED_PAIRS.save(deps.storage, &pair_addr, &pair)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "remove_pair"),
+        ("pair_contract_addr", pair_addr.as_str()),
+    ]))
+}
+
+/// Registers an existing pair contract on the factory. If the pair was removed from the factory,
+/// its deposits and swaps are enabled again.
+pub fn register_existing_pair(
+    deps: DepsMut,
+    pair_address: String,
+) -> Result<Response, ContractError> {
+    let pair_addr = deps.api.addr_validate(&pair_address)?;
+    let pair_info = query_pair_info_from_pair(&deps.querier, pair_addr.clone())?;
+
     let raw_infos = [
-        asset_infos[0].to_raw(deps.api)?,
-        asset_infos[1].to_raw(deps.api)?,
+        pair_info.asset_infos[0].to_raw(deps.api)?,
+        pair_info.asset_infos[1].to_raw(deps.api)?,
     ];
-
     let pair_key = pair_key(&raw_infos);
-    let pair = PAIRS.may_load(deps.storage, &pair_key)?;
+    if pairs().has(deps.storage, &pair_key) {
+        return Err(ContractError::ExistingPair {});
+    }
 
-    let Some(pair) = pair else {
-        return Err(ContractError::UnExistingPair {});
-    };
+    pairs().save(
+        deps.storage,
+        &pair_key,
+        &PairInfoRaw {
+            liquidity_token: pair_info.liquidity_token.to_raw(deps.api)?,
+            contract_addr: deps.api.addr_canonicalize(pair_addr.as_str())?,
+            asset_infos: raw_infos,
+            asset_decimals: pair_info.asset_decimals,
+            pair_type: pair_info.pair_type,
+            created_at: pair_info.created_at,
+        },
+    )?;
+
+    // a pair removed from the factory is re-listed with deposits and swaps enabled again
+    let mut messages = vec![];
+    if DEPRECATED_PAIRS.has(deps.storage, &pair_addr) {
+        DEPRECATED_PAIRS.remove(deps.storage, &pair_addr);
+        messages.push(wasm_execute(
+            pair_addr.to_string(),
+            &pool_network::pair::ExecuteMsg::UpdateConfig {
+                owner: None,
+                fee_collector_addr: None,
+                pool_fees: None,
+                feature_toggle: Some(FeatureToggle {
+                    withdrawals_enabled: true,
+                    deposits_enabled: true,
+                    swaps_enabled: true,
+                }),
+            },
+            vec![],
+        )?);
+    }
+
+    let contract_info: ContractInfoResponse =
+        deps.querier
+            .query(&QueryRequest::Wasm(WasmQuery::ContractInfo {
+                contract_addr: pair_addr.to_string(),
+            }))?;
+    PAIR_CODE_IDS.save(deps.storage, &pair_addr, &contract_info.code_id)?;
 
-    PAIRS.remove(deps.storage, &pair_key);
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "register_existing_pair"),
+        ("pair_contract_addr", pair_addr.as_str()),
+        ("code_id", &contract_info.code_id.to_string()),
+    ]))
+}
+
+/// Migrates the cw20 LP token of a pair to a token factory LP. The liquidity token of the pair is
+/// updated on reply, once the pair has created the new denom.
+pub fn migrate_to_token_factory_lp(
+    deps: DepsMut,
+    asset_infos: [AssetInfo; 2],
+) -> Result<Response, ContractError> {
+    let pair = load_pair(deps.as_ref(), &asset_infos)?;
+    let pair_addr = deps.api.addr_humanize(&pair.contract_addr)?;
+
+    TMP_MIGRATING_PAIR.save(deps.storage, &pair_addr)?;
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(
+            wasm_execute(
+                pair_addr.to_string(),
+                &pool_network::pair::ExecuteMsg::MigrateToTokenFactoryLp {},
+                vec![],
+            )?,
+            MIGRATE_TO_TOKEN_FACTORY_LP_REPLY_ID,
+        ))
+        .add_attributes(vec![
+            ("action", "migrate_to_token_factory_lp"),
+            ("pair_contract_addr", pair_addr.as_str()),
+        ]))
+}
+
+/// Updates the liquidity token of the pair migrated to a token factory LP
+pub fn update_migrated_pair_liquidity_token(deps: DepsMut) -> Result<Response, ContractError> {
+    let pair_addr = TMP_MIGRATING_PAIR.load(deps.storage)?;
+    TMP_MIGRATING_PAIR.remove(deps.storage);
+
+    update_pair_liquidity_token(deps, pair_addr, false)
+}
+
+/// Updates the liquidity token of a pair after its contract was migrated, as pairs move to a token
+/// factory LP when migrating to v1.4.0
+pub fn update_upgraded_pair_liquidity_token(
+    deps: DepsMut,
+    msg: Reply,
+) -> Result<Response, ContractError> {
+    let pair_addr = msg
+        .result
+        .into_result()
+        .map_err(StdError::generic_err)?
+        .events
+        .into_iter()
+        .filter(|event| event.ty == "migrate")
+        .flat_map(|event| event.attributes)
+        .find(|attribute| attribute.key == "_contract_address")
+        .ok_or_else(|| StdError::generic_err("migrated pair address not found"))?
+        .value;
+
+    let pair_addr = deps.api.addr_validate(&pair_addr)?;
+
+    update_pair_liquidity_token(deps, pair_addr, true)
+}
+
+/// Updates the liquidity token of a pair on the factory with the one reported by the pair. If
+/// `skip_unlisted` is set, pairs that are not listed on the factory are ignored.
+fn update_pair_liquidity_token(
+    deps: DepsMut,
+    pair_addr: Addr,
+    skip_unlisted: bool,
+) -> Result<Response, ContractError> {
+    let pair_info = query_pair_info_from_pair(&deps.querier, pair_addr.clone())?;
+    let raw_infos = [
+        pair_info.asset_infos[0].to_raw(deps.api)?,
+        pair_info.asset_infos[1].to_raw(deps.api)?,
+    ];
+
+    let pair_contract_addr = deps.api.addr_canonicalize(pair_addr.as_str())?;
+    let is_listed = pairs()
+        .may_load(deps.storage, &pair_key(&raw_infos))?
+        .is_some_and(|pair| pair.contract_addr == pair_contract_addr);
+    if skip_unlisted && !is_listed {
+        return Ok(Response::default());
+    }
+
+    let liquidity_token = pair_info.liquidity_token.to_raw(deps.api)?;
+    pairs().update(
+        deps.storage,
+        &pair_key(&raw_infos),
+        |pair| -> Result<_, ContractError> {
+            let mut pair = pair.ok_or(ContractError::UnExistingPair {})?;
+            pair.liquidity_token = liquidity_token;
+            Ok(pair)
+        },
+    )?;
 
     Ok(Response::new().add_attributes(vec![
-        ("action", "remove_pair"),
-        (
-            "pair_contract_addr",
-            deps.api.addr_humanize(&pair.contract_addr)?.as_ref(),
-        ),
+        ("pair_contract_addr", pair_addr.to_string()),
+        ("liquidity_token", pair_info.liquidity_token.to_string()),
     ]))
 }
 
+fn load_pair(deps: Deps, asset_infos: &[AssetInfo; 2]) -> Result<PairInfoRaw, ContractError> {
+    let raw_infos = [
+        asset_infos[0].to_raw(deps.api)?,
+        asset_infos[1].to_raw(deps.api)?,
+    ];
+
+    pairs()
+        .may_load(deps.storage, &pair_key(&raw_infos))?
+        .ok_or(ContractError::UnExistingPair {})
+}
+
 /// Adds native/ibc token with decimals to the factory's whitelist so it can create pairs with that asset
 pub fn add_native_token_decimals(
     deps: DepsMut,
@@ -221,11 +633,99 @@ pub fn execute_migrate_pair(
     let config: Config = CONFIG.load(deps.storage)?;
     let code_id = code_id.unwrap_or(config.pair_code_id);
 
-    Ok(
-        Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Migrate {
+    // keep track of the code_id of the pairs created by the factory
+    let contract_addr = deps.api.addr_validate(&contract)?;
+    if PAIR_CODE_IDS.has(deps.storage, &contract_addr) {
+        PAIR_CODE_IDS.save(deps.storage, &contract_addr, &code_id)?;
+    }
+
+    Ok(Response::new().add_submessage(migrate_pair_msg(contract, code_id)?))
+}
+
+/// Migrates a page of the pairs created by the factory to the given code_id, skipping the pairs
+/// already on it. The pairs of the page are re-saved so they get indexed by asset and pair type,
+/// and the code_id of the pairs that don't have one recorded is backfilled from the chain.
+/// The asset infos of the last pair scanned are returned as data, to be used as the cursor of the
+/// next page. No data is returned once all the pairs have been scanned.
+pub fn execute_migrate_pairs(
+    deps: DepsMut,
+    code_id: Option<u64>,
+    start_after: Option<[AssetInfo; 2]>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    let code_id = code_id.unwrap_or(config.pair_code_id);
+
+    let start_after = match start_after {
+        Some(start_after) => Some([
+            start_after[0].to_raw(deps.api)?,
+            start_after[1].to_raw(deps.api)?,
+        ]),
+        None => None,
+    };
+
+    let mut messages = vec![];
+    let mut attributes = vec![
+        ("action", "migrate_pairs".to_string()),
+        ("code_id", code_id.to_string()),
+    ];
+    let mut last_asset_infos = None;
+    for pair in read_pairs(deps.storage, deps.api, start_after, limit)? {
+        // re-save the pair to populate the indexes of the pairs created before they were added
+        let pair_key = pair_key(&[
+            pair.asset_infos[0].to_raw(deps.api)?,
+            pair.asset_infos[1].to_raw(deps.api)?,
+        ]);
+        let pair_raw = pairs().load(deps.storage, &pair_key)?;
+        pairs().save(deps.storage, &pair_key, &pair_raw)?;
+        last_asset_infos = Some(pair.asset_infos.clone());
+
+        let contract_addr = deps.api.addr_validate(&pair.contract_addr)?;
+        let current_code_id = match PAIR_CODE_IDS.may_load(deps.storage, &contract_addr)? {
+            Some(current_code_id) => current_code_id,
+            None => {
+                let contract_info: ContractInfoResponse =
+                    deps.querier
+                        .query(&QueryRequest::Wasm(WasmQuery::ContractInfo {
+                            contract_addr: contract_addr.to_string(),
+                        }))?;
+                PAIR_CODE_IDS.save(deps.storage, &contract_addr, &contract_info.code_id)?;
+                contract_info.code_id
+            }
+        };
+        if current_code_id == code_id {
+            continue;
+        }
+
+        PAIR_CODE_IDS.save(deps.storage, &contract_addr, &code_id)?;
+        messages.push(migrate_pair_msg(pair.contract_addr.clone(), code_id)?);
+        attributes.push(("pair", pair.contract_addr));
+    }
+
+    let mut response = Response::new()
+        .add_submessages(messages)
+        .add_attributes(attributes);
+    if let Some(last_asset_infos) = last_asset_infos {
+        response = response
+            .add_attribute(
+                "last_pair",
+                format!("{}-{}", last_asset_infos[0], last_asset_infos[1]),
+            )
+            .set_data(to_binary(&last_asset_infos)?);
+    }
+
+    Ok(response)
+}
+
+/// Creates the message migrating the given pair to a code_id. The liquidity token of the pair is
+/// updated on reply, as it can change when migrating.
+fn migrate_pair_msg(contract: String, code_id: u64) -> Result<SubMsg, ContractError> {
+    Ok(SubMsg::reply_on_success(
+        CosmosMsg::Wasm(WasmMsg::Migrate {
             contract_addr: contract,
             new_code_id: code_id,
             msg: to_binary(&PairMigrateMsg {})?,
-        })),
-    )
+        }),
+        MIGRATE_PAIR_REPLY_ID,
+    ))
 }
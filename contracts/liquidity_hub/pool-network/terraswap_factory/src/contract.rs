@@ -7,14 +7,14 @@ use cw2::{get_contract_version, set_contract_version};
 use protobuf::Message;
 
 use semver::Version;
-use white_whale::pool_network::asset::PairInfoRaw;
+use white_whale::pool_network::asset::{CreationBlock, PairInfoRaw};
 use white_whale::pool_network::factory::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use white_whale::pool_network::querier::query_pair_info_from_pair;
 
 use crate::error::ContractError;
 use crate::error::ContractError::MigrateInvalidVersion;
 use crate::response::MsgInstantiateContractResponse;
-use crate::state::{Config, CONFIG, PAIRS, TMP_PAIR_INFO};
+use crate::state::{pairs, Config, CONFIG, PAIR_CODE_IDS, TMP_PAIR_INFO};
 use crate::{commands, queries};
 
 // version info for migration info
@@ -35,6 +35,9 @@ pub fn instantiate(
         token_code_id: msg.token_code_id,
         pair_code_id: msg.pair_code_id,
         fee_collector_addr: deps.api.addr_validate(msg.fee_collector_addr.as_str())?,
+        pair_creation_fee: None,
+        pool_fee_bounds: None,
+        allowed_pair_types: None,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -49,9 +52,12 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // Only the owner can execute messages on the factory
+    // Only the owner can execute messages on the factory, except for creating pairs which is
+    // checked against the pair creation settings
     let config: Config = CONFIG.load(deps.storage)?;
-    if deps.api.addr_canonicalize(info.sender.as_str())? != config.owner {
+    if deps.api.addr_canonicalize(info.sender.as_str())? != config.owner
+        && !matches!(msg, ExecuteMsg::CreatePair { .. })
+    {
         return Err(ContractError::Unauthorized {});
     }
 
@@ -76,13 +82,25 @@ pub fn execute(
             pair_type,
             token_factory_lp,
         ),
+        ExecuteMsg::DeprecatePair { asset_infos } => commands::deprecate_pair(deps, asset_infos),
         ExecuteMsg::RemovePair { asset_infos } => commands::remove_pair(deps, env, asset_infos),
+        ExecuteMsg::RegisterExistingPair { pair_address } => {
+            commands::register_existing_pair(deps, pair_address)
+        }
+        ExecuteMsg::MigrateToTokenFactoryLp { asset_infos } => {
+            commands::migrate_to_token_factory_lp(deps, asset_infos)
+        }
         ExecuteMsg::AddNativeTokenDecimals { denom, decimals } => {
             commands::add_native_token_decimals(deps, env, denom, decimals)
         }
         ExecuteMsg::MigratePair { contract, code_id } => {
             commands::execute_migrate_pair(deps, contract, code_id)
         }
+        ExecuteMsg::MigratePairs {
+            code_id,
+            start_after,
+            limit,
+        } => commands::execute_migrate_pairs(deps, code_id, start_after, limit),
         ExecuteMsg::UpdatePairConfig {
             pair_addr,
             owner,
@@ -97,12 +115,39 @@ pub fn execute(
             pool_fees,
             feature_toggle,
         ),
+        ExecuteMsg::UpdateDefaultPoolFees {
+            pair_type,
+            pool_fees,
+        } => commands::update_default_pool_fees(deps, pair_type, pool_fees),
+        ExecuteMsg::BulkUpdatePoolFees {
+            filter,
+            pool_fees,
+            start_after,
+            limit,
+        } => commands::bulk_update_pool_fees(deps, filter, pool_fees, start_after, limit),
+        ExecuteMsg::UpdatePairCreationConfig {
+            pair_creation_fee,
+            pool_fee_bounds,
+            allowed_pair_types,
+        } => commands::update_pair_creation_config(
+            deps,
+            pair_creation_fee,
+            pool_fee_bounds,
+            allowed_pair_types,
+        ),
     }
 }
 
 /// This just stores the result for future query
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    if msg.id == commands::MIGRATE_TO_TOKEN_FACTORY_LP_REPLY_ID {
+        return commands::update_migrated_pair_liquidity_token(deps);
+    }
+    if msg.id == commands::MIGRATE_PAIR_REPLY_ID {
+        return commands::update_upgraded_pair_liquidity_token(deps, msg);
+    }
+
     let tmp_pair_info = TMP_PAIR_INFO.load(deps.storage)?;
 
     let res: MsgInstantiateContractResponse =
@@ -113,7 +158,10 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     let pair_contract = deps.api.addr_validate(&res.address)?;
     let pair_info = query_pair_info_from_pair(&deps.querier, pair_contract.clone())?;
 
-    PAIRS.save(
+    let config = CONFIG.load(deps.storage)?;
+    PAIR_CODE_IDS.save(deps.storage, &pair_contract, &config.pair_code_id)?;
+
+    pairs().save(
         deps.storage,
         &tmp_pair_info.pair_key,
         &PairInfoRaw {
@@ -122,6 +170,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             asset_infos: tmp_pair_info.asset_infos,
             asset_decimals: tmp_pair_info.asset_decimals,
             pair_type: tmp_pair_info.pair_type,
+            created_at: Some(CreationBlock::from(&env.block)),
         },
     )?;
 
@@ -142,6 +191,40 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Pairs { start_after, limit } => {
             to_binary(&queries::query_pairs(deps, start_after, limit)?)
         }
+        QueryMsg::PairsByAsset {
+            asset_info,
+            start_after,
+            limit,
+        } => to_binary(&queries::query_pairs_by_asset(
+            deps,
+            asset_info,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::PairsByType {
+            pair_type,
+            start_after,
+            limit,
+        } => to_binary(&queries::query_pairs_by_type(
+            deps,
+            pair_type,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::PairCodeIds {
+            start_after,
+            limit,
+            outdated_only,
+        } => to_binary(&queries::query_pair_code_ids(
+            deps,
+            start_after,
+            limit,
+            outdated_only,
+        )?),
+        QueryMsg::DeprecatedPairs { start_after, limit } => {
+            to_binary(&queries::query_deprecated_pairs(deps, start_after, limit)?)
+        }
+        QueryMsg::DefaultPoolFees {} => to_binary(&queries::query_default_pool_fees(deps)?),
         QueryMsg::NativeTokenDecimals { denom } => {
             to_binary(&queries::query_native_token_decimal(deps, denom)?)
         }
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use semver::Version;
 use thiserror::Error;
 
@@ -28,11 +28,31 @@ pub enum ContractError {
     #[error("Pair doesn't exist")]
     UnExistingPair {},
 
+    #[error(
+        "The pair must be deprecated before being removed, i.e. its deposits and swaps disabled"
+    )]
+    PairNotDeprecated {},
+
     #[error("A balance greater than zero is required by the factory to verify the asset")]
     InvalidVerificationBalance {},
 
     #[error("Unauthorized")]
     Unauthorized {},
+
+    #[error("The pair creation fee was not paid, expected {expected} but got {amount}")]
+    InvalidPairCreationFee { amount: Uint128, expected: Uint128 },
+
+    #[error("The pair type {pair_type} is not allowed")]
+    PairTypeNotAllowed { pair_type: String },
+
+    #[error("The pool fees are not within the bounds set by the factory")]
+    PoolFeesOutOfBounds {},
+
+    #[error("The minimum pool fees can't be greater than the maximum pool fees")]
+    InvalidPoolFeeBounds {},
+
+    #[error("No pool fees were provided and there are no default pool fees for {pair_type} pairs")]
+    NoPoolFees { pair_type: String },
 }
 
 impl From<semver::Error> for ContractError {
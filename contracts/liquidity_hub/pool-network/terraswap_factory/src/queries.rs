@@ -1,8 +1,12 @@
-use crate::state::{pair_key, read_pairs, Config, ALLOW_NATIVE_TOKENS, CONFIG, PAIRS};
-use cosmwasm_std::{Deps, StdResult};
-use white_whale::pool_network::asset::{AssetInfo, PairInfo, PairInfoRaw};
+use crate::state::{
+    pair_key, pairs, read_deprecated_pairs, read_pair_code_ids, read_pairs, read_pairs_by_asset,
+    read_pairs_by_type, Config, ALLOW_NATIVE_TOKENS, CONFIG, DEFAULT_POOL_FEES,
+};
+use cosmwasm_std::{Deps, Order, StdResult};
+use white_whale::pool_network::asset::{AssetInfo, AssetInfoRaw, PairInfo, PairInfoRaw, PairType};
 use white_whale::pool_network::factory::{
-    ConfigResponse, NativeTokenDecimalsResponse, PairsResponse,
+    ConfigResponse, DefaultPoolFees, DefaultPoolFeesResponse, NativeTokenDecimalsResponse,
+    PairCodeIdsResponse, PairsResponse,
 };
 
 /// Queries [Config]
@@ -13,6 +17,9 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         token_code_id: config.token_code_id,
         pair_code_id: config.pair_code_id,
         fee_collector_addr: config.fee_collector_addr.to_string(),
+        pair_creation_fee: config.pair_creation_fee,
+        pool_fee_bounds: config.pool_fee_bounds,
+        allowed_pair_types: config.allowed_pair_types,
     };
 
     Ok(resp)
@@ -24,7 +31,7 @@ pub fn query_pair(deps: Deps, asset_infos: [AssetInfo; 2]) -> StdResult<PairInfo
         asset_infos[0].to_raw(deps.api)?,
         asset_infos[1].to_raw(deps.api)?,
     ]);
-    let pair_info: PairInfoRaw = PAIRS.load(deps.storage, &pair_key)?;
+    let pair_info: PairInfoRaw = pairs().load(deps.storage, &pair_key)?;
     pair_info.to_normal(deps.api)
 }
 
@@ -49,6 +56,113 @@ pub fn query_pairs(
     Ok(resp)
 }
 
+/// Queries the pairs containing the given asset
+pub fn query_pairs_by_asset(
+    deps: Deps,
+    asset_info: AssetInfo,
+    start_after: Option<[AssetInfo; 2]>,
+    limit: Option<u32>,
+) -> StdResult<PairsResponse> {
+    let pairs = read_pairs_by_asset(
+        deps.storage,
+        deps.api,
+        asset_info.to_raw(deps.api)?,
+        start_after_to_raw(deps, start_after)?,
+        limit,
+    )?;
+
+    Ok(PairsResponse { pairs })
+}
+
+/// Queries the pairs of the given type
+pub fn query_pairs_by_type(
+    deps: Deps,
+    pair_type: PairType,
+    start_after: Option<[AssetInfo; 2]>,
+    limit: Option<u32>,
+) -> StdResult<PairsResponse> {
+    let pairs = read_pairs_by_type(
+        deps.storage,
+        deps.api,
+        pair_type,
+        start_after_to_raw(deps, start_after)?,
+        limit,
+    )?;
+
+    Ok(PairsResponse { pairs })
+}
+
+/// Queries the code_id each pair is running
+pub fn query_pair_code_ids(
+    deps: Deps,
+    start_after: Option<[AssetInfo; 2]>,
+    limit: Option<u32>,
+    outdated_only: Option<bool>,
+) -> StdResult<PairCodeIdsResponse> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    let outdated_code_id = outdated_only
+        .unwrap_or_default()
+        .then_some(config.pair_code_id);
+
+    let pairs = read_pair_code_ids(
+        deps.storage,
+        deps.api,
+        start_after_to_raw(deps, start_after)?,
+        limit,
+        outdated_code_id,
+    )?;
+
+    Ok(PairCodeIdsResponse {
+        pair_code_id: config.pair_code_id,
+        pairs,
+    })
+}
+
+/// Queries the pairs removed from the factory
+pub fn query_deprecated_pairs(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<PairsResponse> {
+    let start_after = start_after
+        .map(|start_after| deps.api.addr_validate(&start_after))
+        .transpose()?;
+
+    Ok(PairsResponse {
+        pairs: read_deprecated_pairs(deps.storage, deps.api, start_after, limit)?,
+    })
+}
+
+/// Queries the default pool fees of each pair type
+pub fn query_default_pool_fees(deps: Deps) -> StdResult<DefaultPoolFeesResponse> {
+    let default_pool_fees = DEFAULT_POOL_FEES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (pair_type, pool_fees) = item?;
+            Ok(DefaultPoolFees {
+                pair_type,
+                pool_fees,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(DefaultPoolFeesResponse { default_pool_fees })
+}
+
+fn start_after_to_raw(
+    deps: Deps,
+    start_after: Option<[AssetInfo; 2]>,
+) -> StdResult<Option<[AssetInfoRaw; 2]>> {
+    start_after
+        .map(|start_after| {
+            Ok([
+                start_after[0].to_raw(deps.api)?,
+                start_after[1].to_raw(deps.api)?,
+            ])
+        })
+        .transpose()
+}
+
 /// Query the native token decimals
 pub fn query_native_token_decimal(
     deps: Deps,
@@ -1,7 +1,9 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Api, CanonicalAddr, Order, StdResult, Storage};
-use cw_storage_plus::{Bound, Item, Map};
-use white_whale::pool_network::asset::{AssetInfoRaw, PairInfo, PairInfoRaw, PairType};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use white_whale::pool_network::asset::{Asset, AssetInfoRaw, PairInfo, PairInfoRaw, PairType};
+use white_whale::pool_network::factory::{PairCodeId, PoolFeeBounds};
+use white_whale::pool_network::pair::PoolFee;
 
 #[cw_serde]
 pub struct Config {
@@ -9,6 +11,13 @@ pub struct Config {
     pub fee_collector_addr: Addr,
     pub pair_code_id: u64,
     pub token_code_id: u64,
+    /// The fee paid to the fee collector by third parties creating a pair. If `None`, only the
+    /// owner can create pairs.
+    pub pair_creation_fee: Option<Asset>,
+    /// The range the pool fees of the pairs created by third parties have to fall in
+    pub pool_fee_bounds: Option<PoolFeeBounds>,
+    /// The pair types third parties can create. If `None`, all of them are allowed.
+    pub allowed_pair_types: Option<Vec<PairType>>,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -22,7 +31,55 @@ pub struct TmpPairInfo {
 }
 
 pub const TMP_PAIR_INFO: Item<TmpPairInfo> = Item::new("tmp_pair_info");
-pub const PAIRS: Map<&[u8], PairInfoRaw> = Map::new("pair_info");
+
+/// The pair being migrated to a token factory LP, whose liquidity token is updated on reply
+pub const TMP_MIGRATING_PAIR: Item<Addr> = Item::new("tmp_migrating_pair");
+
+pub struct PairIndexes<'a> {
+    pub asset_0: MultiIndex<'a, Vec<u8>, PairInfoRaw, Vec<u8>>,
+    pub asset_1: MultiIndex<'a, Vec<u8>, PairInfoRaw, Vec<u8>>,
+    pub pair_type: MultiIndex<'a, String, PairInfoRaw, Vec<u8>>,
+}
+
+impl<'a> IndexList<PairInfoRaw> for PairIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<PairInfoRaw>> + '_> {
+        let v: Vec<&dyn Index<PairInfoRaw>> = vec![&self.asset_0, &self.asset_1, &self.pair_type];
+        Box::new(v.into_iter())
+    }
+}
+
+/// The pairs created by the factory, keyed by [pair_key] and indexed by each of their assets and
+/// their [PairType] label.
+pub fn pairs<'a>() -> IndexedMap<'a, &'a [u8], PairInfoRaw, PairIndexes<'a>> {
+    let indexes = PairIndexes {
+        asset_0: MultiIndex::new(
+            |_, pair| pair.asset_infos[0].as_bytes().to_vec(),
+            "pair_info",
+            "pair_info__asset_0",
+        ),
+        asset_1: MultiIndex::new(
+            |_, pair| pair.asset_infos[1].as_bytes().to_vec(),
+            "pair_info",
+            "pair_info__asset_1",
+        ),
+        pair_type: MultiIndex::new(
+            |_, pair| pair.pair_type.get_label().to_string(),
+            "pair_info",
+            "pair_info__pair_type",
+        ),
+    };
+
+    IndexedMap::new("pair_info", indexes)
+}
+
+/// The default pool fees of the pairs, keyed by the label of their [PairType]
+pub const DEFAULT_POOL_FEES: Map<&str, PoolFee> = Map::new("default_pool_fees");
+
+/// The pairs removed from the factory, keyed by the pair contract address
+pub const DEPRECATED_PAIRS: Map<&Addr, PairInfoRaw> = Map::new("deprecated_pairs");
+
+/// The code_id each pair created by the factory is running, keyed by the pair contract address
+pub const PAIR_CODE_IDS: Map<&Addr, u64> = Map::new("pair_code_ids");
 
 pub fn pair_key(asset_infos: &[AssetInfoRaw; 2]) -> Vec<u8> {
     let mut asset_infos = asset_infos.to_vec();
@@ -43,7 +100,7 @@ pub fn read_pairs(
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
     let start = calc_range_start(start_after).map(Bound::ExclusiveRaw);
 
-    PAIRS
+    pairs()
         .range(storage, start, None, Order::Ascending)
         .take(limit)
         .map(|item| {
@@ -53,6 +110,119 @@ pub fn read_pairs(
         .collect::<StdResult<Vec<PairInfo>>>()
 }
 
+/// Reads the code_id of the pairs. If an `outdated_code_id` is provided, only the pairs running a
+/// different code_id are returned.
+pub fn read_pair_code_ids(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    start_after: Option<[AssetInfoRaw; 2]>,
+    limit: Option<u32>,
+    outdated_code_id: Option<u64>,
+) -> StdResult<Vec<PairCodeId>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = calc_range_start(start_after).map(Bound::ExclusiveRaw);
+
+    pairs()
+        .range(storage, start, None, Order::Ascending)
+        .map(|item| {
+            let (_, pair) = item?;
+            let pair = pair.to_normal(api)?;
+            let code_id = PAIR_CODE_IDS.may_load(storage, &Addr::unchecked(&pair.contract_addr))?;
+
+            Ok(PairCodeId {
+                asset_infos: pair.asset_infos,
+                contract_addr: pair.contract_addr,
+                code_id,
+            })
+        })
+        .filter(
+            |pair_code_id: &StdResult<PairCodeId>| match (pair_code_id, outdated_code_id) {
+                (Ok(pair_code_id), Some(outdated_code_id)) => {
+                    pair_code_id.code_id != Some(outdated_code_id)
+                }
+                _ => true,
+            },
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<PairCodeId>>>()
+}
+
+/// Reads the pairs containing the given asset. As a pair is indexed under each of its assets, the
+/// pages read from both asset indexes are merged in [pair_key] order.
+pub fn read_pairs_by_asset(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    asset_info: AssetInfoRaw,
+    start_after: Option<[AssetInfoRaw; 2]>,
+    limit: Option<u32>,
+) -> StdResult<Vec<PairInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|asset_infos| Bound::ExclusiveRaw(pair_key(&asset_infos)));
+
+    let pairs = pairs();
+    let mut pairs_by_asset = [&pairs.idx.asset_0, &pairs.idx.asset_1]
+        .into_iter()
+        .map(|index| {
+            index
+                .prefix(asset_info.as_bytes().to_vec())
+                .range_raw(storage, start.clone(), None, Order::Ascending)
+                .take(limit)
+                .collect::<StdResult<Vec<_>>>()
+        })
+        .collect::<StdResult<Vec<_>>>()?
+        .concat();
+    pairs_by_asset.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    pairs_by_asset
+        .into_iter()
+        .take(limit)
+        .map(|(_, pair)| pair.to_normal(api))
+        .collect::<StdResult<Vec<PairInfo>>>()
+}
+
+/// Reads the pairs of the given [PairType], matched by their label.
+pub fn read_pairs_by_type(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    pair_type: PairType,
+    start_after: Option<[AssetInfoRaw; 2]>,
+    limit: Option<u32>,
+) -> StdResult<Vec<PairInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|asset_infos| Bound::ExclusiveRaw(pair_key(&asset_infos)));
+
+    pairs()
+        .idx
+        .pair_type
+        .prefix(pair_type.get_label().to_string())
+        .range_raw(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, pair) = item?;
+            pair.to_normal(api)
+        })
+        .collect::<StdResult<Vec<PairInfo>>>()
+}
+
+pub fn read_deprecated_pairs(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<Vec<PairInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    DEPRECATED_PAIRS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, pair) = item?;
+            pair.to_normal(api)
+        })
+        .collect::<StdResult<Vec<PairInfo>>>()
+}
+
 // this will set the first key after the provided key, by appending a 1 byte
 fn calc_range_start(start_after: Option<[AssetInfoRaw; 2]>) -> Option<Vec<u8>> {
     start_after.map(|asset_infos| {
@@ -2,24 +2,30 @@ use cosmwasm_std::testing::{
     mock_dependencies_with_balance, mock_env, mock_info, MockApi, MockStorage, MOCK_CONTRACT_ADDR,
 };
 use cosmwasm_std::{
-    attr, coin, from_binary, to_binary, Api, CanonicalAddr, Coin, CosmosMsg, Decimal, OwnedDeps,
-    Reply, ReplyOn, Response, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
+    attr, coin, coins, from_binary, to_binary, Addr, Api, BankMsg, CanonicalAddr, Coin, CosmosMsg,
+    Decimal, Event, OwnedDeps, Reply, ReplyOn, Response, SubMsg, SubMsgResponse, SubMsgResult,
+    Uint128, WasmMsg,
 };
 
 use white_whale::fee::Fee;
 use white_whale::pool_network;
-use white_whale::pool_network::asset::{AssetInfo, AssetInfoRaw, PairInfo, PairInfoRaw, PairType};
+use white_whale::pool_network::asset::{
+    Asset, AssetInfo, AssetInfoRaw, CreationBlock, PairInfo, PairInfoRaw, PairType,
+};
 use white_whale::pool_network::factory::{
-    ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, NativeTokenDecimalsResponse, QueryMsg,
+    ConfigResponse, DefaultPoolFees, DefaultPoolFeesResponse, ExecuteMsg, InstantiateMsg,
+    MigrateMsg, NativeTokenDecimalsResponse, PairCodeId, PairCodeIdsResponse, PairsFilter,
+    PairsResponse, PoolFeeBounds, QueryMsg,
 };
 use white_whale::pool_network::mock_querier::{mock_dependencies, WasmMockQuerier};
 use white_whale::pool_network::pair::{
-    InstantiateMsg as PairInstantiateMsg, MigrateMsg as PairMigrateMsg, PoolFee,
+    FeatureToggle, InstantiateMsg as PairInstantiateMsg, MigrateMsg as PairMigrateMsg, PoolFee,
 };
 
+use crate::commands::MIGRATE_PAIR_REPLY_ID;
 use crate::contract::{execute, instantiate, migrate, query, reply};
 use crate::error::ContractError;
-use crate::state::{pair_key, TmpPairInfo, PAIRS, TMP_PAIR_INFO};
+use crate::state::{pair_key, pairs, TmpPairInfo, DEPRECATED_PAIRS, PAIR_CODE_IDS, TMP_PAIR_INFO};
 
 #[test]
 fn proper_initialization() {
@@ -178,7 +184,7 @@ fn create_pair() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos: asset_infos.clone(),
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -188,7 +194,7 @@ fn create_pair() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -284,7 +290,7 @@ fn create_stableswap_pair() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos: asset_infos.clone(),
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -294,7 +300,7 @@ fn create_stableswap_pair() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::StableSwap { amp: 100 },
         token_factory_lp: false,
     };
@@ -396,7 +402,7 @@ fn create_pair_native_token_and_ibc_token() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos: asset_infos.clone(),
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -406,7 +412,7 @@ fn create_pair_native_token_and_ibc_token() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -515,7 +521,7 @@ fn create_ibc_tokens_pair() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos: asset_infos.clone(),
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -525,7 +531,7 @@ fn create_ibc_tokens_pair() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -634,7 +640,7 @@ fn create_pair_ethereum_asset_and_ibc_token() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos: asset_infos.clone(),
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -644,7 +650,7 @@ fn create_pair_ethereum_asset_and_ibc_token() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -729,7 +735,7 @@ fn fail_to_create_same_pair() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos,
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -739,7 +745,7 @@ fn fail_to_create_same_pair() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -772,7 +778,7 @@ fn fail_to_create_existing_pair() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos: asset_infos.clone(),
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -782,7 +788,7 @@ fn fail_to_create_existing_pair() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -790,14 +796,14 @@ fn fail_to_create_existing_pair() {
     let env = mock_env();
     let info = mock_info("addr0000", &[]);
 
-    // inject pair into PAIRS
+    // inject pair into the pairs map
     let raw_infos = [
         asset_infos[0].to_raw(deps.as_ref().api).unwrap(),
         asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
     ];
     let pair_key = pair_key(&raw_infos);
 
-    PAIRS
+    pairs()
         .save(
             &mut deps.storage,
             &pair_key,
@@ -809,6 +815,7 @@ fn fail_to_create_existing_pair() {
                 asset_infos: raw_infos,
                 asset_decimals: [6u8, 6u8],
                 pair_type: PairType::ConstantProduct,
+                created_at: None,
             },
         )
         .unwrap();
@@ -838,7 +845,7 @@ fn fail_to_create_pair_with_inactive_denoms() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos,
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -848,7 +855,7 @@ fn fail_to_create_pair_with_inactive_denoms() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -882,7 +889,7 @@ fn fail_to_create_pair_with_invalid_denom() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos,
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -892,7 +899,7 @@ fn fail_to_create_pair_with_invalid_denom() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -918,7 +925,7 @@ fn fail_to_create_pair_with_invalid_denom() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos,
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -928,7 +935,7 @@ fn fail_to_create_pair_with_invalid_denom() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -971,7 +978,7 @@ fn fail_to_create_pair_with_unknown_token() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos,
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -981,7 +988,7 @@ fn fail_to_create_pair_with_unknown_token() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -1024,7 +1031,7 @@ fn fail_to_create_pair_with_unknown_ibc_token() {
 
     let msg = ExecuteMsg::CreatePair {
         asset_infos,
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -1034,7 +1041,7 @@ fn fail_to_create_pair_with_unknown_ibc_token() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -1053,6 +1060,7 @@ fn fail_to_create_pair_with_unknown_ibc_token() {
 #[test]
 fn reply_test() {
     let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
 
     deps.querier.with_token_balances(&[(
         &MOCK_CONTRACT_ADDR.to_string(),
@@ -1116,6 +1124,7 @@ fn reply_test() {
                 },
                 asset_decimals: [8u8, 8u8],
                 pair_type: PairType::ConstantProduct,
+                created_at: None,
             },
         )],
         &[],
@@ -1143,8 +1152,17 @@ fn reply_test() {
             asset_infos,
             asset_decimals: [8u8, 8u8],
             pair_type: PairType::ConstantProduct,
+            created_at: Some(CreationBlock::from(&mock_env().block)),
         }
     );
+
+    // the pair runs the pair code_id of the factory
+    assert_eq!(
+        PAIR_CODE_IDS
+            .load(&deps.storage, &Addr::unchecked("0000"))
+            .unwrap(),
+        321u64
+    );
 }
 
 #[test]
@@ -1286,7 +1304,7 @@ fn execute_transactions_unauthorized() {
     // Try executing ExecuteMsg::CreatePair
     let msg = ExecuteMsg::CreatePair {
         asset_infos,
-        pool_fees: PoolFee {
+        pool_fees: Some(PoolFee {
             protocol_fee: Fee {
                 share: Decimal::percent(1u64),
             },
@@ -1296,7 +1314,7 @@ fn execute_transactions_unauthorized() {
             burn_fee: Fee {
                 share: Decimal::zero(),
             },
-        },
+        }),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -1353,11 +1371,14 @@ fn normal_migrate_pair() {
 
     assert_eq!(
         execute(deps.as_mut(), mock_env(), info, msg).unwrap(),
-        Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Migrate {
-            contract_addr: "contract0000".to_string(),
-            new_code_id: 123u64,
-            msg: to_binary(&PairMigrateMsg {}).unwrap(),
-        })),
+        Response::new().add_submessage(SubMsg::reply_on_success(
+            CosmosMsg::Wasm(WasmMsg::Migrate {
+                contract_addr: "contract0000".to_string(),
+                new_code_id: 123u64,
+                msg: to_binary(&PairMigrateMsg {}).unwrap(),
+            }),
+            MIGRATE_PAIR_REPLY_ID,
+        )),
     );
 }
 
@@ -1375,11 +1396,14 @@ fn normal_migrate_pair_with_none_code_id_will_config_code_id() {
 
     assert_eq!(
         execute(deps.as_mut(), mock_env(), info, msg).unwrap(),
-        Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Migrate {
-            contract_addr: "contract0000".to_string(),
-            new_code_id: 321u64,
-            msg: to_binary(&PairMigrateMsg {}).unwrap(),
-        })),
+        Response::new().add_submessage(SubMsg::reply_on_success(
+            CosmosMsg::Wasm(WasmMsg::Migrate {
+                contract_addr: "contract0000".to_string(),
+                new_code_id: 321u64,
+                msg: to_binary(&PairMigrateMsg {}).unwrap(),
+            }),
+            MIGRATE_PAIR_REPLY_ID,
+        )),
     );
 }
 
@@ -1425,26 +1449,73 @@ fn delete_pair() {
 
     let pair_key_vec = pair_key(&raw_infos);
 
-    PAIRS
+    pairs()
         .save(
             &mut deps.storage,
             &pair_key_vec,
             &PairInfoRaw {
                 liquidity_token: AssetInfoRaw::Token {
-                    contract_addr: CanonicalAddr(cosmwasm_std::Binary(vec![])),
+                    contract_addr: deps.api.addr_canonicalize("liquidity0000").unwrap(),
                 },
                 contract_addr: deps.api.addr_canonicalize("pair0000").unwrap(),
                 asset_infos: raw_infos,
                 asset_decimals: [6, 6],
                 pair_type: PairType::ConstantProduct,
+                created_at: None,
             },
         )
         .unwrap();
 
-    let pair = PAIRS.load(&deps.storage, &pair_key_vec);
+    let pair = pairs().load(&deps.storage, &pair_key_vec);
 
     assert!(pair.is_ok(), "pair key should exist");
 
+    // the pair has to be deprecated first
+    let msg = ExecuteMsg::RemovePair {
+        asset_infos: asset_infos.clone(),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg);
+    match res {
+        Err(ContractError::PairNotDeprecated {}) => (),
+        _ => panic!("should return ContractError::PairNotDeprecated"),
+    }
+
+    let msg = ExecuteMsg::DeprecatePair {
+        asset_infos: asset_infos.clone(),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "pair0000".to_string(),
+                msg: to_binary(&pool_network::pair::ExecuteMsg::UpdateConfig {
+                    owner: None,
+                    fee_collector_addr: None,
+                    pool_fees: None,
+                    feature_toggle: Some(FeatureToggle {
+                        withdrawals_enabled: true,
+                        deposits_enabled: false,
+                        swaps_enabled: false,
+                    }),
+                })
+                .unwrap(),
+                funds: vec![],
+            }))
+            .add_attributes(vec![
+                ("action", "deprecate_pair"),
+                ("pair_contract_addr", "pair0000"),
+            ])
+    );
+    deps.querier.with_pair_feature_toggle(
+        "pair0000",
+        FeatureToggle {
+            withdrawals_enabled: true,
+            deposits_enabled: false,
+            swaps_enabled: false,
+        },
+    );
+
     let msg = ExecuteMsg::RemovePair { asset_infos };
     let env = mock_env();
     let info = mock_info("addr0000", &[]);
@@ -1458,9 +1529,119 @@ fn delete_pair() {
         ]
     );
 
-    let pair = PAIRS.load(&deps.storage, &pair_key_vec);
+    let pair = pairs().load(&deps.storage, &pair_key_vec);
 
     assert!(pair.is_err(), "pair key should not exist");
+
+    let res: PairsResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::DeprecatedPairs {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.pairs.len(), 1);
+    assert_eq!(res.pairs[0].contract_addr, "pair0000");
+}
+
+#[test]
+fn register_existing_pair() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let deprecated_pair = PairInfoRaw {
+        liquidity_token: AssetInfoRaw::Token {
+            contract_addr: deps.api.addr_canonicalize("liquidity0000").unwrap(),
+        },
+        contract_addr: deps.api.addr_canonicalize("pair0000").unwrap(),
+        asset_infos: [
+            AssetInfoRaw::NativeToken {
+                denom: "uluna".to_string(),
+            },
+            AssetInfoRaw::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        ],
+        asset_decimals: [6u8, 6u8],
+        pair_type: PairType::ConstantProduct,
+        created_at: None,
+    };
+    DEPRECATED_PAIRS
+        .save(
+            &mut deps.storage,
+            &Addr::unchecked("pair0000"),
+            &deprecated_pair,
+        )
+        .unwrap();
+
+    let msg = ExecuteMsg::RegisterExistingPair {
+        pair_address: "pair0000".to_string(),
+    };
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        msg.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "register_existing_pair"),
+            attr("pair_contract_addr", "pair0000"),
+            attr("code_id", "0"),
+        ]
+    );
+    // deposits and swaps are enabled again on the pair
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "pair0000".to_string(),
+            msg: to_binary(&pool_network::pair::ExecuteMsg::UpdateConfig {
+                owner: None,
+                fee_collector_addr: None,
+                pool_fees: None,
+                feature_toggle: Some(FeatureToggle {
+                    withdrawals_enabled: true,
+                    deposits_enabled: true,
+                    swaps_enabled: true,
+                }),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+
+    // the pair is listed again, and no longer deprecated
+    assert_eq!(
+        pairs()
+            .load(&deps.storage, &pair_key(&deprecated_pair.asset_infos))
+            .unwrap(),
+        deprecated_pair
+    );
+    assert!(!DEPRECATED_PAIRS.has(&deps.storage, &Addr::unchecked("pair0000")));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        msg.clone(),
+    );
+    match res {
+        Err(ContractError::ExistingPair {}) => (),
+        _ => panic!("should return ContractError::ExistingPair"),
+    }
+
+    let res = execute(deps.as_mut(), mock_env(), mock_info("anyone", &[]), msg);
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
 }
 
 #[test]
@@ -1544,3 +1725,1043 @@ fn update_pair_config() {
             })
     );
 }
+
+/// Lets anyone create constant product pairs with pool fees up to 1% by paying 1000uwhale
+fn enable_permissionless_pair_creation(
+    deps: &mut OwnedDeps<MockStorage, MockApi, WasmMockQuerier>,
+) {
+    let zero_fee = Fee {
+        share: Decimal::zero(),
+    };
+    let msg = ExecuteMsg::UpdatePairCreationConfig {
+        pair_creation_fee: Some(Asset {
+            info: AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            amount: Uint128::new(1_000u128),
+        }),
+        pool_fee_bounds: Some(PoolFeeBounds {
+            min: PoolFee {
+                protocol_fee: zero_fee.clone(),
+                swap_fee: zero_fee.clone(),
+                burn_fee: zero_fee,
+            },
+            max: PoolFee {
+                protocol_fee: Fee {
+                    share: Decimal::percent(1u64),
+                },
+                swap_fee: Fee {
+                    share: Decimal::percent(1u64),
+                },
+                burn_fee: Fee {
+                    share: Decimal::percent(1u64),
+                },
+            },
+        }),
+        allowed_pair_types: Some(vec![PairType::ConstantProduct]),
+    };
+
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "update_pair_creation_config"),
+            attr("pair_creation_fee", "1000uwhale"),
+        ]
+    );
+}
+
+fn create_pair_msg(pair_type: PairType, swap_fee: Decimal) -> ExecuteMsg {
+    ExecuteMsg::CreatePair {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0001".to_string(),
+            },
+        ],
+        pool_fees: Some(PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::percent(1u64),
+            },
+            swap_fee: Fee { share: swap_fee },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        }),
+        pair_type,
+        token_factory_lp: false,
+    }
+}
+
+#[test]
+fn create_pair_paying_pair_creation_fee() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
+    enable_permissionless_pair_creation(&mut deps);
+
+    let config: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(
+        config.allowed_pair_types,
+        Some(vec![PairType::ConstantProduct])
+    );
+
+    let info = mock_info(
+        "anyone",
+        &[coin(1_500u128, "uwhale"), coin(1u128, "uusd".to_string())],
+    );
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        create_pair_msg(PairType::ConstantProduct, Decimal::percent(1u64)),
+    )
+    .unwrap();
+
+    // the fee goes to the fee collector, the rest of the funds to the pair
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "collector".to_string(),
+            amount: coins(1_000u128, "uwhale"),
+        })
+    );
+    match &res.messages[1].msg {
+        CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => {
+            assert_eq!(
+                funds,
+                &vec![coin(500u128, "uwhale"), coin(1u128, "uusd".to_string())]
+            );
+        }
+        _ => panic!("expected the pair instantiation"),
+    }
+}
+
+#[test]
+fn fail_to_create_pair_without_meeting_pair_creation_config() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
+    enable_permissionless_pair_creation(&mut deps);
+
+    // fee not paid
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[coin(999u128, "uwhale")]),
+        create_pair_msg(PairType::ConstantProduct, Decimal::percent(1u64)),
+    );
+    match res {
+        Err(ContractError::InvalidPairCreationFee { amount, expected }) => {
+            assert_eq!(amount, Uint128::new(999u128));
+            assert_eq!(expected, Uint128::new(1_000u128));
+        }
+        _ => panic!("should return ContractError::InvalidPairCreationFee"),
+    }
+
+    // pair type not allowed
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[coin(1_000u128, "uwhale")]),
+        create_pair_msg(PairType::StableSwap { amp: 100 }, Decimal::percent(1u64)),
+    );
+    match res {
+        Err(ContractError::PairTypeNotAllowed { pair_type }) => {
+            assert_eq!(pair_type, "StableSwap")
+        }
+        _ => panic!("should return ContractError::PairTypeNotAllowed"),
+    }
+
+    // pool fees out of bounds
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[coin(1_000u128, "uwhale")]),
+        create_pair_msg(PairType::ConstantProduct, Decimal::percent(2u64)),
+    );
+    match res {
+        Err(ContractError::PoolFeesOutOfBounds {}) => (),
+        _ => panic!("should return ContractError::PoolFeesOutOfBounds"),
+    }
+
+    // the owner is not restricted
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        create_pair_msg(PairType::StableSwap { amp: 100 }, Decimal::percent(2u64)),
+    )
+    .unwrap();
+}
+
+#[test]
+fn fail_to_update_pair_creation_config() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let one_percent_fee = Fee {
+        share: Decimal::percent(1u64),
+    };
+    let zero_fee = Fee {
+        share: Decimal::zero(),
+    };
+    let msg = ExecuteMsg::UpdatePairCreationConfig {
+        pair_creation_fee: None,
+        pool_fee_bounds: Some(PoolFeeBounds {
+            min: PoolFee {
+                protocol_fee: one_percent_fee.clone(),
+                swap_fee: one_percent_fee,
+                burn_fee: zero_fee.clone(),
+            },
+            max: PoolFee {
+                protocol_fee: zero_fee.clone(),
+                swap_fee: zero_fee.clone(),
+                burn_fee: zero_fee,
+            },
+        }),
+        allowed_pair_types: None,
+    };
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        msg.clone(),
+    );
+    match res {
+        Err(ContractError::InvalidPoolFeeBounds {}) => (),
+        _ => panic!("should return ContractError::InvalidPoolFeeBounds"),
+    }
+
+    let res = execute(deps.as_mut(), mock_env(), mock_info("anyone", &[]), msg);
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+}
+
+#[test]
+fn query_pairs_by_asset_and_type() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let uusd = AssetInfo::NativeToken {
+        denom: "uusd".to_string(),
+    };
+    let uluna = AssetInfo::NativeToken {
+        denom: "uluna".to_string(),
+    };
+    let token = AssetInfo::Token {
+        contract_addr: "asset0001".to_string(),
+    };
+
+    let pairs_to_store = vec![
+        ([uusd.clone(), token.clone()], PairType::ConstantProduct),
+        (
+            [uluna.clone(), uusd.clone()],
+            PairType::StableSwap { amp: 100 },
+        ),
+        ([uluna.clone(), token], PairType::ConstantProduct),
+    ];
+    for (i, (asset_infos, pair_type)) in pairs_to_store.into_iter().enumerate() {
+        let raw_infos = [
+            asset_infos[0].to_raw(deps.as_ref().api).unwrap(),
+            asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
+        ];
+        pairs()
+            .save(
+                &mut deps.storage,
+                &pair_key(&raw_infos),
+                &PairInfoRaw {
+                    liquidity_token: AssetInfoRaw::NativeToken {
+                        denom: format!("lp{i}"),
+                    },
+                    contract_addr: deps.api.addr_canonicalize(&format!("pair{i}")).unwrap(),
+                    asset_infos: raw_infos,
+                    asset_decimals: [6u8, 6u8],
+                    pair_type,
+                    created_at: None,
+                },
+            )
+            .unwrap();
+    }
+
+    let query_pairs = |deps: &OwnedDeps<MockStorage, MockApi, WasmMockQuerier>, msg: QueryMsg| {
+        from_binary::<PairsResponse>(&query(deps.as_ref(), mock_env(), msg).unwrap())
+            .unwrap()
+            .pairs
+            .into_iter()
+            .map(|pair| pair.contract_addr)
+            .collect::<Vec<_>>()
+    };
+
+    // uusd is the first asset of a pair and the second asset of another one
+    let mut pairs_with_uusd = query_pairs(
+        &deps,
+        QueryMsg::PairsByAsset {
+            asset_info: uusd.clone(),
+            start_after: None,
+            limit: None,
+        },
+    );
+    pairs_with_uusd.sort();
+    assert_eq!(pairs_with_uusd, vec!["pair0", "pair1"]);
+
+    // paginate through them
+    let first_page = from_binary::<PairsResponse>(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PairsByAsset {
+                asset_info: uusd.clone(),
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+    .pairs;
+    assert_eq!(first_page.len(), 1);
+
+    let second_page = query_pairs(
+        &deps,
+        QueryMsg::PairsByAsset {
+            asset_info: uusd.clone(),
+            start_after: Some(first_page[0].asset_infos.clone()),
+            limit: Some(1),
+        },
+    );
+    assert_eq!(second_page.len(), 1);
+    assert_ne!(second_page[0], first_page[0].contract_addr);
+
+    // stableswap pairs are matched regardless of their amplification
+    let stableswap_pairs = query_pairs(
+        &deps,
+        QueryMsg::PairsByType {
+            pair_type: PairType::StableSwap { amp: 1 },
+            start_after: None,
+            limit: None,
+        },
+    );
+    assert_eq!(stableswap_pairs, vec!["pair1"]);
+
+    let mut constant_product_pairs = query_pairs(
+        &deps,
+        QueryMsg::PairsByType {
+            pair_type: PairType::ConstantProduct,
+            start_after: None,
+            limit: None,
+        },
+    );
+    constant_product_pairs.sort();
+    assert_eq!(constant_product_pairs, vec!["pair0", "pair2"]);
+
+    // removing a pair removes it from the indexes
+    deps.querier.with_pair_feature_toggle(
+        "pair1",
+        FeatureToggle {
+            withdrawals_enabled: true,
+            deposits_enabled: false,
+            swaps_enabled: false,
+        },
+    );
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::RemovePair {
+            asset_infos: [uluna.clone(), uusd.clone()],
+        },
+    )
+    .unwrap();
+    let stableswap_pairs = query_pairs(
+        &deps,
+        QueryMsg::PairsByType {
+            pair_type: PairType::StableSwap { amp: 100 },
+            start_after: None,
+            limit: None,
+        },
+    );
+    assert!(stableswap_pairs.is_empty());
+    let pairs_with_uluna = query_pairs(
+        &deps,
+        QueryMsg::PairsByAsset {
+            asset_info: uluna,
+            start_after: None,
+            limit: None,
+        },
+    );
+    assert_eq!(pairs_with_uluna, vec!["pair2"]);
+}
+
+#[test]
+fn migrate_pairs_in_pages() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let denoms = ["uatom", "uluna", "uusd"];
+    for (i, denom) in denoms.iter().enumerate() {
+        let raw_infos = [
+            AssetInfoRaw::NativeToken {
+                denom: denom.to_string(),
+            },
+            AssetInfoRaw::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+        ];
+        let contract_addr = format!("pair{i}");
+        pairs()
+            .save(
+                &mut deps.storage,
+                &pair_key(&raw_infos),
+                &PairInfoRaw {
+                    liquidity_token: AssetInfoRaw::NativeToken {
+                        denom: format!("lp{i}"),
+                    },
+                    contract_addr: deps.api.addr_canonicalize(&contract_addr).unwrap(),
+                    asset_infos: raw_infos,
+                    asset_decimals: [6u8, 6u8],
+                    pair_type: PairType::ConstantProduct,
+                    created_at: None,
+                },
+            )
+            .unwrap();
+        PAIR_CODE_IDS
+            .save(&mut deps.storage, &Addr::unchecked(contract_addr), &321u64)
+            .unwrap();
+    }
+
+    // upgrade the pair code_id of the factory, the first pair is already on it
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateConfig {
+            owner: None,
+            fee_collector_addr: None,
+            token_code_id: None,
+            pair_code_id: Some(400u64),
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigratePair {
+            contract: "pair0".to_string(),
+            code_id: None,
+        },
+    )
+    .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigratePairs {
+            code_id: None,
+            start_after: None,
+            limit: Some(2),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_submessage(SubMsg::reply_on_success(
+                CosmosMsg::Wasm(WasmMsg::Migrate {
+                    contract_addr: "pair1".to_string(),
+                    new_code_id: 400u64,
+                    msg: to_binary(&PairMigrateMsg {}).unwrap(),
+                }),
+                MIGRATE_PAIR_REPLY_ID,
+            ))
+            .add_attributes(vec![
+                ("action", "migrate_pairs"),
+                ("code_id", "400"),
+                ("pair", "pair1"),
+                ("last_pair", "uluna-uwhale"),
+            ])
+            .set_data(
+                to_binary(&[
+                    AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                    AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                ])
+                .unwrap()
+            )
+    );
+
+    // a page where all the pairs are skipped still returns the cursor of the next page
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigratePairs {
+            code_id: None,
+            start_after: None,
+            limit: Some(2),
+        },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    let cursor: Option<[AssetInfo; 2]> = from_binary(&res.data.unwrap()).unwrap();
+    assert_eq!(
+        cursor,
+        Some([
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+        ])
+    );
+
+    // the remaining pair is reported as outdated
+    let res: PairCodeIdsResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PairCodeIds {
+                start_after: None,
+                limit: None,
+                outdated_only: Some(true),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        PairCodeIdsResponse {
+            pair_code_id: 400u64,
+            pairs: vec![PairCodeId {
+                asset_infos: [
+                    AssetInfo::NativeToken {
+                        denom: "uusd".to_string(),
+                    },
+                    AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                ],
+                contract_addr: "pair2".to_string(),
+                code_id: Some(321u64),
+            }],
+        }
+    );
+
+    // migrate the next page
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigratePairs {
+            code_id: Some(400u64),
+            start_after: Some([
+                AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+            ]),
+            limit: Some(2),
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(
+        PAIR_CODE_IDS
+            .load(&deps.storage, &Addr::unchecked("pair2"))
+            .unwrap(),
+        400u64
+    );
+
+    // the pairs without a code_id recorded get it backfilled from the chain, which reports 0
+    PAIR_CODE_IDS.remove(&mut deps.storage, &Addr::unchecked("pair2"));
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigratePairs {
+            code_id: Some(0u64),
+            start_after: Some([
+                AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+            ]),
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(
+        PAIR_CODE_IDS
+            .load(&deps.storage, &Addr::unchecked("pair2"))
+            .unwrap(),
+        0u64
+    );
+
+    // once all the pairs have been scanned, no cursor is returned
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigratePairs {
+            code_id: Some(0u64),
+            start_after: Some([
+                AssetInfo::NativeToken {
+                    denom: "uusd".to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+            ]),
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(res.data, None);
+
+    // only the owner can migrate pairs
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::MigratePairs {
+            code_id: None,
+            start_after: None,
+            limit: None,
+        },
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+}
+
+#[test]
+fn create_pair_with_default_pool_fees() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
+
+    let mut msg = create_pair_msg(PairType::ConstantProduct, Decimal::percent(1u64));
+    if let ExecuteMsg::CreatePair { pool_fees, .. } = &mut msg {
+        *pool_fees = None;
+    }
+
+    // there are no default pool fees for constant product pairs yet
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        msg.clone(),
+    );
+    match res {
+        Err(ContractError::NoPoolFees { pair_type }) => assert_eq!(pair_type, "ConstantProduct"),
+        _ => panic!("should return ContractError::NoPoolFees"),
+    }
+
+    let default_pool_fees = PoolFee {
+        protocol_fee: Fee {
+            share: Decimal::permille(2u64),
+        },
+        swap_fee: Fee {
+            share: Decimal::permille(3u64),
+        },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+    };
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateDefaultPoolFees {
+            pair_type: PairType::ConstantProduct,
+            pool_fees: Some(default_pool_fees.clone()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "update_default_pool_fees"),
+            attr("pair_type", "ConstantProduct"),
+        ]
+    );
+
+    let res: DefaultPoolFeesResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::DefaultPoolFees {}).unwrap())
+            .unwrap();
+    assert_eq!(
+        res.default_pool_fees,
+        vec![DefaultPoolFees {
+            pair_type: "ConstantProduct".to_string(),
+            pool_fees: default_pool_fees.clone(),
+        }]
+    );
+
+    // the pair inherits the default pool fees
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Instantiate { msg, .. }) => {
+            let msg: PairInstantiateMsg = from_binary(msg).unwrap();
+            assert_eq!(msg.pool_fees, default_pool_fees);
+        }
+        _ => panic!("expected the pair instantiation"),
+    }
+
+    // only the owner can set the default pool fees
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::UpdateDefaultPoolFees {
+            pair_type: PairType::ConstantProduct,
+            pool_fees: None,
+        },
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+}
+
+#[test]
+fn bulk_update_pool_fees() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let uusd = AssetInfo::NativeToken {
+        denom: "uusd".to_string(),
+    };
+    let uluna = AssetInfo::NativeToken {
+        denom: "uluna".to_string(),
+    };
+    let uwhale = AssetInfo::NativeToken {
+        denom: "uwhale".to_string(),
+    };
+
+    let pairs_to_store = vec![
+        ([uluna.clone(), uwhale.clone()], PairType::ConstantProduct),
+        (
+            [uluna.clone(), uusd.clone()],
+            PairType::StableSwap { amp: 100 },
+        ),
+        ([uusd, uwhale], PairType::ConstantProduct),
+    ];
+    for (i, (asset_infos, pair_type)) in pairs_to_store.into_iter().enumerate() {
+        let raw_infos = [
+            asset_infos[0].to_raw(deps.as_ref().api).unwrap(),
+            asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
+        ];
+        pairs()
+            .save(
+                &mut deps.storage,
+                &pair_key(&raw_infos),
+                &PairInfoRaw {
+                    liquidity_token: AssetInfoRaw::NativeToken {
+                        denom: format!("lp{i}"),
+                    },
+                    contract_addr: deps.api.addr_canonicalize(&format!("pair{i}")).unwrap(),
+                    asset_infos: raw_infos,
+                    asset_decimals: [6u8, 6u8],
+                    pair_type,
+                    created_at: None,
+                },
+            )
+            .unwrap();
+    }
+
+    let pool_fees = PoolFee {
+        protocol_fee: Fee {
+            share: Decimal::permille(1u64),
+        },
+        swap_fee: Fee {
+            share: Decimal::permille(2u64),
+        },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+    };
+    let update_config_msg = to_binary(&pool_network::pair::ExecuteMsg::UpdateConfig {
+        owner: None,
+        fee_collector_addr: None,
+        pool_fees: Some(pool_fees.clone()),
+        feature_toggle: None,
+    })
+    .unwrap();
+
+    let updated_pairs = |res: Response| {
+        res.messages
+            .into_iter()
+            .map(|sub_msg| match sub_msg.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr, msg, ..
+                }) => {
+                    assert_eq!(msg, update_config_msg);
+                    contract_addr
+                }
+                _ => panic!("expected the pair config update"),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // constant product pairs with uluna
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::BulkUpdatePoolFees {
+            filter: PairsFilter {
+                asset_info: Some(uluna),
+                pair_type: Some(PairType::ConstantProduct),
+            },
+            pool_fees: pool_fees.clone(),
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "bulk_update_pool_fees"),
+            attr("pair", "pair0"),
+        ]
+    );
+    assert_eq!(updated_pairs(res), vec!["pair0"]);
+
+    // stableswap pairs
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::BulkUpdatePoolFees {
+            filter: PairsFilter {
+                asset_info: None,
+                pair_type: Some(PairType::StableSwap { amp: 1 }),
+            },
+            pool_fees: pool_fees.clone(),
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(updated_pairs(res), vec!["pair1"]);
+
+    // every pair, in pages
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::BulkUpdatePoolFees {
+            filter: PairsFilter {
+                asset_info: None,
+                pair_type: None,
+            },
+            pool_fees: pool_fees.clone(),
+            start_after: None,
+            limit: Some(2),
+        },
+    )
+    .unwrap();
+    assert_eq!(updated_pairs(res).len(), 2);
+
+    // invalid pool fees are rejected
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::BulkUpdatePoolFees {
+            filter: PairsFilter {
+                asset_info: None,
+                pair_type: None,
+            },
+            pool_fees: PoolFee {
+                protocol_fee: Fee {
+                    share: Decimal::percent(200u64),
+                },
+                ..pool_fees.clone()
+            },
+            start_after: None,
+            limit: None,
+        },
+    );
+    assert!(res.is_err());
+
+    // only the owner can update the pool fees
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::BulkUpdatePoolFees {
+            filter: PairsFilter {
+                asset_info: None,
+                pair_type: None,
+            },
+            pool_fees,
+            start_after: None,
+            limit: None,
+        },
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+}
+
+#[test]
+fn migrate_pair_to_token_factory_lp() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let asset_infos = [
+        AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        },
+        AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        },
+    ];
+    let raw_infos = [
+        asset_infos[0].to_raw(deps.as_ref().api).unwrap(),
+        asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
+    ];
+    pairs()
+        .save(
+            &mut deps.storage,
+            &pair_key(&raw_infos),
+            &PairInfoRaw {
+                liquidity_token: AssetInfoRaw::NativeToken {
+                    denom: "lp".to_string(),
+                },
+                contract_addr: deps.api.addr_canonicalize("pair0000").unwrap(),
+                asset_infos: raw_infos.clone(),
+                asset_decimals: [6u8, 6u8],
+                pair_type: PairType::ConstantProduct,
+                created_at: None,
+            },
+        )
+        .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigrateToTokenFactoryLp { asset_infos },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::reply_on_success(
+            WasmMsg::Execute {
+                contract_addr: "pair0000".to_string(),
+                msg: to_binary(&pool_network::pair::ExecuteMsg::MigrateToTokenFactoryLp {})
+                    .unwrap(),
+                funds: vec![],
+            },
+            2,
+        )]
+    );
+
+    // the liquidity token reported by the pair is stored once it's migrated
+    let reply_msg = Reply {
+        id: 2,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        }),
+    };
+    reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+
+    assert_eq!(
+        pairs()
+            .load(&deps.storage, &pair_key(&raw_infos))
+            .unwrap()
+            .liquidity_token,
+        AssetInfoRaw::Token {
+            contract_addr: deps.api.addr_canonicalize("liquidity0000").unwrap(),
+        }
+    );
+}
+
+#[test]
+fn update_liquidity_token_of_migrated_pair() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let raw_infos = [
+        AssetInfoRaw::NativeToken {
+            denom: "uluna".to_string(),
+        },
+        AssetInfoRaw::NativeToken {
+            denom: "uluna".to_string(),
+        },
+    ];
+    let pair = PairInfoRaw {
+        liquidity_token: AssetInfoRaw::NativeToken {
+            denom: "lp".to_string(),
+        },
+        contract_addr: deps.api.addr_canonicalize("pair0000").unwrap(),
+        asset_infos: raw_infos.clone(),
+        asset_decimals: [6u8, 6u8],
+        pair_type: PairType::ConstantProduct,
+        created_at: None,
+    };
+    pairs()
+        .save(&mut deps.storage, &pair_key(&raw_infos), &pair)
+        .unwrap();
+
+    // pairs not listed on the factory are ignored
+    let reply_msg = Reply {
+        id: MIGRATE_PAIR_REPLY_ID,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![Event::new("migrate").add_attribute("_contract_address", "pair0001")],
+            data: None,
+        }),
+    };
+    let res = reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+    assert_eq!(res, Response::default());
+    assert_eq!(
+        pairs().load(&deps.storage, &pair_key(&raw_infos)).unwrap(),
+        pair
+    );
+
+    // the liquidity token reported by the migrated pair is stored
+    let reply_msg = Reply {
+        id: MIGRATE_PAIR_REPLY_ID,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![Event::new("migrate").add_attribute("_contract_address", "pair0000")],
+            data: None,
+        }),
+    };
+    reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+    assert_eq!(
+        pairs()
+            .load(&deps.storage, &pair_key(&raw_infos))
+            .unwrap()
+            .liquidity_token,
+        AssetInfoRaw::Token {
+            contract_addr: deps.api.addr_canonicalize("liquidity0000").unwrap(),
+        }
+    );
+}
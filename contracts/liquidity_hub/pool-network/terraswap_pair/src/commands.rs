@@ -12,12 +12,12 @@ use white_whale::pool_network::pair::{Config, Cw20HookMsg, FeatureToggle, PoolFe
 use white_whale::pool_network::U256;
 
 use crate::error::ContractError;
-use crate::helpers;
 use crate::helpers::{get_protocol_fee_for_asset, get_total_share, has_factory_token};
 use crate::state::{
     store_fee, ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES,
-    CONFIG, PAIR_INFO,
+    CONFIG, LEGACY_LP_TOKEN, PAIR_INFO,
 };
+use crate::{helpers, migrations};
 
 /// Receives cw20 tokens. Used to swap and withdraw from the pool.
 /// If the Cw20HookMsg is Swap, the user must call IncreaseAllowance on the cw20 token first to allow
@@ -89,18 +89,39 @@ pub fn receive_cw20(
                 ));
             }
 
+            // the cw20 LP token of the pair, or the one it was migrated from to a token factory LP,
+            // as the holders of the latter can still withdraw without converting it first
             let config: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
-            let cw20_lp_token = match config.liquidity_token {
-                AssetInfoRaw::Token { contract_addr } => contract_addr,
-                AssetInfoRaw::NativeToken { .. } => return Err(ContractError::Unauthorized {}),
+            let is_cw20_lp_token = match config.liquidity_token {
+                AssetInfoRaw::Token { contract_addr } => {
+                    deps.api.addr_canonicalize(info.sender.as_str())? == contract_addr
+                }
+                AssetInfoRaw::NativeToken { .. } => {
+                    LEGACY_LP_TOKEN.may_load(deps.storage)? == Some(info.sender.clone())
+                }
             };
 
-            if deps.api.addr_canonicalize(info.sender.as_str())? != cw20_lp_token {
+            if !is_cw20_lp_token {
+                return Err(ContractError::Unauthorized {});
+            }
+
+            let sender_addr = deps.api.addr_validate(cw20_msg.sender.as_str())?;
+            withdraw_liquidity(
+                deps,
+                env,
+                sender_addr,
+                info.sender.to_string(),
+                cw20_msg.amount,
+            )
+        }
+        Ok(Cw20HookMsg::ConvertToTokenFactoryLp {}) => {
+            // only the cw20 LP token the pair was migrated from can be converted
+            if LEGACY_LP_TOKEN.may_load(deps.storage)? != Some(info.sender.clone()) {
                 return Err(ContractError::Unauthorized {});
             }
 
             let sender_addr = deps.api.addr_validate(cw20_msg.sender.as_str())?;
-            withdraw_liquidity(deps, env, sender_addr, cw20_msg.amount)
+            convert_to_token_factory_lp(deps, env, info.sender, sender_addr, cw20_msg.amount)
         }
         Err(err) => Err(ContractError::Std(err)),
     }
@@ -254,11 +275,13 @@ pub fn provide_liquidity(
 }
 
 /// Withdraws the liquidity. The user burns the LP tokens in exchange for the tokens provided, including
-/// the swap fees accrued by its share of the pool.
+/// the swap fees accrued by its share of the pool. The `lp_token` is the LP token being burned, which
+/// can be the cw20 LP token a token factory LP pair was migrated from.
 pub fn withdraw_liquidity(
     deps: DepsMut,
     env: Env,
     sender: Addr,
+    lp_token: String,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
     let pair_info: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
@@ -272,7 +295,7 @@ pub fn withdraw_liquidity(
         AssetInfoRaw::NativeToken { denom } => denom,
     };
 
-    let total_share = get_total_share(&deps.as_ref(), liquidity_token.clone())?;
+    let total_share = get_total_share(&deps.as_ref(), liquidity_token)?;
 
     let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
 
@@ -297,8 +320,7 @@ pub fn withdraw_liquidity(
 
     let refund_assets = refund_assets?;
 
-    let burn_lp_token_msg =
-        burn_lp_token_msg(liquidity_token, env.contract.address.to_string(), amount)?;
+    let burn_lp_token_msg = burn_lp_token_msg(lp_token, env.contract.address.to_string(), amount)?;
 
     // update pool info
     Ok(Response::new()
@@ -319,6 +341,65 @@ pub fn withdraw_liquidity(
         ]))
 }
 
+/// Converts cw20 LP tokens into the token factory LP denom of the pair 1:1, burning the cw20 LP
+/// tokens so the total share of the pool remains unchanged.
+fn convert_to_token_factory_lp(
+    deps: DepsMut,
+    env: Env,
+    legacy_lp_token: Addr,
+    sender: Addr,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let liquidity_token = match PAIR_INFO.load(deps.storage)?.liquidity_token {
+        AssetInfoRaw::NativeToken { denom } => denom,
+        AssetInfoRaw::Token { .. } => return Err(ContractError::Unauthorized {}),
+    };
+
+    let mut messages = vec![burn_lp_token_msg(
+        legacy_lp_token.to_string(),
+        env.contract.address.to_string(),
+        amount,
+    )?];
+    messages.append(&mut mint_lp_token_msg(
+        liquidity_token,
+        sender.to_string(),
+        env.contract.address.to_string(),
+        amount,
+    )?);
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "convert_to_token_factory_lp"),
+        ("sender", sender.as_str()),
+        ("amount", &amount.to_string()),
+    ]))
+}
+
+/// Migrates the cw20 LP token of the pair to a token factory LP. Only the owner can do this.
+pub fn migrate_to_token_factory_lp(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let messages = migrations::migrate_to_token_factory_lp(deps, &env)?;
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "migrate_to_token_factory_lp"),
+        (
+            "liquidity_token",
+            &helpers::get_lp_denom(&env.contract.address),
+        ),
+    ]))
+}
+
 /// Swaps tokens from the pool. The user provides an offer asset and receives the ask asset in return.
 #[allow(clippy::too_many_arguments)]
 pub fn swap(
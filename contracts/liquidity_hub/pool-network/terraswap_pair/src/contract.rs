@@ -1,16 +1,15 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Reply, ReplyOn,
-    Response, StdError, StdResult, SubMsg, WasmMsg,
+    to_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Reply, ReplyOn, Response,
+    StdError, StdResult, SubMsg, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::MinterResponse;
 use protobuf::Message;
 use semver::Version;
 
-use white_whale::pool_network::asset::{AssetInfoRaw, PairInfoRaw};
-use white_whale::pool_network::denom::MsgCreateDenom;
+use white_whale::pool_network::asset::{AssetInfoRaw, CreationBlock, PairInfoRaw};
 use white_whale::pool_network::pair::{
     Config, ExecuteMsg, FeatureToggle, InstantiateMsg, MigrateMsg, QueryMsg,
 };
@@ -21,7 +20,7 @@ use crate::helpers::has_factory_token;
 use crate::response::MsgInstantiateContractResponse;
 use crate::state::{
     ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES, CONFIG,
-    LP_SYMBOL, PAIR_INFO,
+    LP_DECIMALS, LP_SYMBOL, PAIR_INFO,
 };
 use crate::{commands, helpers, queries};
 
@@ -55,6 +54,7 @@ pub fn instantiate(
         ],
         asset_decimals: msg.asset_decimals,
         pair_type: msg.pair_type.clone(),
+        created_at: Some(CreationBlock::from(&env.block)),
     };
 
     PAIR_INFO.save(deps.storage, pair_info)?;
@@ -105,20 +105,16 @@ pub fn instantiate(
     if msg.token_factory_lp {
         // create native LP token
         PAIR_INFO.update(deps.storage, |mut pair_info| -> StdResult<_> {
-            let denom = format!("{}/{}/{}", "factory", env.contract.address, LP_SYMBOL);
+            let denom = helpers::get_lp_denom(&env.contract.address);
             pair_info.liquidity_token = AssetInfoRaw::NativeToken { denom };
 
             Ok(pair_info)
         })?;
 
-        Ok(
-            Response::new().add_message(<MsgCreateDenom as Into<CosmosMsg>>::into(
-                MsgCreateDenom {
-                    sender: env.contract.address.to_string(),
-                    subdenom: LP_SYMBOL.to_string(),
-                },
-            )),
-        )
+        Ok(Response::new().add_messages(helpers::create_lp_denom_msgs(
+            &env.contract.address,
+            [asset0_label, asset1_label],
+        )))
     } else {
         Ok(Response::new().add_submessage(SubMsg {
             // Create LP token
@@ -128,7 +124,7 @@ pub fn instantiate(
                 msg: to_binary(&TokenInstantiateMsg {
                     name: lp_token_name.clone(),
                     symbol: LP_SYMBOL.to_string(),
-                    decimals: 6,
+                    decimals: LP_DECIMALS,
                     initial_balances: vec![],
                     mint: Some(MinterResponse {
                         minter: env.contract.address.to_string(),
@@ -172,7 +168,13 @@ pub fn execute(
                 return Err(ContractError::AssetMismatch {});
             }
 
-            commands::withdraw_liquidity(deps, env, info.sender, info.funds[0].amount)
+            commands::withdraw_liquidity(
+                deps,
+                env,
+                info.sender,
+                lp_token_denom,
+                info.funds[0].amount,
+            )
         }
         ExecuteMsg::Swap {
             offer_asset,
@@ -221,6 +223,9 @@ pub fn execute(
             feature_toggle,
         ),
         ExecuteMsg::CollectProtocolFees {} => commands::collect_protocol_fees(deps),
+        ExecuteMsg::MigrateToTokenFactoryLp {} => {
+            commands::migrate_to_token_factory_lp(deps, env, info)
+        }
     }
 }
 
@@ -276,7 +281,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
 
 #[cfg(not(tarpaulin_include))]
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     use crate::migrations;
 
     let version: Version = CONTRACT_VERSION.parse()?;
@@ -298,6 +303,14 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
         migrations::migrate_to_v130(deps.branch())?;
     }
 
+    let mut messages = vec![];
+    if storage_version < Version::parse("1.4.0")? {
+        // the pairs still on a cw20 LP token move to a token factory LP
+        if let AssetInfoRaw::Token { .. } = PAIR_INFO.load(deps.storage)?.liquidity_token {
+            messages = migrations::migrate_to_token_factory_lp(deps.branch(), &env)?;
+        }
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-    Ok(Response::default())
+    Ok(Response::default().add_messages(messages))
 }
@@ -72,6 +72,9 @@ pub enum ContractError {
 
     #[error("Burn fee is not allowed when using factory tokens")]
     TokenFactoryAssetBurnDisabled {},
+
+    #[error("The pair is already using a token factory LP")]
+    TokenFactoryLpAlreadyEnabled {},
 }
 
 impl From<semver::Error> for ContractError {
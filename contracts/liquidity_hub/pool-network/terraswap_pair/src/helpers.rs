@@ -2,15 +2,19 @@ use std::cmp::Ordering;
 use std::ops::Mul;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Decimal, Decimal256, Deps, StdError, StdResult, Storage, Uint128, Uint256};
+use cosmwasm_std::{
+    Addr, CosmosMsg, Decimal, Decimal256, Deps, StdError, StdResult, Storage, Uint128, Uint256,
+};
 use cw_storage_plus::Item;
 
 use white_whale::pool_network::asset::{is_factory_token, Asset, AssetInfo, PairType};
+use white_whale::pool_network::denom::{DenomUnit, Metadata, MsgCreateDenom, MsgSetDenomMetadata};
 use white_whale::pool_network::pair::PoolFee;
 use white_whale::pool_network::querier::query_token_info;
 
 use crate::error::ContractError;
 use crate::math::Decimal256Helper;
+use crate::state::{LEGACY_LP_TOKEN, LP_DECIMALS, LP_SYMBOL};
 
 /// The amount of iterations to perform when calculating the Newton-Raphson approximation.
 const NEWTON_ITERATIONS: u64 = 32;
@@ -469,11 +473,17 @@ pub fn instantiate_fees(
     )
 }
 
-/// Gets the total supply of the given liquidity token
+/// Gets the total supply of the given liquidity token. For pairs migrated from a cw20 LP token to a
+/// token factory LP, the cw20 LP tokens that haven't been converted yet are included.
 pub fn get_total_share(deps: &Deps, liquidity_token: String) -> StdResult<Uint128> {
     let total_share = if is_factory_token(liquidity_token.as_str()) {
         //bank query total
-        deps.querier.query_supply(&liquidity_token)?.amount
+        let mut total_share = deps.querier.query_supply(&liquidity_token)?.amount;
+        if let Some(legacy_lp_token) = LEGACY_LP_TOKEN.may_load(deps.storage)? {
+            total_share = total_share
+                .checked_add(query_token_info(&deps.querier, legacy_lp_token)?.total_supply)?;
+        }
+        total_share
     } else {
         query_token_info(
             &deps.querier,
@@ -484,6 +494,50 @@ pub fn get_total_share(deps: &Deps, liquidity_token: String) -> StdResult<Uint12
     Ok(total_share)
 }
 
+/// Gets the token factory LP denom of the given pair
+pub fn get_lp_denom(pair: &Addr) -> String {
+    format!("{}/{}/{}", "factory", pair, LP_SYMBOL)
+}
+
+/// Creates the messages to create the token factory LP denom of the given pair and set its bank
+/// metadata, so wallets can display the LP token properly
+pub fn create_lp_denom_msgs(pair: &Addr, asset_labels: [String; 2]) -> Vec<CosmosMsg> {
+    let denom = get_lp_denom(pair);
+    let [asset0_label, asset1_label] = asset_labels;
+    let lp_token_name = format!("{asset0_label}-{asset1_label}-LP");
+
+    let metadata = Metadata {
+        description: format!("{asset0_label}-{asset1_label} pair liquidity token"),
+        denom_units: vec![
+            DenomUnit {
+                denom: denom.clone(),
+                exponent: 0,
+                aliases: vec![],
+            },
+            DenomUnit {
+                denom: lp_token_name.clone(),
+                exponent: LP_DECIMALS as u32,
+                aliases: vec![],
+            },
+        ],
+        base: denom,
+        display: lp_token_name.clone(),
+        name: lp_token_name.clone(),
+        symbol: lp_token_name,
+    };
+
+    vec![
+        <MsgCreateDenom as Into<CosmosMsg>>::into(MsgCreateDenom {
+            sender: pair.to_string(),
+            subdenom: LP_SYMBOL.to_string(),
+        }),
+        <MsgSetDenomMetadata as Into<CosmosMsg>>::into(MsgSetDenomMetadata {
+            sender: pair.to_string(),
+            metadata: Some(metadata),
+        }),
+    ]
+}
+
 /// Verifies if there's a factory token in the vector of [AssetInfo]s.
 /// todo consolidate this once the pool PRs are merged
 pub fn has_factory_token(assets: &[AssetInfo]) -> bool {
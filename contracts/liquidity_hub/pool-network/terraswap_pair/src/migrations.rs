@@ -1,6 +1,6 @@
 #![cfg(not(tarpaulin_include))]
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, CanonicalAddr, Decimal, DepsMut, StdError, Uint128};
+use cosmwasm_std::{Addr, CanonicalAddr, CosmosMsg, Decimal, DepsMut, Env, StdError, Uint128};
 use cw_storage_plus::Item;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -10,8 +10,9 @@ use white_whale::pool_network;
 use white_whale::pool_network::asset::{AssetInfo, AssetInfoRaw, PairType};
 use white_whale::pool_network::pair::{Config, FeatureToggle};
 
-use crate::helpers::instantiate_fees;
-use crate::state::{ALL_TIME_BURNED_FEES, CONFIG, PAIR_INFO};
+use crate::error::ContractError;
+use crate::helpers::{create_lp_denom_msgs, get_lp_denom, instantiate_fees};
+use crate::state::{ALL_TIME_BURNED_FEES, CONFIG, LEGACY_LP_TOKEN, PAIR_INFO};
 
 /// Migrate state of the factory from PascalCase to snake_case for the following items:
 /// [`PairInfoRaw`], [`PairInfo`]
@@ -158,3 +159,34 @@ pub fn migrate_to_v130(deps: DepsMut) -> Result<(), StdError> {
 
     Ok(())
 }
+
+/// Migrates the cw20 LP token of the pair to a token factory LP denom. The cw20 LP token is kept as
+/// [LEGACY_LP_TOKEN] so its holders can convert it into the new denom, while the liquidity token of
+/// the [PAIR_INFO] becomes the new denom right away. Returns the messages creating the new denom.
+pub fn migrate_to_token_factory_lp(
+    deps: DepsMut,
+    env: &Env,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let mut pair_info = PAIR_INFO.load(deps.storage)?;
+    let AssetInfoRaw::Token { contract_addr } = pair_info.liquidity_token else {
+        return Err(ContractError::TokenFactoryLpAlreadyEnabled {});
+    };
+
+    LEGACY_LP_TOKEN.save(deps.storage, &deps.api.addr_humanize(&contract_addr)?)?;
+
+    pair_info.liquidity_token = AssetInfoRaw::NativeToken {
+        denom: get_lp_denom(&env.contract.address),
+    };
+    PAIR_INFO.save(deps.storage, &pair_info)?;
+
+    let asset_labels = [
+        pair_info.asset_infos[0]
+            .to_normal(deps.api)?
+            .get_label(&deps.as_ref())?,
+        pair_info.asset_infos[1]
+            .to_normal(deps.api)?
+            .get_label(&deps.as_ref())?,
+    ];
+
+    Ok(create_lp_denom_msgs(&env.contract.address, asset_labels))
+}
@@ -1,13 +1,17 @@
-use cosmwasm_std::{StdError, StdResult, Storage, Uint128};
+use cosmwasm_std::{Addr, StdError, StdResult, Storage, Uint128};
 use cw_storage_plus::Item;
 
 use white_whale::pool_network::asset::{Asset, PairInfoRaw};
 use white_whale::pool_network::pair::Config;
 
 pub const LP_SYMBOL: &str = "uLP";
+pub const LP_DECIMALS: u8 = 6;
 
 pub const PAIR_INFO: Item<PairInfoRaw> = Item::new("pair_info");
 pub const CONFIG: Item<Config> = Item::new("config");
+/// The cw20 LP token of a pair migrated to a token factory LP, which holders can still convert into
+/// the token factory LP denom
+pub const LEGACY_LP_TOKEN: Item<Addr> = Item::new("legacy_lp_token");
 
 // Fees that have been accrued by the pool, still unclaimed by the fee collector
 pub const COLLECTED_PROTOCOL_FEES: Item<Vec<Asset>> = Item::new("collected_protocol_fees");
@@ -0,0 +1,309 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    coin, coins, from_binary, to_binary, Addr, BankMsg, CosmosMsg, Decimal, Reply, SubMsgResponse,
+    SubMsgResult, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+
+use white_whale::fee::Fee;
+use white_whale::pool_network::asset::{AssetInfo, PairInfo, PairType};
+use white_whale::pool_network::denom::{Coin, MsgMint};
+use white_whale::pool_network::mock_querier::mock_dependencies;
+use white_whale::pool_network::pair::{
+    Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, PoolFee, PoolResponse, QueryMsg,
+};
+
+use crate::contract::{execute, instantiate, migrate, query, reply};
+use crate::error::ContractError;
+use crate::helpers::create_lp_denom_msgs;
+use crate::state::LP_SYMBOL;
+
+#[test]
+fn migrate_cw20_lp_to_token_factory_lp() {
+    let lp_denom = format!("{}/{MOCK_CONTRACT_ADDR}/{LP_SYMBOL}", "factory");
+    let mut deps = mock_dependencies(&[coin(1_000u128, "uusd")]);
+    deps.querier.with_token_balances(&[
+        (
+            &"asset0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(1_000u128))],
+        ),
+        (
+            &"liquidity0000".to_string(),
+            &[(&"addr0001".to_string(), &Uint128::from(600u128))],
+        ),
+    ]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::zero(),
+            },
+            swap_fee: Fee {
+                share: Decimal::zero(),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    // store the cw20 liquidity token
+    let reply_msg = Reply {
+        id: 1,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(
+                vec![
+                    10, 13, 108, 105, 113, 117, 105, 100, 105, 116, 121, 48, 48, 48, 48,
+                ]
+                .into(),
+            ),
+        }),
+    };
+    reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+
+    // only the owner can migrate the LP token
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::MigrateToTokenFactoryLp {},
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigrateToTokenFactoryLp {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages
+            .into_iter()
+            .map(|sub_msg| sub_msg.msg)
+            .collect::<Vec<_>>(),
+        create_lp_denom_msgs(
+            &Addr::unchecked(MOCK_CONTRACT_ADDR),
+            ["uusd".to_string(), "mAAPL".to_string()]
+        )
+    );
+
+    let pair_info: PairInfo =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Pair {}).unwrap()).unwrap();
+    assert_eq!(
+        pair_info.liquidity_token,
+        AssetInfo::NativeToken {
+            denom: lp_denom.clone()
+        }
+    );
+
+    // the pair can't be migrated twice
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigrateToTokenFactoryLp {},
+    );
+    match res {
+        Err(ContractError::TokenFactoryLpAlreadyEnabled {}) => (),
+        _ => panic!("should return ContractError::TokenFactoryLpAlreadyEnabled"),
+    }
+
+    // the cw20 LP tokens not converted yet are part of the total share
+    deps.querier
+        .with_balance(&[(&"addr0002".to_string(), coins(400u128, lp_denom.as_str()))]);
+    let pool: PoolResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Pool {}).unwrap()).unwrap();
+    assert_eq!(pool.total_share, Uint128::new(1_000u128));
+
+    // convert the cw20 LP tokens 1:1
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("liquidity0000", &[]),
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "addr0001".to_string(),
+            amount: Uint128::new(600u128),
+            msg: to_binary(&Cw20HookMsg::ConvertToTokenFactoryLp {}).unwrap(),
+        }),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages
+            .into_iter()
+            .map(|sub_msg| sub_msg.msg)
+            .collect::<Vec<_>>(),
+        vec![
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "liquidity0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Burn {
+                    amount: Uint128::new(600u128),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            <MsgMint as Into<CosmosMsg>>::into(MsgMint {
+                sender: MOCK_CONTRACT_ADDR.to_string(),
+                amount: Some(Coin {
+                    denom: lp_denom.clone(),
+                    amount: "600".to_string(),
+                }),
+            }),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "addr0001".to_string(),
+                amount: coins(600u128, lp_denom.as_str()),
+            }),
+        ]
+    );
+
+    // only the cw20 LP token can be converted
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("asset0000", &[]),
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "addr0001".to_string(),
+            amount: Uint128::new(600u128),
+            msg: to_binary(&Cw20HookMsg::ConvertToTokenFactoryLp {}).unwrap(),
+        }),
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    // the cw20 LP tokens can still be withdrawn without converting them first
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("liquidity0000", &[]),
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "addr0001".to_string(),
+            amount: Uint128::new(100u128),
+            msg: to_binary(&Cw20HookMsg::WithdrawLiquidity {}).unwrap(),
+        }),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages
+            .into_iter()
+            .map(|sub_msg| sub_msg.msg)
+            .collect::<Vec<_>>(),
+        vec![
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "addr0001".to_string(),
+                amount: coins(100u128, "uusd"),
+            }),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "asset0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "addr0001".to_string(),
+                    amount: Uint128::new(100u128),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "liquidity0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Burn {
+                    amount: Uint128::new(100u128),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+        ]
+    );
+}
+
+#[test]
+fn migrate_cw20_lp_to_token_factory_lp_on_contract_migration() {
+    let lp_denom = format!("{}/{MOCK_CONTRACT_ADDR}/{LP_SYMBOL}", "factory");
+    let mut deps = mock_dependencies(&[]);
+    deps.querier.with_token_balances(&[(
+        &"asset0000".to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(1_000u128))],
+    )]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::zero(),
+            },
+            swap_fee: Fee {
+                share: Decimal::zero(),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    // store the cw20 liquidity token
+    let reply_msg = Reply {
+        id: 1,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(
+                vec![
+                    10, 13, 108, 105, 113, 117, 105, 100, 105, 116, 121, 48, 48, 48, 48,
+                ]
+                .into(),
+            ),
+        }),
+    };
+    reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+
+    // migrating from a version before v1.4.0 moves the pair to a token factory LP
+    set_contract_version(&mut deps.storage, "white_whale-pool", "1.3.0").unwrap();
+    let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    assert_eq!(
+        res.messages
+            .into_iter()
+            .map(|sub_msg| sub_msg.msg)
+            .collect::<Vec<_>>(),
+        create_lp_denom_msgs(
+            &Addr::unchecked(MOCK_CONTRACT_ADDR),
+            ["uusd".to_string(), "mAAPL".to_string()]
+        )
+    );
+
+    let pair_info: PairInfo =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Pair {}).unwrap()).unwrap();
+    assert_eq!(
+        pair_info.liquidity_token,
+        AssetInfo::NativeToken { denom: lp_denom }
+    );
+}
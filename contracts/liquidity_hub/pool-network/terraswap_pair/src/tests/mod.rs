@@ -1,4 +1,5 @@
 mod feature_toggle;
+mod lp_migration;
 mod protocol_fees;
 mod provide_liquidity;
 mod queries;
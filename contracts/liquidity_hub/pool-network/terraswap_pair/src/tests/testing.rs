@@ -7,7 +7,7 @@ use cw20::MinterResponse;
 
 use white_whale::fee::Fee;
 use white_whale::pool_network::asset::{Asset, AssetInfo, PairInfo, PairType};
-use white_whale::pool_network::denom::MsgCreateDenom;
+use white_whale::pool_network::denom::{DenomUnit, Metadata, MsgCreateDenom, MsgSetDenomMetadata};
 use white_whale::pool_network::mock_querier::mock_dependencies;
 use white_whale::pool_network::pair::ExecuteMsg::UpdateConfig;
 use white_whale::pool_network::pair::{Config, InstantiateMsg, MigrateMsg, PoolFee, QueryMsg};
@@ -169,6 +169,32 @@ fn proper_initialization_tokenfactory_lp() {
 
     assert_eq!(res.messages[0].msg, expected);
 
+    let lp_denom = format!("{}/{MOCK_CONTRACT_ADDR}/{LP_SYMBOL}", "factory");
+    let expected = <MsgSetDenomMetadata as Into<CosmosMsg>>::into(MsgSetDenomMetadata {
+        sender: MOCK_CONTRACT_ADDR.to_string(),
+        metadata: Some(Metadata {
+            description: "uusd-mAAPL pair liquidity token".to_string(),
+            denom_units: vec![
+                DenomUnit {
+                    denom: lp_denom.clone(),
+                    exponent: 0,
+                    aliases: vec![],
+                },
+                DenomUnit {
+                    denom: "uusd-mAAPL-LP".to_string(),
+                    exponent: 6,
+                    aliases: vec![],
+                },
+            ],
+            base: lp_denom,
+            display: "uusd-mAAPL-LP".to_string(),
+            name: "uusd-mAAPL-LP".to_string(),
+            symbol: "uusd-mAAPL-LP".to_string(),
+        }),
+    });
+
+    assert_eq!(res.messages[1].msg, expected);
+
     // let's query the state
     let pair_info: PairInfo = query_pair_info(deps.as_ref()).unwrap();
     assert_eq!(
@@ -411,6 +411,8 @@ fn reverse_simulate_return_amount(
     Ok(res.offer_amount)
 }
 
+/// Gets the swap route for the given assets. As pairs can be removed from the factory after the
+/// route was added, a route going through a pair that is no longer on the factory is invalid.
 fn get_swap_route(
     deps: Deps,
     offer_asset_info: AssetInfo,
@@ -421,12 +423,40 @@ fn get_swap_route(
         ask_asset_info.clone().get_label(&deps)?.as_str(),
     ));
 
-    swap_route_key
-        .load(deps.storage)
-        .map_err(|_| ContractError::NoSwapRouteForAssets {
-            offer_asset: offer_asset_info.to_string(),
-            ask_asset: ask_asset_info.to_string(),
-        })
+    let swap_operations =
+        swap_route_key
+            .load(deps.storage)
+            .map_err(|_| ContractError::NoSwapRouteForAssets {
+                offer_asset: offer_asset_info.to_string(),
+                ask_asset: ask_asset_info.to_string(),
+            })?;
+
+    let config: Config = CONFIG.load(deps.storage)?;
+    let terraswap_factory = deps.api.addr_humanize(&config.terraswap_factory)?;
+    for operation in swap_operations.iter() {
+        let SwapOperation::TerraSwap {
+            offer_asset_info: operation_offer_asset_info,
+            ask_asset_info: operation_ask_asset_info,
+        } = operation;
+
+        query_pair_info(
+            &deps.querier,
+            terraswap_factory.clone(),
+            &[
+                operation_offer_asset_info.clone(),
+                operation_ask_asset_info.clone(),
+            ],
+        )
+        .map_err(|_| {
+            ContractError::InvalidSwapRoute(SwapRoute {
+                offer_asset_info: offer_asset_info.clone(),
+                ask_asset_info: ask_asset_info.clone(),
+                swap_operations: swap_operations.clone(),
+            })
+        })?;
+    }
+
+    Ok(swap_operations)
 }
 
 fn assert_operations(operations: &[SwapOperation]) -> Result<(), ContractError> {
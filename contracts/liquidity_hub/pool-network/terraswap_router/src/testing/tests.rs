@@ -293,6 +293,7 @@ fn execute_swap_operation() {
                 },
                 asset_decimals: [6u8, 6u8],
                 pair_type: PairType::ConstantProduct,
+                created_at: None,
             },
         )],
         &[("uusd".to_string(), 6u8)],
@@ -396,6 +397,7 @@ fn execute_swap_operation() {
                 },
                 asset_decimals: [6u8, 6u8],
                 pair_type: PairType::ConstantProduct,
+                created_at: None,
             },
         )],
         &[("uusd".to_string(), 6u8)],
@@ -506,6 +508,7 @@ fn query_buy_with_routes() {
                     },
                     asset_decimals: [6u8, 6u8],
                     pair_type: PairType::ConstantProduct,
+                    created_at: None,
                 },
             ),
             (
@@ -525,6 +528,7 @@ fn query_buy_with_routes() {
                     },
                     asset_decimals: [6u8, 6u8],
                     pair_type: PairType::ConstantProduct,
+                    created_at: None,
                 },
             ),
         ],
@@ -614,6 +618,7 @@ fn query_reverse_routes_with_from_native() {
                     ],
                     asset_decimals: [8u8, 6u8],
                     pair_type: PairType::ConstantProduct,
+                    created_at: None,
                 },
             ),
             (
@@ -633,6 +638,7 @@ fn query_reverse_routes_with_from_native() {
                     ],
                     asset_decimals: [8u8, 6u8],
                     pair_type: PairType::ConstantProduct,
+                    created_at: None,
                 },
             ),
         ],
@@ -750,6 +756,7 @@ fn query_reverse_routes_with_to_native() {
                     ],
                     asset_decimals: [8u8, 6u8],
                     pair_type: PairType::ConstantProduct,
+                    created_at: None,
                 },
             ),
             (
@@ -769,6 +776,7 @@ fn query_reverse_routes_with_to_native() {
                     ],
                     asset_decimals: [8u8, 6u8],
                     pair_type: PairType::ConstantProduct,
+                    created_at: None,
                 },
             ),
         ],
@@ -1001,6 +1009,7 @@ fn add_swap_routes() {
                     },
                     asset_decimals: [6u8, 6u8],
                     pair_type: PairType::ConstantProduct,
+                    created_at: None,
                 },
             ),
             (
@@ -1020,6 +1029,7 @@ fn add_swap_routes() {
                     },
                     asset_decimals: [6u8, 6u8],
                     pair_type: PairType::ConstantProduct,
+                    created_at: None,
                 },
             ),
             (
@@ -1039,6 +1049,7 @@ fn add_swap_routes() {
                     },
                     asset_decimals: [6u8, 6u8],
                     pair_type: PairType::ConstantProduct,
+                    created_at: None,
                 },
             ),
         ],
@@ -1167,8 +1178,15 @@ fn add_swap_routes() {
     };
 
     let res: Vec<SwapOperation> =
-        from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        from_binary(&query(deps.as_ref(), mock_env(), msg.clone()).unwrap()).unwrap();
     assert_eq!(res, swap_route_1.swap_operations);
+
+    // the pairs are removed from the factory, so the route is no longer valid
+    deps.querier.with_pool_factory(&[], &[]);
+    match query(deps.as_ref(), mock_env(), msg) {
+        Err(ContractError::InvalidSwapRoute(swap_route)) => assert_eq!(swap_route, swap_route_1),
+        _ => panic!("should return ContractError::InvalidSwapRoute"),
+    }
 }
 
 #[test]
@@ -1203,6 +1221,7 @@ fn add_swap_routes_invalid_route() {
                     },
                     asset_decimals: [6u8, 6u8],
                     pair_type: PairType::ConstantProduct,
+                    created_at: None,
                 },
             ),
             (
@@ -1222,6 +1241,7 @@ fn add_swap_routes_invalid_route() {
                     },
                     asset_decimals: [6u8, 6u8],
                     pair_type: PairType::ConstantProduct,
+                    created_at: None,
                 },
             ),
         ],
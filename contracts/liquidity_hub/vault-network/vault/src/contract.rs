@@ -16,9 +16,15 @@ use white_whale::vault_network::vault::{
 use crate::state::{initialize_fee, ALL_TIME_BURNED_FEES};
 use crate::{
     error::VaultError,
-    execute::{callback, collect_protocol_fees, deposit, flash_loan, receive, update_config},
+    execute::{
+        callback, collect_protocol_fees, deposit, flash_loan, rebalance, receive, update_config,
+        update_strategy,
+    },
     migrations,
-    queries::{get_config, get_fees, get_payback_amount, get_share},
+    queries::{
+        get_config, get_exchange_rate, get_fees, get_payback_amount, get_preview_deposit,
+        get_preview_withdraw, get_share, get_strategy,
+    },
     state::{ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES, CONFIG, LOAN_COUNTER},
 };
 
@@ -135,9 +141,15 @@ pub fn execute(
 ) -> Result<Response, VaultError> {
     match msg {
         ExecuteMsg::UpdateConfig(params) => update_config(deps, info, params),
-        ExecuteMsg::Deposit { amount } => deposit(deps, env, info, amount),
+        ExecuteMsg::Deposit {
+            amount,
+            receiver,
+            min_lp_out,
+        } => deposit(deps, env, info, amount, receiver, min_lp_out),
         ExecuteMsg::FlashLoan { amount, msg } => flash_loan(deps, env, info, amount, msg),
         ExecuteMsg::CollectProtocolFees {} => collect_protocol_fees(deps),
+        ExecuteMsg::UpdateStrategy { strategy } => update_strategy(deps, env, info, strategy),
+        ExecuteMsg::Rebalance {} => rebalance(deps, env, info),
         ExecuteMsg::Receive(msg) => receive(deps, env, info, msg),
         ExecuteMsg::Callback(msg) => callback(deps, env, info, msg),
     }
@@ -189,5 +201,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, VaultError>
         ),
         QueryMsg::GetPaybackAmount { amount } => get_payback_amount(deps, amount),
         QueryMsg::BurnedFees {} => get_fees(deps, true, ALL_TIME_BURNED_FEES, None),
+        QueryMsg::PreviewDeposit { amount } => get_preview_deposit(deps, env, amount),
+        QueryMsg::PreviewWithdraw { lp_amount } => get_preview_withdraw(deps, env, lp_amount),
+        QueryMsg::ExchangeRate {} => get_exchange_rate(deps, env),
+        QueryMsg::Strategy {} => get_strategy(deps),
     }
 }
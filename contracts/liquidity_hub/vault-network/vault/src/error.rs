@@ -1,4 +1,6 @@
-use cosmwasm_std::{ConversionOverflowError, DivideByZeroError, OverflowError, StdError, Uint128};
+use cosmwasm_std::{
+    ConversionOverflowError, Decimal, DivideByZeroError, OverflowError, StdError, Uint128,
+};
 use semver::Version;
 use thiserror::Error;
 
@@ -57,4 +59,31 @@ pub enum VaultError {
 
     #[error("Cannot deposit while flash-loaning")]
     DepositDuringLoan {},
+
+    #[error("Deposit would mint {lp_amount} lp tokens, less than the minimum of {min_lp_out}")]
+    MinLpOutNotMet {
+        lp_amount: Uint128,
+        min_lp_out: Uint128,
+    },
+
+    #[error("Withdrawal would return {asset_amount}, less than the minimum of {min_asset_out}")]
+    MinAssetOutNotMet {
+        asset_amount: Uint128,
+        min_asset_out: Uint128,
+    },
+
+    #[error("Cannot move funds to or from the strategy while flash-loaning")]
+    StrategyDuringLoan {},
+
+    #[error("The vault has no strategy")]
+    NoStrategy {},
+
+    #[error("Invalid deploy ratio {deploy_ratio}, it must be between 0 and 1")]
+    InvalidDeployRatio { deploy_ratio: Decimal },
+
+    #[error("The strategy returned funds up to a vault balance of {balance}, less than the expected {expected_balance}")]
+    StrategyWithdrawNotReturned {
+        expected_balance: Uint128,
+        balance: Uint128,
+    },
 }
@@ -0,0 +1,27 @@
+use cosmwasm_std::{DepsMut, Env, Response, Uint128};
+
+use crate::{error::VaultError, state::CONFIG};
+
+/// Checks the strategy returned the funds withdrawn from it
+pub fn after_strategy_withdraw(
+    deps: DepsMut,
+    env: Env,
+    expected_balance: Uint128,
+) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let balance = config
+        .asset_info
+        .query_pool(&deps.querier, deps.api, env.contract.address)?;
+    if balance < expected_balance {
+        return Err(VaultError::StrategyWithdrawNotReturned {
+            expected_balance,
+            balance,
+        });
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        ("method", "after_strategy_withdraw"),
+        ("balance", &balance.to_string()),
+    ]))
+}
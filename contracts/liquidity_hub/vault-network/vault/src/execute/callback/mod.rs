@@ -1,5 +1,7 @@
+mod after_strategy_withdraw;
 mod after_trade;
 
+pub use after_strategy_withdraw::after_strategy_withdraw;
 pub use after_trade::after_trade;
 
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
@@ -23,6 +25,9 @@ pub fn callback(
             old_balance,
             loan_amount,
         } => after_trade(deps, env, old_balance, loan_amount),
+        CallbackMsg::AfterStrategyWithdraw { expected_balance } => {
+            after_strategy_withdraw(deps, env, expected_balance)
+        }
     }
 }
 
@@ -5,7 +5,8 @@ use white_whale::pool_network::{asset::AssetInfo, querier::query_token_info};
 
 use crate::{
     error::VaultError,
-    state::{COLLECTED_PROTOCOL_FEES, CONFIG, LOAN_COUNTER},
+    helpers::{compute_lp_mint_amount, get_total_deposits},
+    state::{CONFIG, LOAN_COUNTER},
 };
 
 pub fn deposit(
@@ -13,6 +14,8 @@ pub fn deposit(
     env: Env,
     info: MessageInfo,
     amount: Uint128,
+    receiver: Option<String>,
+    min_lp_out: Option<Uint128>,
 ) -> Result<Response, VaultError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -88,23 +91,32 @@ pub fn deposit(
         };
 
         // return based on a share of the total pool
-        let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
-        let total_deposits = config
-            .asset_info
-            .query_pool(&deps.querier, deps.api, env.contract.address)?
-            .checked_sub(collected_protocol_fees.amount)?
-            .checked_sub(deposit_amount)?;
+        let total_deposits =
+            get_total_deposits(deps.as_ref(), &env, &config)?.checked_sub(deposit_amount)?;
 
-        amount
-            .checked_mul(total_lp_share)?
-            .checked_div(total_deposits)?
+        compute_lp_mint_amount(amount, total_deposits, total_lp_share)?
+    };
+
+    // protect the depositor against share price manipulation
+    if let Some(min_lp_out) = min_lp_out {
+        if lp_amount < min_lp_out {
+            return Err(VaultError::MinLpOutNotMet {
+                lp_amount,
+                min_lp_out,
+            });
+        }
+    }
+
+    let receiver = match receiver {
+        Some(receiver) => deps.api.addr_validate(&receiver)?,
+        None => info.sender,
     };
 
     messages.push(
         WasmMsg::Execute {
             contract_addr: config.liquidity_token.into_string(),
             msg: to_binary(&Cw20ExecuteMsg::Mint {
-                recipient: info.sender.into_string(),
+                recipient: receiver.to_string(),
                 amount: lp_amount,
             })?,
             funds: vec![],
@@ -180,6 +192,8 @@ mod test {
             mock_info("creator", &coins(5_000, "uluna")),
             white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
+                min_lp_out: None,
             },
         );
 
@@ -243,6 +257,8 @@ mod test {
             mock_creator(),
             white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
+                min_lp_out: None,
             },
         );
 
@@ -283,6 +299,8 @@ mod test {
             },
             white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
+                min_lp_out: None,
             },
         );
 
@@ -328,6 +346,8 @@ mod test {
             mock_creator(),
             white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
+                min_lp_out: None,
             },
         );
 
@@ -370,6 +390,8 @@ mod test {
             mock_creator(),
             white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
+                min_lp_out: None,
             },
         );
 
@@ -408,6 +430,8 @@ mod test {
             mock_creator(),
             white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
+                min_lp_out: None,
             },
         );
 
@@ -442,6 +466,8 @@ mod test {
             vault_addr.clone(),
             &white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(10_000),
+                receiver: None,
+                min_lp_out: None,
             },
             &coins(10_000, "uluna"),
         )
@@ -484,6 +510,8 @@ mod test {
             vault_addr.clone(),
             &white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
+                min_lp_out: None,
             },
             &coins(5_000, "uluna"),
         )
@@ -508,6 +536,8 @@ mod test {
             vault_addr,
             &white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(8_000),
+                receiver: None,
+                min_lp_out: None,
             },
             &coins(8_000, "uluna"),
         )
@@ -532,4 +562,126 @@ mod test {
         // depositor2 is entitled to 3,333 / 18,666 of the total LP supply or 5,000 tokens
         // depositor3 is entitled to 5,333 / 18,666 of the total LP supply or 8,000 tokens
     }
+
+    #[test]
+    fn can_deposit_to_receiver() {
+        let receiver = Addr::unchecked("receiver");
+
+        let mut app = mock_app_with_balance(vec![(mock_creator().sender, coins(10_000, "uluna"))]);
+
+        let vault_addr = app_mock_instantiate(
+            &mut app,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &white_whale::vault_network::vault::ExecuteMsg::Deposit {
+                amount: Uint128::new(10_000),
+                receiver: Some(receiver.to_string()),
+                min_lp_out: None,
+            },
+            &coins(10_000, "uluna"),
+        )
+        .unwrap();
+
+        let config: Config = app
+            .wrap()
+            .query_wasm_smart(
+                vault_addr,
+                &white_whale::vault_network::vault::QueryMsg::Config {},
+            )
+            .unwrap();
+
+        // the lp tokens are minted to the receiver rather than the depositor
+        for (address, expected) in [
+            (receiver.into_string(), Uint128::new(10_000)),
+            (mock_creator().sender.into_string(), Uint128::zero()),
+        ] {
+            let cw20_balance: cw20::BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    config.liquidity_token.clone(),
+                    &cw20::Cw20QueryMsg::Balance { address },
+                )
+                .unwrap();
+            assert_eq!(cw20_balance.balance, expected);
+        }
+    }
+
+    #[test]
+    fn does_enforce_min_lp_out() {
+        let second_depositor = Addr::unchecked("depositor2");
+
+        let mut app = mock_app_with_balance(vec![
+            (mock_creator().sender, coins(15_000, "uluna")),
+            (second_depositor.clone(), coins(5_000, "uluna")),
+        ]);
+
+        let vault_addr = app_mock_instantiate(
+            &mut app,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &white_whale::vault_network::vault::ExecuteMsg::Deposit {
+                amount: Uint128::new(10_000),
+                receiver: None,
+                min_lp_out: None,
+            },
+            &coins(10_000, "uluna"),
+        )
+        .unwrap();
+
+        // the share price goes up between the user building the tx and it being executed
+        app.execute(
+            mock_creator().sender,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: vault_addr.to_string(),
+                amount: coins(5_000, "uluna"),
+            }),
+        )
+        .unwrap();
+
+        // depositor2 would get (5000 / 15000) * 10000 = 3,333 LP tokens
+        let err = app
+            .execute_contract(
+                second_depositor.clone(),
+                vault_addr.clone(),
+                &white_whale::vault_network::vault::ExecuteMsg::Deposit {
+                    amount: Uint128::new(5_000),
+                    receiver: None,
+                    min_lp_out: Some(Uint128::new(5_000)),
+                },
+                &coins(5_000, "uluna"),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast::<VaultError>().unwrap(),
+            VaultError::MinLpOutNotMet {
+                lp_amount: Uint128::new(3_333),
+                min_lp_out: Uint128::new(5_000),
+            }
+        );
+
+        app.execute_contract(
+            second_depositor,
+            vault_addr,
+            &white_whale::vault_network::vault::ExecuteMsg::Deposit {
+                amount: Uint128::new(5_000),
+                receiver: None,
+                min_lp_out: Some(Uint128::new(3_333)),
+            },
+            &coins(5_000, "uluna"),
+        )
+        .unwrap();
+    }
 }
@@ -8,7 +8,8 @@ use white_whale::vault_network::vault::{CallbackMsg, ExecuteMsg};
 
 use crate::{
     error::VaultError,
-    state::{CONFIG, LOAN_COUNTER},
+    helpers::get_strategy_withdraw_msgs,
+    state::{CONFIG, LOAN_COUNTER, STRATEGY},
 };
 
 pub fn flash_loan(
@@ -31,7 +32,7 @@ pub fn flash_loan(
     })?;
 
     // store current balance for after trade profit check
-    let old_balance = match config.asset_info.clone() {
+    let mut old_balance = match config.asset_info.clone() {
         AssetInfo::NativeToken { denom } => {
             deps.querier
                 .query_balance(env.contract.address.clone(), denom)?
@@ -50,6 +51,22 @@ pub fn flash_loan(
 
     let mut messages: Vec<CosmosMsg> = vec![];
 
+    // pull the missing funds from the strategy if the liquid buffer can't cover the loan. They are
+    // part of the balance the loan has to be paid back to, so they are not taken as profit
+    if amount > old_balance {
+        if let Some(strategy) = STRATEGY.may_load(deps.storage)? {
+            let shortfall = amount.checked_sub(old_balance)?;
+            messages.extend(get_strategy_withdraw_msgs(
+                deps.as_ref(),
+                &env,
+                &config,
+                &strategy,
+                shortfall,
+            )?);
+            old_balance = old_balance.checked_add(shortfall)?;
+        }
+    }
+
     // create message to send funds to sender if cw20 token
     if let AssetInfo::Token { contract_addr } = config.asset_info.clone() {
         let loan_msg = WasmMsg::Execute {
@@ -104,16 +121,21 @@ mod test {
     use cosmwasm_std::{
         coins,
         testing::{mock_dependencies, mock_dependencies_with_balance, mock_env},
-        to_binary, Addr, BankMsg, Response, Uint128, WasmMsg,
+        to_binary, Addr, BankMsg, Decimal, Response, Uint128, WasmMsg,
     };
+    use cw_multi_test::Executor;
     use white_whale::pool_network::asset::AssetInfo;
-    use white_whale::vault_network::vault::Config;
+    use white_whale::vault_network::vault::{Config, ExecuteMsg, StrategyParams};
 
     use crate::{
         contract::{execute, instantiate},
         error::VaultError,
         state::{CONFIG, LOAN_COUNTER},
-        tests::{get_fees, mock_creator, mock_dependencies_lp},
+        tests::{
+            get_fees, mock_app::mock_app_with_balance, mock_borrower::app_mock_borrower,
+            mock_creator, mock_dependencies_lp, mock_instantiate::app_mock_instantiate,
+            mock_strategy::app_mock_strategy,
+        },
     };
 
     #[test]
@@ -332,4 +354,80 @@ mod test {
                 ])
         );
     }
+
+    #[test]
+    fn can_loan_more_than_liquid_buffer() {
+        let mut app = mock_app_with_balance(vec![(mock_creator().sender, coins(11_000, "uluna"))]);
+
+        let vault_addr = app_mock_instantiate(
+            &mut app,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+        let strategy_addr = app_mock_strategy(
+            &mut app,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+            true,
+        );
+        let borrower_addr = app_mock_borrower(&mut app, "uluna");
+
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::Deposit {
+                amount: Uint128::new(10_000),
+                receiver: None,
+                min_lp_out: None,
+            },
+            &coins(10_000, "uluna"),
+        )
+        .unwrap();
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::UpdateStrategy {
+                strategy: Some(StrategyParams {
+                    contract_addr: strategy_addr.to_string(),
+                    deploy_ratio: Decimal::percent(60),
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::Rebalance {},
+            &[],
+        )
+        .unwrap();
+
+        // the borrower pays the fees with its own funds
+        app.send_tokens(
+            mock_creator().sender,
+            borrower_addr.clone(),
+            &coins(1_000, "uluna"),
+        )
+        .unwrap();
+
+        // only 4,000 are liquid in the vault, so the missing 1,000 are pulled from the strategy
+        app.execute_contract(
+            mock_creator().sender,
+            borrower_addr.clone(),
+            &crate::tests::mock_borrower::ExecuteMsg::Borrow {
+                vault: vault_addr.to_string(),
+                amount: Uint128::new(5_000),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let balance = |address: &Addr| app.wrap().query_balance(address, "uluna").unwrap().amount;
+        assert_eq!(balance(&strategy_addr), Uint128::new(5_000));
+        assert_eq!(balance(&vault_addr), Uint128::new(6_000));
+        assert_eq!(balance(&borrower_addr), Uint128::zero());
+    }
 }
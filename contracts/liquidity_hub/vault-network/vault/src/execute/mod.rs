@@ -2,12 +2,16 @@ mod callback;
 mod collect_protocol_fee;
 mod deposit;
 mod flash_loan;
+mod rebalance;
 mod receive;
 mod update_config;
+mod update_strategy;
 
 pub use callback::callback;
 pub use collect_protocol_fee::collect_protocol_fees;
 pub use deposit::deposit;
 pub use flash_loan::flash_loan;
+pub use rebalance::rebalance;
 pub use receive::receive;
 pub use update_config::update_config;
+pub use update_strategy::update_strategy;
@@ -0,0 +1,482 @@
+use cosmwasm_std::{CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128};
+
+use crate::{
+    error::VaultError,
+    helpers::{
+        get_liquid_deposits, get_strategy_deploy_msgs, get_strategy_withdraw_msgs,
+        query_deployed_amount,
+    },
+    state::{CONFIG, LOAN_COUNTER, STRATEGY},
+};
+
+/// Moves funds between the vault and its strategy so that the deployed amount matches the
+/// strategy's deploy ratio. Only the owner can rebalance, as moving funds right before a large
+/// deposit or withdrawal could be used to game the liquid buffer.
+pub fn rebalance(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.owner != info.sender {
+        return Err(VaultError::Unauthorized {});
+    }
+
+    // withdrawing from the strategy during a loan would be taken as profit by the vault
+    if LOAN_COUNTER.load(deps.storage)? != 0 {
+        return Err(VaultError::StrategyDuringLoan {});
+    }
+
+    let strategy = STRATEGY
+        .may_load(deps.storage)?
+        .ok_or(VaultError::NoStrategy {})?;
+
+    let liquid_deposits = get_liquid_deposits(deps.as_ref(), &env, &config)?;
+    let deployed_amount = query_deployed_amount(deps.as_ref())?;
+    let target_amount = strategy.deploy_ratio * liquid_deposits.checked_add(deployed_amount)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut deployed = Uint128::zero();
+    let mut withdrawn = Uint128::zero();
+
+    if target_amount > deployed_amount {
+        // the protocol fees are kept in the vault, so only the liquid deposits can be deployed
+        deployed = target_amount
+            .checked_sub(deployed_amount)?
+            .min(liquid_deposits);
+        if !deployed.is_zero() {
+            messages.extend(get_strategy_deploy_msgs(
+                &config.asset_info,
+                &strategy,
+                deployed,
+            )?);
+        }
+    } else if deployed_amount > target_amount {
+        withdrawn = deployed_amount.checked_sub(target_amount)?;
+        messages.extend(get_strategy_withdraw_msgs(
+            deps.as_ref(),
+            &env,
+            &config,
+            &strategy,
+            withdrawn,
+        )?);
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("method", "rebalance"),
+        ("deployed", &deployed.to_string()),
+        ("withdrawn", &withdrawn.to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{
+        coins, testing::mock_info, to_binary, Addr, BankMsg, CosmosMsg, Decimal, Uint128,
+    };
+    use cw20::BalanceResponse;
+    use cw20::Cw20ExecuteMsg;
+    use cw_multi_test::{App, Executor};
+
+    use white_whale::pool_network::asset::AssetInfo;
+    use white_whale::vault_network::vault::{
+        Config, Cw20HookMsg, ExecuteMsg, QueryMsg, StrategyParams, StrategyResponse,
+    };
+
+    use crate::{
+        contract::execute,
+        error::VaultError,
+        state::LOAN_COUNTER,
+        tests::{
+            mock_app::mock_app_with_balance, mock_creator, mock_instantiate::app_mock_instantiate,
+            mock_instantiate::mock_instantiate, mock_strategy::app_mock_strategy,
+            store_code::store_cw20_token_code,
+        },
+    };
+
+    fn balance(app: &App, address: &Addr) -> Uint128 {
+        app.wrap().query_balance(address, "uluna").unwrap().amount
+    }
+
+    #[test]
+    fn does_rebalance_with_strategy() {
+        let mut app = mock_app_with_balance(vec![(mock_creator().sender, coins(11_000, "uluna"))]);
+
+        let vault_addr = app_mock_instantiate(
+            &mut app,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+        let strategy_addr = app_mock_strategy(
+            &mut app,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+            true,
+        );
+
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::Deposit {
+                amount: Uint128::new(10_000),
+                receiver: None,
+                min_lp_out: None,
+            },
+            &coins(10_000, "uluna"),
+        )
+        .unwrap();
+
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::UpdateStrategy {
+                strategy: Some(StrategyParams {
+                    contract_addr: strategy_addr.to_string(),
+                    deploy_ratio: Decimal::percent(60),
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // 60% of the deposits are deployed to the strategy
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::Rebalance {},
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &strategy_addr), Uint128::new(6_000));
+        assert_eq!(balance(&app, &vault_addr), Uint128::new(4_000));
+
+        // the strategy generates yield, which is accounted for in the share of the lp holders
+        app.execute(
+            mock_creator().sender,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: strategy_addr.to_string(),
+                amount: coins(1_000, "uluna"),
+            }),
+        )
+        .unwrap();
+
+        let strategy: StrategyResponse = app
+            .wrap()
+            .query_wasm_smart(vault_addr.clone(), &QueryMsg::Strategy {})
+            .unwrap();
+        assert_eq!(strategy.deployed_amount, Uint128::new(7_000));
+
+        let share: Uint128 = app
+            .wrap()
+            .query_wasm_smart(
+                vault_addr.clone(),
+                &QueryMsg::Share {
+                    amount: Uint128::new(10_000),
+                },
+            )
+            .unwrap();
+        assert_eq!(share, Uint128::new(11_000));
+
+        // withdrawing half the lp tokens is worth 5,500 uluna, more than the 4,000 liquid in the
+        // vault, so the missing 1,500 are pulled from the strategy
+        let config: Config = app
+            .wrap()
+            .query_wasm_smart(vault_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+        app.execute_contract(
+            mock_creator().sender,
+            config.liquidity_token,
+            &Cw20ExecuteMsg::Send {
+                contract: vault_addr.to_string(),
+                amount: Uint128::new(5_000),
+                msg: to_binary(&Cw20HookMsg::Withdraw {
+                    receiver: None,
+                    min_asset_out: None,
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &mock_creator().sender), Uint128::new(5_500));
+        assert_eq!(balance(&app, &strategy_addr), Uint128::new(5_500));
+        assert_eq!(balance(&app, &vault_addr), Uint128::zero());
+
+        // rebalancing refills the liquid buffer to 40% of the 5,500 deposits
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::Rebalance {},
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &strategy_addr), Uint128::new(3_300));
+        assert_eq!(balance(&app, &vault_addr), Uint128::new(2_200));
+
+        // removing the strategy brings all the funds back
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::UpdateStrategy { strategy: None },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &strategy_addr), Uint128::zero());
+        assert_eq!(balance(&app, &vault_addr), Uint128::new(5_500));
+
+        let strategy: StrategyResponse = app
+            .wrap()
+            .query_wasm_smart(vault_addr, &QueryMsg::Strategy {})
+            .unwrap();
+        assert_eq!(
+            strategy,
+            StrategyResponse {
+                strategy: None,
+                deployed_amount: Uint128::zero(),
+            }
+        );
+    }
+
+    #[test]
+    fn cannot_rebalance_without_strategy() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let res = execute(deps.as_mut(), env, mock_creator(), ExecuteMsg::Rebalance {});
+        assert_eq!(res.unwrap_err(), VaultError::NoStrategy {});
+    }
+
+    #[test]
+    fn cannot_rebalance_during_loan() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+        LOAN_COUNTER.save(&mut deps.storage, &1).unwrap();
+
+        let res = execute(deps.as_mut(), env, mock_creator(), ExecuteMsg::Rebalance {});
+        assert_eq!(res.unwrap_err(), VaultError::StrategyDuringLoan {});
+    }
+
+    #[test]
+    fn cannot_rebalance_unauthorized() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::Rebalance {},
+        );
+        assert_eq!(res.unwrap_err(), VaultError::Unauthorized {});
+    }
+
+    #[test]
+    fn does_rebalance_with_cw20_strategy() {
+        let mut app = mock_app_with_balance(vec![]);
+
+        let token_id = store_cw20_token_code(&mut app);
+        let token_addr = app
+            .instantiate_contract(
+                token_id,
+                mock_creator().sender,
+                &cw20_base::msg::InstantiateMsg {
+                    decimals: 6,
+                    initial_balances: vec![cw20::Cw20Coin {
+                        address: mock_creator().sender.into_string(),
+                        amount: Uint128::new(10_000),
+                    }],
+                    marketing: None,
+                    mint: None,
+                    name: "CASH".to_string(),
+                    symbol: "CASH".to_string(),
+                },
+                &[],
+                "cw20_token",
+                None,
+            )
+            .unwrap();
+        let asset_info = AssetInfo::Token {
+            contract_addr: token_addr.to_string(),
+        };
+        let token_balance = |app: &App, address: &Addr| -> Uint128 {
+            let res: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_addr.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: address.to_string(),
+                    },
+                )
+                .unwrap();
+            res.balance
+        };
+
+        let vault_addr = app_mock_instantiate(&mut app, asset_info.clone());
+        let strategy_addr = app_mock_strategy(&mut app, asset_info, true);
+
+        app.execute_contract(
+            mock_creator().sender,
+            token_addr.clone(),
+            &Cw20ExecuteMsg::IncreaseAllowance {
+                spender: vault_addr.to_string(),
+                amount: Uint128::new(10_000),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::Deposit {
+                amount: Uint128::new(10_000),
+                receiver: None,
+                min_lp_out: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::UpdateStrategy {
+                strategy: Some(StrategyParams {
+                    contract_addr: strategy_addr.to_string(),
+                    deploy_ratio: Decimal::percent(60),
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::Rebalance {},
+            &[],
+        )
+        .unwrap();
+        assert_eq!(token_balance(&app, &strategy_addr), Uint128::new(6_000));
+        assert_eq!(token_balance(&app, &vault_addr), Uint128::new(4_000));
+
+        // withdrawing half the lp tokens pulls the missing 1,000 tokens from the strategy
+        let config: Config = app
+            .wrap()
+            .query_wasm_smart(vault_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+        app.execute_contract(
+            mock_creator().sender,
+            config.liquidity_token,
+            &Cw20ExecuteMsg::Send {
+                contract: vault_addr.to_string(),
+                amount: Uint128::new(5_000),
+                msg: to_binary(&Cw20HookMsg::Withdraw {
+                    receiver: None,
+                    min_asset_out: None,
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            token_balance(&app, &mock_creator().sender),
+            Uint128::new(5_000)
+        );
+        assert_eq!(token_balance(&app, &strategy_addr), Uint128::new(5_000));
+        assert_eq!(token_balance(&app, &vault_addr), Uint128::zero());
+    }
+
+    #[test]
+    fn cannot_withdraw_from_illiquid_strategy() {
+        let mut app = mock_app_with_balance(vec![(mock_creator().sender, coins(10_000, "uluna"))]);
+
+        let vault_addr = app_mock_instantiate(
+            &mut app,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+        let strategy_addr = app_mock_strategy(
+            &mut app,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+            false,
+        );
+
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::Deposit {
+                amount: Uint128::new(10_000),
+                receiver: None,
+                min_lp_out: None,
+            },
+            &coins(10_000, "uluna"),
+        )
+        .unwrap();
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::UpdateStrategy {
+                strategy: Some(StrategyParams {
+                    contract_addr: strategy_addr.to_string(),
+                    deploy_ratio: Decimal::percent(60),
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::Rebalance {},
+            &[],
+        )
+        .unwrap();
+
+        // the strategy doesn't return the funds right away, so the vault reverts the withdrawal
+        let config: Config = app
+            .wrap()
+            .query_wasm_smart(vault_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+        let err = app
+            .execute_contract(
+                mock_creator().sender,
+                config.liquidity_token,
+                &Cw20ExecuteMsg::Send {
+                    contract: vault_addr.to_string(),
+                    amount: Uint128::new(5_000),
+                    msg: to_binary(&Cw20HookMsg::Withdraw {
+                        receiver: None,
+                        min_asset_out: None,
+                    })
+                    .unwrap(),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            VaultError::StrategyWithdrawNotReturned {
+                expected_balance: Uint128::new(5_000),
+                balance: Uint128::new(4_000),
+            }
+            .to_string()
+        );
+        assert_eq!(balance(&app, &strategy_addr), Uint128::new(6_000));
+        assert_eq!(balance(&app, &vault_addr), Uint128::new(4_000));
+    }
+}
@@ -22,7 +22,10 @@ pub fn receive(
     }
 
     match from_binary(&msg.msg)? {
-        Cw20HookMsg::Withdraw {} => withdraw(deps, env, msg.sender, msg.amount),
+        Cw20HookMsg::Withdraw {
+            receiver,
+            min_asset_out,
+        } => withdraw(deps, env, msg.sender, msg.amount, receiver, min_asset_out),
     }
 }
 
@@ -53,8 +56,11 @@ mod test {
             white_whale::vault_network::vault::Cw20ReceiveMsg {
                 sender: mock_creator().sender.into_string(),
                 amount: Uint128::new(5_000),
-                msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {})
-                    .unwrap(),
+                msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {
+                    receiver: None,
+                    min_asset_out: None,
+                })
+                .unwrap(),
             },
         );
 
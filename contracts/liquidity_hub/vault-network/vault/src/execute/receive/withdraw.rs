@@ -1,11 +1,15 @@
 use cosmwasm_std::{
-    coins, to_binary, BankMsg, CosmosMsg, Decimal, DepsMut, Env, Response, Uint128, WasmMsg,
+    coins, to_binary, BankMsg, CosmosMsg, DepsMut, Env, Response, Uint128, WasmMsg,
 };
-use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, TokenInfoResponse};
+use cw20::Cw20ExecuteMsg;
 
 use white_whale::pool_network::asset::AssetInfo;
+use white_whale::pool_network::querier::query_token_info;
 
-use crate::state::COLLECTED_PROTOCOL_FEES;
+use crate::helpers::{
+    compute_asset_share, get_liquid_deposits, get_strategy_withdraw_msgs, query_deployed_amount,
+};
+use crate::state::STRATEGY;
 use crate::{error::VaultError, state::CONFIG};
 
 pub fn withdraw(
@@ -13,6 +17,8 @@ pub fn withdraw(
     env: Env,
     sender: String,
     amount: Uint128,
+    receiver: Option<String>,
+    min_asset_out: Option<Uint128>,
 ) -> Result<Response, VaultError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -21,47 +27,54 @@ pub fn withdraw(
         return Err(VaultError::WithdrawsDisabled {});
     }
 
-    // parse sender
-    let sender = deps.api.addr_validate(&sender)?;
+    // parse receiver, defaulting to the sender of the lp tokens
+    let receiver = deps.api.addr_validate(&receiver.unwrap_or(sender))?;
 
     // calculate the size of vault and the amount of assets to withdraw
-    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
-    let total_asset_amount = match &config.asset_info {
-        AssetInfo::NativeToken { denom } => {
-            deps.querier
-                .query_balance(env.contract.address, denom)?
-                .amount
-        }
-        AssetInfo::Token { contract_addr } => {
-            let balance: BalanceResponse = deps.querier.query_wasm_smart(
-                contract_addr,
-                &Cw20QueryMsg::Balance {
-                    address: env.contract.address.into_string(),
-                },
-            )?;
-            balance.balance
+    let liquid_asset_amount = get_liquid_deposits(deps.as_ref(), &env, &config)?;
+    let total_asset_amount =
+        liquid_asset_amount.checked_add(query_deployed_amount(deps.as_ref())?)?;
+
+    let total_share_amount =
+        query_token_info(&deps.querier, config.liquidity_token.clone())?.total_supply;
+    let withdraw_amount = compute_asset_share(amount, total_asset_amount, total_share_amount);
+
+    // protect the withdrawer against share price manipulation
+    if let Some(min_asset_out) = min_asset_out {
+        if withdraw_amount < min_asset_out {
+            return Err(VaultError::MinAssetOutNotMet {
+                asset_amount: withdraw_amount,
+                min_asset_out,
+            });
         }
-    } // deduct protocol fees
-    .checked_sub(collected_protocol_fees.amount)?;
+    }
 
-    let total_share_amount: TokenInfoResponse = deps
-        .querier
-        .query_wasm_smart(config.liquidity_token.clone(), &Cw20QueryMsg::TokenInfo {})?;
-    let withdraw_amount =
-        Decimal::from_ratio(amount, total_share_amount.total_supply) * total_asset_amount;
+    let mut messages: Vec<CosmosMsg> = vec![];
+
+    // pull the missing funds from the strategy if the liquid buffer can't cover the withdrawal
+    if withdraw_amount > liquid_asset_amount {
+        let strategy = STRATEGY.load(deps.storage)?;
+        messages.extend(get_strategy_withdraw_msgs(
+            deps.as_ref(),
+            &env,
+            &config,
+            &strategy,
+            withdraw_amount.checked_sub(liquid_asset_amount)?,
+        )?);
+    }
 
     // create message to send back to user if cw20
-    let messages: Vec<CosmosMsg> = vec![
+    messages.extend(vec![
         match config.asset_info {
             AssetInfo::NativeToken { denom } => BankMsg::Send {
-                to_address: sender.into_string(),
+                to_address: receiver.to_string(),
                 amount: coins(withdraw_amount.u128(), denom),
             }
             .into(),
             AssetInfo::Token { contract_addr } => WasmMsg::Execute {
                 contract_addr,
                 msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                    recipient: sender.into_string(),
+                    recipient: receiver.to_string(),
                     amount: withdraw_amount,
                 })?,
                 funds: vec![],
@@ -74,7 +87,7 @@ pub fn withdraw(
             msg: to_binary(&Cw20ExecuteMsg::Burn { amount })?,
         }
         .into(),
-    ];
+    ]);
 
     Ok(Response::new().add_messages(messages).add_attributes(vec![
         ("method", "withdraw"),
@@ -121,8 +134,11 @@ mod tests {
                 white_whale::vault_network::vault::Cw20ReceiveMsg {
                     sender: mock_creator().sender.into_string(),
                     amount: Uint128::new(5_000),
-                    msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {})
-                        .unwrap(),
+                    msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {
+                        receiver: None,
+                        min_asset_out: None,
+                    })
+                    .unwrap(),
                 },
             ),
         );
@@ -156,8 +172,11 @@ mod tests {
                 white_whale::vault_network::vault::Cw20ReceiveMsg {
                     amount: Uint128::new(2_000),
                     sender: mock_creator().sender.into_string(),
-                    msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {})
-                        .unwrap(),
+                    msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {
+                        receiver: None,
+                        min_asset_out: None,
+                    })
+                    .unwrap(),
                 },
             ),
         );
@@ -191,6 +210,8 @@ mod tests {
             vault_addr.clone(),
             &white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(10_000),
+                receiver: None,
+                min_lp_out: None,
             },
             &coins(10_000, "uluna"),
         )
@@ -203,8 +224,11 @@ mod tests {
             &Cw20ExecuteMsg::Send {
                 contract: vault_addr.to_string(),
                 amount: Uint128::new(5_000),
-                msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {})
-                    .unwrap(),
+                msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {
+                    receiver: None,
+                    min_asset_out: None,
+                })
+                .unwrap(),
             },
             &[],
         )
@@ -293,6 +317,8 @@ mod tests {
             vault_addr.clone(),
             &white_whale::vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(10_000),
+                receiver: None,
+                min_lp_out: None,
             },
             &[],
         )
@@ -305,8 +331,11 @@ mod tests {
             &Cw20ExecuteMsg::Send {
                 contract: vault_addr.to_string(),
                 amount: Uint128::new(5_000),
-                msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {})
-                    .unwrap(),
+                msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {
+                    receiver: None,
+                    min_asset_out: None,
+                })
+                .unwrap(),
             },
             &[],
         )
@@ -401,8 +430,11 @@ mod tests {
                 white_whale::vault_network::vault::Cw20ReceiveMsg {
                     amount: Uint128::new(5_000),
                     sender: mock_creator().sender.into_string(),
-                    msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {})
-                        .unwrap(),
+                    msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {
+                        receiver: None,
+                        min_asset_out: None,
+                    })
+                    .unwrap(),
                 },
             ),
         )
@@ -509,8 +541,11 @@ mod tests {
                 white_whale::vault_network::vault::Cw20ReceiveMsg {
                     amount: Uint128::new(5_000),
                     sender: mock_creator().sender.into_string(),
-                    msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {})
-                        .unwrap(),
+                    msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {
+                        receiver: None,
+                        min_asset_out: None,
+                    })
+                    .unwrap(),
                 },
             ),
         )
@@ -557,4 +592,93 @@ mod tests {
                 ])
         );
     }
+
+    #[test]
+    fn can_withdraw_to_receiver_with_min_asset_out() {
+        let receiver = Addr::unchecked("receiver");
+
+        let mut app = mock_app_with_balance(vec![(mock_creator().sender, coins(10_000, "uluna"))]);
+
+        let vault_addr = app_mock_instantiate(
+            &mut app,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let config: Config = app
+            .wrap()
+            .query_wasm_smart(
+                vault_addr.clone(),
+                &white_whale::vault_network::vault::QueryMsg::Config {},
+            )
+            .unwrap();
+
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &white_whale::vault_network::vault::ExecuteMsg::Deposit {
+                amount: Uint128::new(10_000),
+                receiver: None,
+                min_lp_out: None,
+            },
+            &coins(10_000, "uluna"),
+        )
+        .unwrap();
+
+        // withdrawing 5,000 LP tokens returns 5,000 uluna, less than the minimum
+        let err = app
+            .execute_contract(
+                mock_creator().sender,
+                config.liquidity_token.clone(),
+                &Cw20ExecuteMsg::Send {
+                    contract: vault_addr.to_string(),
+                    amount: Uint128::new(5_000),
+                    msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {
+                        receiver: Some(receiver.to_string()),
+                        min_asset_out: Some(Uint128::new(5_001)),
+                    })
+                    .unwrap(),
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast::<VaultError>().unwrap(),
+            VaultError::MinAssetOutNotMet {
+                asset_amount: Uint128::new(5_000),
+                min_asset_out: Uint128::new(5_001),
+            }
+        );
+
+        app.execute_contract(
+            mock_creator().sender,
+            config.liquidity_token,
+            &Cw20ExecuteMsg::Send {
+                contract: vault_addr.to_string(),
+                amount: Uint128::new(5_000),
+                msg: to_binary(&white_whale::vault_network::vault::Cw20HookMsg::Withdraw {
+                    receiver: Some(receiver.to_string()),
+                    min_asset_out: Some(Uint128::new(5_000)),
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // the assets are sent to the receiver rather than the lp token holder
+        assert_eq!(
+            app.wrap().query_balance(receiver, "uluna").unwrap().amount,
+            Uint128::new(5_000)
+        );
+        assert_eq!(
+            app.wrap()
+                .query_balance(mock_creator().sender, "uluna")
+                .unwrap()
+                .amount,
+            Uint128::zero()
+        );
+    }
 }
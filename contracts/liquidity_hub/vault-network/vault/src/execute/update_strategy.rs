@@ -0,0 +1,176 @@
+use cosmwasm_std::{CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response};
+
+use white_whale::vault_network::vault::{Strategy, StrategyParams};
+
+use crate::{
+    error::VaultError,
+    helpers::{get_strategy_withdraw_msgs, query_deployed_amount},
+    state::{CONFIG, LOAN_COUNTER, STRATEGY},
+};
+
+/// Sets or removes the strategy idle funds are deployed to
+pub fn update_strategy(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    strategy: Option<StrategyParams>,
+) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.owner != info.sender {
+        return Err(VaultError::Unauthorized {});
+    }
+
+    // withdrawing from the strategy during a loan would be taken as profit by the vault
+    if LOAN_COUNTER.load(deps.storage)? != 0 {
+        return Err(VaultError::StrategyDuringLoan {});
+    }
+
+    let new_strategy = strategy
+        .map(|params| -> Result<Strategy, VaultError> {
+            if params.deploy_ratio > Decimal::one() {
+                return Err(VaultError::InvalidDeployRatio {
+                    deploy_ratio: params.deploy_ratio,
+                });
+            }
+
+            Ok(Strategy {
+                contract_addr: deps.api.addr_validate(&params.contract_addr)?,
+                deploy_ratio: params.deploy_ratio,
+            })
+        })
+        .transpose()?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+
+    // bring back the funds deployed to the current strategy if it's being replaced or removed
+    if let Some(current_strategy) = STRATEGY.may_load(deps.storage)? {
+        let is_replaced = match &new_strategy {
+            Some(new_strategy) => new_strategy.contract_addr != current_strategy.contract_addr,
+            None => true,
+        };
+
+        let deployed_amount = query_deployed_amount(deps.as_ref())?;
+        if is_replaced && !deployed_amount.is_zero() {
+            messages.extend(get_strategy_withdraw_msgs(
+                deps.as_ref(),
+                &env,
+                &config,
+                &current_strategy,
+                deployed_amount,
+            )?);
+        }
+    }
+
+    match &new_strategy {
+        Some(strategy) => STRATEGY.save(deps.storage, strategy)?,
+        None => STRATEGY.remove(deps.storage),
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("method", "update_strategy".to_string()),
+        (
+            "strategy",
+            new_strategy
+                .as_ref()
+                .map_or("None".to_string(), |s| s.contract_addr.to_string()),
+        ),
+        (
+            "deploy_ratio",
+            new_strategy
+                .map_or(Decimal::zero(), |s| s.deploy_ratio)
+                .to_string(),
+        ),
+    ]))
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{testing::mock_info, Addr, Decimal};
+
+    use white_whale::pool_network::asset::AssetInfo;
+    use white_whale::vault_network::vault::{ExecuteMsg, Strategy, StrategyParams};
+
+    use crate::{
+        contract::execute,
+        error::VaultError,
+        state::STRATEGY,
+        tests::{mock_creator, mock_instantiate::mock_instantiate},
+    };
+
+    #[test]
+    fn does_set_strategy() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            ExecuteMsg::UpdateStrategy {
+                strategy: Some(StrategyParams {
+                    contract_addr: "strategy".to_string(),
+                    deploy_ratio: Decimal::percent(50),
+                }),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            STRATEGY.load(&deps.storage).unwrap(),
+            Strategy {
+                contract_addr: Addr::unchecked("strategy"),
+                deploy_ratio: Decimal::percent(50),
+            }
+        );
+    }
+
+    #[test]
+    fn cannot_set_strategy_unauthorized() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("unauthorized", &[]),
+            ExecuteMsg::UpdateStrategy { strategy: None },
+        );
+        assert_eq!(res.unwrap_err(), VaultError::Unauthorized {});
+    }
+
+    #[test]
+    fn cannot_set_invalid_deploy_ratio() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            ExecuteMsg::UpdateStrategy {
+                strategy: Some(StrategyParams {
+                    contract_addr: "strategy".to_string(),
+                    deploy_ratio: Decimal::percent(101),
+                }),
+            },
+        );
+        assert_eq!(
+            res.unwrap_err(),
+            VaultError::InvalidDeployRatio {
+                deploy_ratio: Decimal::percent(101)
+            }
+        );
+    }
+}
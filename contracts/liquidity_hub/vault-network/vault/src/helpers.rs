@@ -0,0 +1,138 @@
+use cosmwasm_std::{coins, to_binary, CosmosMsg, Decimal, Deps, Env, StdResult, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+
+use white_whale::pool_network::asset::AssetInfo;
+use white_whale::vault_network::vault::{
+    CallbackMsg, Config, ExecuteMsg, Strategy, StrategyExecuteMsg, StrategyQueryMsg,
+};
+
+use crate::error::VaultError;
+use crate::state::{COLLECTED_PROTOCOL_FEES, STRATEGY};
+
+/// Gets the amount of assets held by the vault itself on behalf of the LP holders, i.e. the vault
+/// balance minus the protocol fees that haven't been collected yet.
+pub fn get_liquid_deposits(deps: Deps, env: &Env, config: &Config) -> Result<Uint128, VaultError> {
+    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
+
+    Ok(config
+        .asset_info
+        .query_pool(&deps.querier, deps.api, env.contract.address.clone())?
+        .checked_sub(collected_protocol_fees.amount)?)
+}
+
+/// Gets the amount of assets held on behalf of the LP holders, including the ones deployed to the
+/// strategy.
+pub fn get_total_deposits(deps: Deps, env: &Env, config: &Config) -> Result<Uint128, VaultError> {
+    Ok(get_liquid_deposits(deps, env, config)?.checked_add(query_deployed_amount(deps)?)?)
+}
+
+/// Queries the amount of assets the vault is entitled to in its strategy, if it has any.
+pub fn query_deployed_amount(deps: Deps) -> Result<Uint128, VaultError> {
+    match STRATEGY.may_load(deps.storage)? {
+        Some(strategy) => Ok(deps
+            .querier
+            .query_wasm_smart(strategy.contract_addr, &StrategyQueryMsg::Value {})?),
+        None => Ok(Uint128::zero()),
+    }
+}
+
+/// Creates the messages to deploy `amount` of the vault asset to the given strategy.
+pub fn get_strategy_deploy_msgs(
+    asset_info: &AssetInfo,
+    strategy: &Strategy,
+    amount: Uint128,
+) -> StdResult<Vec<CosmosMsg>> {
+    let deploy_msg = to_binary(&StrategyExecuteMsg::Deploy { amount })?;
+
+    Ok(match asset_info {
+        AssetInfo::NativeToken { denom } => vec![WasmMsg::Execute {
+            contract_addr: strategy.contract_addr.to_string(),
+            msg: deploy_msg,
+            funds: coins(amount.u128(), denom),
+        }
+        .into()],
+        AssetInfo::Token { contract_addr } => vec![
+            WasmMsg::Execute {
+                contract_addr: contract_addr.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: strategy.contract_addr.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+            WasmMsg::Execute {
+                contract_addr: strategy.contract_addr.to_string(),
+                msg: deploy_msg,
+                funds: vec![],
+            }
+            .into(),
+        ],
+    })
+}
+
+/// Creates the messages to withdraw `amount` of the vault asset from the given strategy, followed
+/// by a callback checking the strategy returned the funds within the same transaction.
+pub fn get_strategy_withdraw_msgs(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    strategy: &Strategy,
+    amount: Uint128,
+) -> Result<Vec<CosmosMsg>, VaultError> {
+    let expected_balance = config
+        .asset_info
+        .query_pool(&deps.querier, deps.api, env.contract.address.clone())?
+        .checked_add(amount)?;
+
+    Ok(vec![
+        WasmMsg::Execute {
+            contract_addr: strategy.contract_addr.to_string(),
+            msg: to_binary(&StrategyExecuteMsg::Withdraw { amount })?,
+            funds: vec![],
+        }
+        .into(),
+        WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::Callback(CallbackMsg::AfterStrategyWithdraw {
+                expected_balance,
+            }))?,
+            funds: vec![],
+        }
+        .into(),
+    ])
+}
+
+/// Computes the amount of LP tokens minted for depositing `amount` into a vault holding
+/// `total_deposits` with `total_lp_supply` LP tokens outstanding.
+pub fn compute_lp_mint_amount(
+    amount: Uint128,
+    total_deposits: Uint128,
+    total_lp_supply: Uint128,
+) -> Result<Uint128, VaultError> {
+    if total_lp_supply.is_zero() {
+        // first depositor to the vault, mint LP tokens 1:1
+        return Ok(amount);
+    }
+
+    // return based on a share of the total pool
+    Ok(amount
+        .checked_mul(total_lp_supply)?
+        .checked_div(total_deposits)?)
+}
+
+/// Computes the amount of assets `lp_amount` of LP tokens is entitled to in a vault holding
+/// `total_deposits` with `total_lp_supply` LP tokens outstanding.
+pub fn compute_asset_share(
+    lp_amount: Uint128,
+    total_deposits: Uint128,
+    total_lp_supply: Uint128,
+) -> Uint128 {
+    if total_lp_supply.is_zero() {
+        return Uint128::zero();
+    }
+
+    // lp_share = lp_amount / total_lp_supply
+    // asset_share = lp_share * total_deposits
+    Decimal::from_ratio(lp_amount, total_lp_supply) * total_deposits
+}
@@ -0,0 +1,118 @@
+use cosmwasm_std::{to_binary, Binary, Decimal, Deps, Env};
+
+use white_whale::pool_network::querier::query_token_info;
+use white_whale::vault_network::vault::ExchangeRateResponse;
+
+use crate::error::VaultError;
+use crate::helpers::get_total_deposits;
+use crate::state::CONFIG;
+
+pub fn get_exchange_rate(deps: Deps, env: Env) -> Result<Binary, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let total_lp_supply =
+        query_token_info(&deps.querier, config.liquidity_token.clone())?.total_supply;
+    let total_deposits = get_total_deposits(deps, &env, &config)?;
+
+    let exchange_rate = if total_lp_supply.is_zero() {
+        // the first depositor gets lp tokens 1:1
+        Decimal::one()
+    } else {
+        Decimal::from_ratio(total_deposits, total_lp_supply)
+    };
+
+    Ok(to_binary(&ExchangeRateResponse {
+        total_deposits,
+        total_lp_supply,
+        exchange_rate,
+    })?)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{coins, from_binary, testing::mock_env, Addr, Decimal, Uint128};
+
+    use white_whale::pool_network::asset::{Asset, AssetInfo};
+    use white_whale::vault_network::vault::{Config, ExchangeRateResponse, QueryMsg};
+
+    use crate::state::COLLECTED_PROTOCOL_FEES;
+    use crate::{
+        contract::query,
+        state::CONFIG,
+        tests::{get_fees, mock_creator, mock_dependencies_lp},
+    };
+
+    fn save_config(storage: &mut dyn cosmwasm_std::Storage, protocol_fees: Uint128) {
+        CONFIG
+            .save(
+                storage,
+                &Config {
+                    owner: mock_creator().sender,
+                    liquidity_token: Addr::unchecked("lp_token"),
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                    deposit_enabled: true,
+                    flash_loan_enabled: true,
+                    withdraw_enabled: true,
+                    fee_collector_addr: Addr::unchecked("fee_collector"),
+                    fees: get_fees(),
+                },
+            )
+            .unwrap();
+
+        COLLECTED_PROTOCOL_FEES
+            .save(
+                storage,
+                &Asset {
+                    amount: protocol_fees,
+                    info: AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                },
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn does_get_exchange_rate() {
+        let env = mock_env();
+        let mut deps = mock_dependencies_lp(
+            &[(
+                &env.clone().contract.address.into_string(),
+                &coins(100_000, "uluna"),
+            )],
+            &[(
+                mock_creator().sender.into_string(),
+                &[("lp_token".to_string(), Uint128::new(60_000))],
+            )],
+            vec![],
+        );
+        save_config(&mut deps.storage, Uint128::new(10_000));
+
+        let res: ExchangeRateResponse =
+            from_binary(&query(deps.as_ref(), env, QueryMsg::ExchangeRate {}).unwrap()).unwrap();
+
+        assert_eq!(
+            res,
+            ExchangeRateResponse {
+                total_deposits: Uint128::new(90_000),
+                total_lp_supply: Uint128::new(60_000),
+                exchange_rate: Decimal::from_ratio(3u128, 2u128),
+            }
+        );
+    }
+
+    #[test]
+    fn does_get_exchange_rate_empty_vault() {
+        let env = mock_env();
+        let mut deps = mock_dependencies_lp(&[], &[], vec![]);
+        save_config(&mut deps.storage, Uint128::zero());
+
+        let res: ExchangeRateResponse =
+            from_binary(&query(deps.as_ref(), env, QueryMsg::ExchangeRate {}).unwrap()).unwrap();
+
+        assert_eq!(res.exchange_rate, Decimal::one());
+        assert_eq!(res.total_lp_supply, Uint128::zero());
+    }
+}
@@ -0,0 +1,128 @@
+use cosmwasm_std::{to_binary, Binary, Deps, Env, Uint128};
+
+use white_whale::pool_network::querier::query_token_info;
+
+use crate::error::VaultError;
+use crate::helpers::{compute_lp_mint_amount, get_total_deposits};
+use crate::state::CONFIG;
+
+pub fn get_preview_deposit(deps: Deps, env: Env, amount: Uint128) -> Result<Binary, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let total_lp_supply =
+        query_token_info(&deps.querier, config.liquidity_token.clone())?.total_supply;
+
+    let lp_amount = if total_lp_supply.is_zero() {
+        amount
+    } else {
+        // the deposit hasn't been sent to the vault yet, so unlike in the deposit itself
+        // there's nothing to subtract from the vault balance here
+        let total_deposits = get_total_deposits(deps, &env, &config)?;
+        compute_lp_mint_amount(amount, total_deposits, total_lp_supply)?
+    };
+
+    Ok(to_binary(&lp_amount)?)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{coins, Addr, BankMsg, CosmosMsg, Uint128};
+    use cw_multi_test::Executor;
+
+    use white_whale::pool_network::asset::AssetInfo;
+    use white_whale::vault_network::vault::{Config, ExecuteMsg, QueryMsg};
+
+    use crate::tests::{
+        mock_app::mock_app_with_balance, mock_creator, mock_instantiate::app_mock_instantiate,
+    };
+
+    #[test]
+    fn does_preview_deposit() {
+        let depositor = Addr::unchecked("depositor");
+
+        let mut app = mock_app_with_balance(vec![
+            (mock_creator().sender, coins(15_000, "uluna")),
+            (depositor.clone(), coins(5_000, "uluna")),
+        ]);
+
+        let vault_addr = app_mock_instantiate(
+            &mut app,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        // empty vault mints 1:1
+        let preview: Uint128 = app
+            .wrap()
+            .query_wasm_smart(
+                vault_addr.clone(),
+                &QueryMsg::PreviewDeposit {
+                    amount: Uint128::new(10_000),
+                },
+            )
+            .unwrap();
+        assert_eq!(preview, Uint128::new(10_000));
+
+        app.execute_contract(
+            mock_creator().sender,
+            vault_addr.clone(),
+            &ExecuteMsg::Deposit {
+                amount: Uint128::new(10_000),
+                receiver: None,
+                min_lp_out: None,
+            },
+            &coins(10_000, "uluna"),
+        )
+        .unwrap();
+
+        // inject 5,000 luna that where "generated" via fees
+        app.execute(
+            mock_creator().sender,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: vault_addr.to_string(),
+                amount: coins(5_000, "uluna"),
+            }),
+        )
+        .unwrap();
+
+        // (5000 / 15000) * 10000 = 3,333 LP tokens
+        let preview: Uint128 = app
+            .wrap()
+            .query_wasm_smart(
+                vault_addr.clone(),
+                &QueryMsg::PreviewDeposit {
+                    amount: Uint128::new(5_000),
+                },
+            )
+            .unwrap();
+        assert_eq!(preview, Uint128::new(3_333));
+
+        app.execute_contract(
+            depositor.clone(),
+            vault_addr.clone(),
+            &ExecuteMsg::Deposit {
+                amount: Uint128::new(5_000),
+                receiver: None,
+                min_lp_out: None,
+            },
+            &coins(5_000, "uluna"),
+        )
+        .unwrap();
+
+        let config: Config = app
+            .wrap()
+            .query_wasm_smart(vault_addr, &QueryMsg::Config {})
+            .unwrap();
+        let cw20_balance: cw20::BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                config.liquidity_token,
+                &cw20::Cw20QueryMsg::Balance {
+                    address: depositor.into_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(cw20_balance.balance, preview);
+    }
+}
@@ -0,0 +1,99 @@
+use cosmwasm_std::{to_binary, Binary, Deps, Env, Uint128};
+
+use white_whale::pool_network::querier::query_token_info;
+
+use crate::error::VaultError;
+use crate::helpers::{compute_asset_share, get_total_deposits};
+use crate::state::CONFIG;
+
+pub fn get_preview_withdraw(
+    deps: Deps,
+    env: Env,
+    lp_amount: Uint128,
+) -> Result<Binary, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let total_lp_supply =
+        query_token_info(&deps.querier, config.liquidity_token.clone())?.total_supply;
+    let total_deposits = get_total_deposits(deps, &env, &config)?;
+
+    let asset_amount = compute_asset_share(lp_amount, total_deposits, total_lp_supply);
+    Ok(to_binary(&asset_amount)?)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{coins, from_binary, testing::mock_env, Addr, Uint128};
+
+    use white_whale::pool_network::asset::{Asset, AssetInfo};
+    use white_whale::vault_network::vault::{Config, QueryMsg};
+
+    use crate::state::COLLECTED_PROTOCOL_FEES;
+    use crate::{
+        contract::query,
+        state::CONFIG,
+        tests::{get_fees, mock_creator, mock_dependencies_lp},
+    };
+
+    #[test]
+    fn does_preview_withdraw() {
+        let env = mock_env();
+        let mut deps = mock_dependencies_lp(
+            &[(
+                &env.clone().contract.address.into_string(),
+                &coins(100_000, "uluna"),
+            )],
+            &[(
+                mock_creator().sender.into_string(),
+                &[("lp_token".to_string(), Uint128::new(40_000))],
+            )],
+            vec![],
+        );
+
+        CONFIG
+            .save(
+                &mut deps.storage,
+                &Config {
+                    owner: mock_creator().sender,
+                    liquidity_token: Addr::unchecked("lp_token"),
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                    deposit_enabled: true,
+                    flash_loan_enabled: true,
+                    withdraw_enabled: true,
+                    fee_collector_addr: Addr::unchecked("fee_collector"),
+                    fees: get_fees(),
+                },
+            )
+            .unwrap();
+
+        // the uncollected protocol fees don't belong to the lp holders
+        COLLECTED_PROTOCOL_FEES
+            .save(
+                &mut deps.storage,
+                &Asset {
+                    amount: Uint128::new(20_000),
+                    info: AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                },
+            )
+            .unwrap();
+
+        // (10000 / 40000) * 80000 = 20,000 uluna
+        let res: Uint128 = from_binary(
+            &query(
+                deps.as_ref(),
+                env,
+                QueryMsg::PreviewWithdraw {
+                    lp_amount: Uint128::new(10_000),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(res, Uint128::new(20_000));
+    }
+}
@@ -1,42 +1,19 @@
-use cosmwasm_std::{to_binary, Binary, Decimal, Deps, Env, Uint128};
-use cw20::{BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
+use cosmwasm_std::{to_binary, Binary, Deps, Env, Uint128};
 
-use white_whale::pool_network::asset::AssetInfo;
+use white_whale::pool_network::querier::query_token_info;
 
 use crate::error::VaultError;
-use crate::state::COLLECTED_PROTOCOL_FEES;
+use crate::helpers::{compute_asset_share, get_total_deposits};
 use crate::state::CONFIG;
 
 pub fn get_share(deps: Deps, env: Env, amount: Uint128) -> Result<Binary, VaultError> {
     let config = CONFIG.load(deps.storage)?;
 
-    let lp_amount: TokenInfoResponse = deps
-        .querier
-        .query_wasm_smart(config.liquidity_token, &Cw20QueryMsg::TokenInfo {})?;
-
-    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
-
-    let balance = match config.asset_info {
-        AssetInfo::NativeToken { denom } => {
-            deps.querier
-                .query_balance(env.contract.address, denom)?
-                .amount
-        }
-        AssetInfo::Token { contract_addr } => {
-            let balance: BalanceResponse = deps.querier.query_wasm_smart(
-                contract_addr,
-                &Cw20QueryMsg::Balance {
-                    address: env.contract.address.into_string(),
-                },
-            )?;
-            balance.balance
-        }
-    } // deduct protocol fees
-    .checked_sub(collected_protocol_fees.amount)?;
-
-    // lp_share = amount / lp_amount
-    // asset_share = lp_share * balance
-    let asset_share = Decimal::from_ratio(amount, lp_amount.total_supply) * balance;
+    let total_lp_supply =
+        query_token_info(&deps.querier, config.liquidity_token.clone())?.total_supply;
+    let total_deposits = get_total_deposits(deps, &env, &config)?;
+
+    let asset_share = compute_asset_share(amount, total_deposits, total_lp_supply);
     Ok(to_binary(&asset_share)?)
 }
 
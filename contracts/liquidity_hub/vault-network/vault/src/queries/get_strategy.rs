@@ -0,0 +1,14 @@
+use cosmwasm_std::{to_binary, Binary, Deps};
+
+use white_whale::vault_network::vault::StrategyResponse;
+
+use crate::error::VaultError;
+use crate::helpers::query_deployed_amount;
+use crate::state::STRATEGY;
+
+pub fn get_strategy(deps: Deps) -> Result<Binary, VaultError> {
+    Ok(to_binary(&StrategyResponse {
+        strategy: STRATEGY.may_load(deps.storage)?,
+        deployed_amount: query_deployed_amount(deps)?,
+    })?)
+}
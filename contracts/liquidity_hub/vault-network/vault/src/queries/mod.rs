@@ -1,9 +1,17 @@
 mod get_config;
+mod get_exchange_rate;
 mod get_payback_amount;
+mod get_preview_deposit;
+mod get_preview_withdraw;
 mod get_protocol_fees;
 mod get_share;
+mod get_strategy;
 
 pub use get_config::get_config;
+pub use get_exchange_rate::get_exchange_rate;
 pub use get_payback_amount::get_payback_amount;
+pub use get_preview_deposit::get_preview_deposit;
+pub use get_preview_withdraw::get_preview_withdraw;
 pub use get_protocol_fees::get_fees;
 pub use get_share::get_share;
+pub use get_strategy::get_strategy;
@@ -2,10 +2,13 @@ use cosmwasm_std::{StdError, StdResult, Storage, Uint128};
 use cw_storage_plus::Item;
 use white_whale::pool_network::asset::{Asset, AssetInfo};
 
-use white_whale::vault_network::vault::Config;
+use white_whale::vault_network::vault::{Config, Strategy};
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
+// The strategy idle funds are deployed to, if any
+pub const STRATEGY: Item<Strategy> = Item::new("strategy");
+
 // Fees that have been accrued by the vault, still unclaimed by the fee collector
 pub const COLLECTED_PROTOCOL_FEES: Item<Asset> = Item::new("collected_protocol_fees");
 // Fees that have been accrued by the vault since the vault's inception
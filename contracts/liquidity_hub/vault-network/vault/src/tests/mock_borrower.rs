@@ -0,0 +1,74 @@
+use cosmwasm_std::{coins, to_binary, Addr, BankMsg, Empty, Response, StdError, Uint128, WasmMsg};
+use cw_multi_test::{App, ContractWrapper, Executor};
+use cw_storage_plus::Item;
+use serde::{Deserialize, Serialize};
+
+use white_whale::vault_network::vault::ExecuteMsg as VaultExecuteMsg;
+
+use super::mock_creator;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InstantiateMsg {
+    pub denom: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Takes a flash-loan of `amount` from the vault.
+    Borrow { vault: String, amount: Uint128 },
+    /// Pays back the loan, sending the whole balance of the borrower to the vault.
+    Repay { vault: String },
+}
+
+const DENOM: Item<String> = Item::new("denom");
+
+/// Creates a flash-loan borrower of native tokens, which pays back the loan with its whole balance.
+pub fn create_mock_borrower_contract(
+) -> ContractWrapper<ExecuteMsg, InstantiateMsg, Empty, StdError, StdError, StdError> {
+    ContractWrapper::new(
+        |deps, env, _info, msg| match msg {
+            ExecuteMsg::Borrow { vault, amount } => {
+                Ok(Response::new().add_message(WasmMsg::Execute {
+                    contract_addr: vault.clone(),
+                    msg: to_binary(&VaultExecuteMsg::FlashLoan {
+                        amount,
+                        msg: to_binary(&ExecuteMsg::Repay { vault })?,
+                    })?,
+                    funds: vec![],
+                }))
+            }
+            ExecuteMsg::Repay { vault } => {
+                let balance = deps
+                    .querier
+                    .query_balance(env.contract.address, DENOM.load(deps.storage)?)?;
+                Ok(Response::new().add_message(BankMsg::Send {
+                    to_address: vault,
+                    amount: coins(balance.amount.u128(), balance.denom),
+                }))
+            }
+        },
+        |deps, _env, _info, msg| {
+            DENOM.save(deps.storage, &msg.denom)?;
+            Ok(Response::new())
+        },
+        |_deps, _env, _msg: Empty| to_binary(&Empty {}),
+    )
+}
+
+/// Uploads and instantiates the mock borrower, returning the address of the contract.
+pub fn app_mock_borrower(app: &mut App, denom: &str) -> Addr {
+    let code_id = app.store_code(Box::new(create_mock_borrower_contract()));
+
+    app.instantiate_contract(
+        code_id,
+        mock_creator().sender,
+        &InstantiateMsg {
+            denom: denom.to_string(),
+        },
+        &[],
+        "mock borrower",
+        None,
+    )
+    .unwrap()
+}
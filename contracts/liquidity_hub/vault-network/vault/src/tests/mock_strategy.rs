@@ -0,0 +1,75 @@
+use cosmwasm_std::{to_binary, Addr, Response, StdError};
+use cw_multi_test::{App, ContractWrapper, Executor};
+use cw_storage_plus::Item;
+use serde::{Deserialize, Serialize};
+
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::vault_network::vault::{StrategyExecuteMsg, StrategyQueryMsg};
+
+use super::mock_creator;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InstantiateMsg {
+    pub asset_info: AssetInfo,
+    /// If false, the strategy doesn't return the funds withdrawn from it, as if they were subject
+    /// to an unbonding period.
+    pub liquid: bool,
+}
+
+const ASSET_INFO: Item<AssetInfo> = Item::new("asset_info");
+const LIQUID: Item<bool> = Item::new("liquid");
+
+/// Creates a strategy that holds the native or cw20 funds deployed to it, its value being its
+/// balance. Yield can be simulated by sending funds to it.
+pub fn create_mock_strategy_contract() -> ContractWrapper<
+    StrategyExecuteMsg,
+    InstantiateMsg,
+    StrategyQueryMsg,
+    StdError,
+    StdError,
+    StdError,
+> {
+    ContractWrapper::new(
+        |deps, _env, info, msg| match msg {
+            StrategyExecuteMsg::Deploy { .. } => Ok(Response::new()),
+            StrategyExecuteMsg::Withdraw { amount } => {
+                if !LIQUID.load(deps.storage)? {
+                    return Ok(Response::new());
+                }
+
+                let asset = Asset {
+                    info: ASSET_INFO.load(deps.storage)?,
+                    amount,
+                };
+                Ok(Response::new().add_message(asset.into_msg(info.sender)?))
+            }
+        },
+        |deps, _env, _info, msg| {
+            ASSET_INFO.save(deps.storage, &msg.asset_info)?;
+            LIQUID.save(deps.storage, &msg.liquid)?;
+            Ok(Response::new())
+        },
+        |deps, env, msg| match msg {
+            StrategyQueryMsg::Value {} => to_binary(&ASSET_INFO.load(deps.storage)?.query_pool(
+                &deps.querier,
+                deps.api,
+                env.contract.address,
+            )?),
+        },
+    )
+}
+
+/// Uploads and instantiates the mock strategy, returning the address of the contract.
+pub fn app_mock_strategy(app: &mut App, asset_info: AssetInfo, liquid: bool) -> Addr {
+    let code_id = app.store_code(Box::new(create_mock_strategy_contract()));
+
+    app.instantiate_contract(
+        code_id,
+        mock_creator().sender,
+        &InstantiateMsg { asset_info, liquid },
+        &[],
+        "mock strategy",
+        None,
+    )
+    .unwrap()
+}
@@ -1,9 +1,11 @@
 mod get_fees;
 pub mod mock_app;
+pub mod mock_borrower;
 mod mock_creator;
 mod mock_dependencies_lp;
 mod mock_execute;
 pub mod mock_instantiate;
+pub mod mock_strategy;
 pub mod store_code;
 
 pub use get_fees::get_fees;
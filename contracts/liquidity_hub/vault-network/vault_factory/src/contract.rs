@@ -8,7 +8,8 @@ use white_whale::vault_network::vault_factory::{
 
 use crate::err::{StdResult, VaultFactoryError};
 use crate::execute::{
-    create_vault, migrate_vaults, remove_vault, update_config, update_vault_config,
+    create_vault, create_vaults, migrate_vaults, remove_vault, update_config, update_vault_config,
+    update_vault_creation_config, update_vault_metadata,
 };
 use crate::migrations;
 use crate::queries::{get_config, get_vault, get_vaults};
@@ -31,6 +32,9 @@ pub fn instantiate(
         vault_id: msg.vault_id,
         token_id: msg.token_id,
         fee_collector_addr: deps.api.addr_validate(&msg.fee_collector_addr)?,
+        vault_creation_fee: None,
+        default_vault_fees: None,
+        vault_fee_bounds: None,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -39,14 +43,23 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
-    // permission check
+    // permission check, creating a single vault is checked against the vault creation settings
     let config = CONFIG.load(deps.storage)?;
-    if config.owner != info.sender {
+    if config.owner != info.sender && !matches!(msg, ExecuteMsg::CreateVault { .. }) {
         return Err(VaultFactoryError::Unauthorized {});
     }
 
     match msg {
-        ExecuteMsg::CreateVault { asset_info, fees } => create_vault(deps, env, asset_info, fees),
+        ExecuteMsg::CreateVault {
+            asset_info,
+            fees,
+            metadata,
+        } => create_vault(deps, env, info, asset_info, fees, metadata),
+        ExecuteMsg::CreateVaults { vaults } => create_vaults(deps, env, vaults),
+        ExecuteMsg::UpdateVaultMetadata {
+            asset_info,
+            metadata,
+        } => update_vault_metadata(deps, asset_info, metadata),
         ExecuteMsg::UpdateVaultConfig { vault_addr, params } => {
             update_vault_config(deps, vault_addr, params)
         }
@@ -61,6 +74,16 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
             vault_id,
             token_id,
         } => update_config(deps, owner, fee_collector_addr, vault_id, token_id),
+        ExecuteMsg::UpdateVaultCreationConfig {
+            vault_creation_fee,
+            default_vault_fees,
+            vault_fee_bounds,
+        } => update_vault_creation_config(
+            deps,
+            vault_creation_fee,
+            default_vault_fees,
+            vault_fee_bounds,
+        ),
     }
 }
 
@@ -114,6 +137,10 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => get_config(deps),
         QueryMsg::Vault { asset_info } => get_vault(deps, asset_info),
-        QueryMsg::Vaults { start_after, limit } => get_vaults(deps, start_after, limit),
+        QueryMsg::Vaults {
+            start_after,
+            limit,
+            filter,
+        } => get_vaults(deps, start_after, limit, filter),
     }
 }
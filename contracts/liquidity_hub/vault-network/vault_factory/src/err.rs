@@ -26,6 +26,18 @@ pub enum VaultFactoryError {
 
     #[error("Vault doesn't exist given the vault address provided")]
     NonExistentVault {},
+
+    #[error("The asset {asset_info} is used by more than one of the vaults to create")]
+    DuplicateAsset { asset_info: String },
+
+    #[error("No fees were provided for the vault and the factory has no default vault fees")]
+    NoVaultFees {},
+
+    #[error("The vault fees are not within the bounds set by the factory")]
+    VaultFeesOutOfBounds {},
+
+    #[error("The minimum vault fees can't be greater than the maximum vault fees")]
+    InvalidVaultFeeBounds {},
 }
 
 impl From<semver::Error> for VaultFactoryError {
@@ -1,22 +1,159 @@
-use cosmwasm_std::{to_binary, DepsMut, Env, ReplyOn, Response, SubMsg, WasmMsg};
+use std::collections::HashSet;
+
+use cosmwasm_std::{
+    to_binary, CosmosMsg, DepsMut, Env, MessageInfo, ReplyOn, Response, SubMsg, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
 use white_whale::fee::VaultFee;
 use white_whale::pool_network::asset::AssetInfo;
 use white_whale::vault_network::vault::InstantiateMsg;
-use white_whale::vault_network::vault_factory::INSTANTIATE_VAULT_REPLY_ID;
+use white_whale::vault_network::vault_factory::{
+    Config, CreateVaultParams, CreationFeeDestination, VaultCreationFee, VaultMetadata,
+    INSTANTIATE_VAULT_REPLY_ID,
+};
 
 use crate::{
     asset::AssetReference,
     err::{StdResult, VaultFactoryError},
-    state::{CONFIG, TMP_VAULT_ASSET, VAULTS},
+    state::{CONFIG, VAULTS, VAULT_METADATA},
 };
 
 pub fn create_vault(
     deps: DepsMut,
     env: Env,
+    info: MessageInfo,
     asset_info: AssetInfo,
-    fees: VaultFee,
+    fees: Option<VaultFee>,
+    metadata: Option<VaultMetadata>,
+) -> StdResult<Response> {
+    let config = CONFIG.load(deps.storage)?;
+    let fees = get_vault_fees(&config, fees)?;
+
+    // a vault created by a non-owner costs the creation fee and has its fees bounded
+    let mut messages = vec![];
+    if info.sender != config.owner {
+        let vault_creation_fee = config
+            .vault_creation_fee
+            .as_ref()
+            .ok_or(VaultFactoryError::Unauthorized {})?;
+
+        if let Some(vault_fee_bounds) = &config.vault_fee_bounds {
+            if !vault_fee_bounds.contains(&fees) {
+                return Err(VaultFactoryError::VaultFeesOutOfBounds {});
+            }
+        }
+
+        // there is nothing to collect if the creation fee is zero
+        if !vault_creation_fee.fee.amount.is_zero() {
+            messages.push(get_creation_fee_msg(&config, vault_creation_fee, &info)?);
+        }
+    }
+
+    let vault_instantiate_msg = vault_instantiate_msg(deps, &env, asset_info, fees, metadata)?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_submessage(vault_instantiate_msg)
+        .add_attributes(vec![("method", "create_vault")]))
+}
+
+pub fn create_vaults(
+    mut deps: DepsMut,
+    env: Env,
+    vaults: Vec<CreateVaultParams>,
 ) -> StdResult<Response> {
-    // check that owner is creating vault
+    let config = CONFIG.load(deps.storage)?;
+
+    // the vaults are only stored in the reply, so duplicates within the batch have to be caught here
+    let mut asset_references = HashSet::new();
+    for vault in vaults.iter() {
+        if !asset_references.insert(vault.asset_info.get_reference().to_vec()) {
+            return Err(VaultFactoryError::DuplicateAsset {
+                asset_info: vault.asset_info.to_string(),
+            });
+        }
+    }
+
+    let vaults_count = vaults.len();
+    let vault_instantiate_msgs = vaults
+        .into_iter()
+        .map(|vault| {
+            vault_instantiate_msg(
+                deps.branch(),
+                &env,
+                vault.asset_info,
+                get_vault_fees(&config, vault.fees)?,
+                vault.metadata,
+            )
+        })
+        .collect::<StdResult<Vec<SubMsg>>>()?;
+
+    Ok(Response::new()
+        .add_submessages(vault_instantiate_msgs)
+        .add_attributes(vec![
+            ("method", "create_vaults"),
+            ("vaults", &vaults_count.to_string()),
+        ]))
+}
+
+/// Gets the fees to create a vault with, falling back to the factory's default vault fees
+fn get_vault_fees(config: &Config, fees: Option<VaultFee>) -> StdResult<VaultFee> {
+    fees.or_else(|| config.default_vault_fees.clone())
+        .ok_or(VaultFactoryError::NoVaultFees {})
+}
+
+/// Checks the vault creation fee was paid and builds the message sending it to its destination.
+/// Native fees have to be sent along with the message, while cw20 fees are taken from the sender
+/// using its allowance.
+fn get_creation_fee_msg(
+    config: &Config,
+    vault_creation_fee: &VaultCreationFee,
+    info: &MessageInfo,
+) -> StdResult<CosmosMsg> {
+    let fee = vault_creation_fee.fee.clone();
+
+    Ok(match fee.info.clone() {
+        AssetInfo::NativeToken { .. } => {
+            fee.assert_sent_native_token_balance(info)?;
+
+            match vault_creation_fee.destination {
+                CreationFeeDestination::FeeCollector => {
+                    fee.into_msg(config.fee_collector_addr.clone())?
+                }
+                CreationFeeDestination::Burn => fee.into_burn_msg()?,
+            }
+        }
+        AssetInfo::Token { contract_addr } => {
+            let msg = match vault_creation_fee.destination {
+                CreationFeeDestination::FeeCollector => Cw20ExecuteMsg::TransferFrom {
+                    owner: info.sender.to_string(),
+                    recipient: config.fee_collector_addr.to_string(),
+                    amount: fee.amount,
+                },
+                CreationFeeDestination::Burn => Cw20ExecuteMsg::BurnFrom {
+                    owner: info.sender.to_string(),
+                    amount: fee.amount,
+                },
+            };
+
+            WasmMsg::Execute {
+                contract_addr,
+                msg: to_binary(&msg)?,
+                funds: vec![],
+            }
+            .into()
+        }
+    })
+}
+
+/// Validates the vault to create and builds the submessage instantiating it
+fn vault_instantiate_msg(
+    deps: DepsMut,
+    env: &Env,
+    asset_info: AssetInfo,
+    fees: VaultFee,
+    metadata: Option<VaultMetadata>,
+) -> StdResult<SubMsg> {
     let config = CONFIG.load(deps.storage)?;
 
     // check that existing vault does not exist
@@ -29,14 +166,19 @@ pub fn create_vault(
     fees.flash_loan_fee.is_valid()?;
     fees.protocol_fee.is_valid()?;
 
+    // store the metadata right away, the vault will be linked to it in the reply
+    if let Some(metadata) = metadata {
+        VAULT_METADATA.save(deps.storage, asset_info.get_reference(), &metadata)?;
+    }
+
     // create a new vault
-    let vault_instantiate_msg: SubMsg = SubMsg {
+    Ok(SubMsg {
         id: INSTANTIATE_VAULT_REPLY_ID,
         msg: WasmMsg::Instantiate {
             admin: Some(env.contract.address.clone().into_string()),
             code_id: config.vault_id,
             msg: to_binary(&InstantiateMsg {
-                owner: env.contract.address.into_string(),
+                owner: env.contract.address.clone().into_string(),
                 asset_info: asset_info.clone(),
                 token_id: config.token_id,
                 fee_collector_addr: config.fee_collector_addr.into_string(),
@@ -45,34 +187,32 @@ pub fn create_vault(
             funds: vec![],
             label: format!(
                 "White Whale {} Vault",
-                asset_info.clone().get_label(&deps.as_ref())?
+                asset_info.get_label(&deps.as_ref())?
             ),
         }
         .into(),
         gas_limit: None,
         reply_on: ReplyOn::Success,
-    };
-
-    // store asset for use in reply callback
-    TMP_VAULT_ASSET.save(
-        deps.storage,
-        &(asset_info.get_reference().to_vec(), asset_info),
-    )?;
-
-    Ok(Response::new()
-        .add_submessage(vault_instantiate_msg)
-        .add_attributes(vec![("method", "create_vault")]))
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::{
-        testing::mock_info, to_binary, Addr, Decimal, ReplyOn, Response, StdError, SubMsg, WasmMsg,
+        coins,
+        testing::{mock_info, MockApi, MockQuerier, MockStorage},
+        to_binary, Addr, BankMsg, CosmosMsg, Decimal, Env, OwnedDeps, ReplyOn, Response, StdError,
+        SubMsg, Uint128, WasmMsg,
     };
+    use cw20::Cw20ExecuteMsg;
     use cw_multi_test::Executor;
     use white_whale::fee::{Fee, VaultFee};
-    use white_whale::pool_network::asset::AssetInfo;
-    use white_whale::vault_network::vault_factory::INSTANTIATE_VAULT_REPLY_ID;
+    use white_whale::pool_network::asset::{Asset, AssetInfo};
+    use white_whale::vault_network::vault_factory::{
+        CreateVaultParams, CreationFeeDestination, ExecuteMsg, QueryMsg, RiskTier,
+        VaultCreationFee, VaultFeeBounds, VaultMetadata, VaultsResponse,
+        INSTANTIATE_VAULT_REPLY_ID,
+    };
 
     use crate::{
         contract::execute,
@@ -95,7 +235,8 @@ mod tests {
             6,
             white_whale::vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
-                fees: get_fees(),
+                fees: Some(get_fees()),
+                metadata: None,
             },
         );
 
@@ -143,7 +284,8 @@ mod tests {
             bad_actor,
             white_whale::vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info,
-                fees: get_fees(),
+                fees: Some(get_fees()),
+                metadata: None,
             },
         );
 
@@ -168,7 +310,8 @@ mod tests {
             factory_addr.clone(),
             &white_whale::vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
-                fees: get_fees(),
+                fees: Some(get_fees()),
+                metadata: None,
             },
             &[],
         )
@@ -191,7 +334,8 @@ mod tests {
             factory_addr,
             &white_whale::vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info,
-                fees: get_fees(),
+                fees: Some(get_fees()),
+                metadata: None,
             },
             &[],
         );
@@ -220,7 +364,7 @@ mod tests {
                 asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
-                fees: VaultFee {
+                fees: Some(VaultFee {
                     flash_loan_fee: Fee {
                         share: Decimal::percent(150),
                     },
@@ -230,7 +374,8 @@ mod tests {
                     burn_fee: Fee {
                         share: Decimal::zero(),
                     },
-                },
+                }),
+                metadata: None,
             },
         );
         assert_eq!(
@@ -249,7 +394,7 @@ mod tests {
                 asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
-                fees: VaultFee {
+                fees: Some(VaultFee {
                     flash_loan_fee: Fee {
                         share: Decimal::percent(30),
                     },
@@ -259,7 +404,8 @@ mod tests {
                     burn_fee: Fee {
                         share: Decimal::zero(),
                     },
-                },
+                }),
+                metadata: None,
             },
         );
         assert_eq!(
@@ -283,7 +429,8 @@ mod tests {
             6,
             white_whale::vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
-                fees: get_fees(),
+                fees: Some(get_fees()),
+                metadata: None,
             },
         );
 
@@ -327,7 +474,8 @@ mod tests {
             6,
             white_whale::vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
-                fees: get_fees(),
+                fees: Some(get_fees()),
+                metadata: None,
             },
         );
 
@@ -357,4 +505,325 @@ mod tests {
                 })
         )
     }
+
+    #[test]
+    fn can_create_vaults_in_batch() {
+        let mut app = mock_app();
+        let factory_addr = app_mock_instantiate(&mut app);
+
+        let vaults = vec!["uluna", "uwhale", "uatom"]
+            .into_iter()
+            .map(|denom| CreateVaultParams {
+                asset_info: AssetInfo::NativeToken {
+                    denom: denom.to_string(),
+                },
+                fees: Some(get_fees()),
+                metadata: Some(VaultMetadata {
+                    display_name: format!("{denom} vault"),
+                    description: None,
+                    risk_tier: Some(RiskTier::Low),
+                }),
+            })
+            .collect::<Vec<_>>();
+
+        let res = app
+            .execute_contract(
+                mock_creator().sender,
+                factory_addr.clone(),
+                &ExecuteMsg::CreateVaults {
+                    vaults: vaults.clone(),
+                },
+                &[],
+            )
+            .unwrap();
+
+        let created_vault_addrs = res
+            .events
+            .iter()
+            .flat_map(|event| &event.attributes)
+            .filter(|attribute| attribute.key == "vault_address")
+            .map(|attribute| attribute.value.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(created_vault_addrs.len(), 3);
+
+        // each vault is stored under its own asset, along with its metadata
+        for (vault, vault_addr) in vaults.into_iter().zip(created_vault_addrs) {
+            let stored_addr: Option<String> = app
+                .wrap()
+                .query_wasm_smart(
+                    factory_addr.clone(),
+                    &QueryMsg::Vault {
+                        asset_info: vault.asset_info.clone(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(stored_addr, Some(vault_addr));
+
+            let vault_config: white_whale::vault_network::vault::Config = app
+                .wrap()
+                .query_wasm_smart(
+                    stored_addr.unwrap(),
+                    &white_whale::vault_network::vault::QueryMsg::Config {},
+                )
+                .unwrap();
+            assert_eq!(vault_config.asset_info, vault.asset_info);
+        }
+
+        let vaults_response: VaultsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr,
+                &QueryMsg::Vaults {
+                    start_after: None,
+                    limit: None,
+                    filter: None,
+                },
+            )
+            .unwrap();
+        assert!(vaults_response.vaults.iter().all(|vault_info| vault_info
+            .metadata
+            .as_ref()
+            .unwrap()
+            .display_name
+            == format!("{} vault", vault_info.asset_info)));
+    }
+
+    #[test]
+    fn cannot_create_duplicate_vaults_in_batch() {
+        let asset_info = AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        };
+
+        let (res, ..) = mock_execute(
+            5,
+            6,
+            ExecuteMsg::CreateVaults {
+                vaults: vec![
+                    CreateVaultParams {
+                        asset_info: asset_info.clone(),
+                        fees: Some(get_fees()),
+                        metadata: None,
+                    },
+                    CreateVaultParams {
+                        asset_info,
+                        fees: Some(get_fees()),
+                        metadata: None,
+                    },
+                ],
+            },
+        );
+
+        assert_eq!(
+            res.unwrap_err(),
+            VaultFactoryError::DuplicateAsset {
+                asset_info: "uluna".to_string()
+            }
+        );
+    }
+
+    /// Instantiates the factory and lets anyone create vaults by paying the given fee, with vault
+    /// fees between zero and the ones from [get_fees].
+    fn mock_permissionless_instantiate(
+        fee_info: AssetInfo,
+        destination: CreationFeeDestination,
+    ) -> (OwnedDeps<MockStorage, MockApi, MockQuerier>, Env) {
+        let (mut deps, env) = mock_instantiate(5, 6);
+
+        let zero_fee = Fee {
+            share: Decimal::zero(),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            ExecuteMsg::UpdateVaultCreationConfig {
+                vault_creation_fee: Some(VaultCreationFee {
+                    fee: Asset {
+                        info: fee_info,
+                        amount: Uint128::new(1_000),
+                    },
+                    destination,
+                }),
+                default_vault_fees: Some(get_fees()),
+                vault_fee_bounds: Some(VaultFeeBounds {
+                    min: VaultFee {
+                        protocol_fee: zero_fee.clone(),
+                        flash_loan_fee: zero_fee.clone(),
+                        burn_fee: zero_fee,
+                    },
+                    max: get_fees(),
+                }),
+            },
+        )
+        .unwrap();
+
+        (deps, env)
+    }
+
+    #[test]
+    fn can_create_vault_paying_native_creation_fee() {
+        let (mut deps, env) = mock_permissionless_instantiate(
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            CreationFeeDestination::FeeCollector,
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("anyone", &coins(1_000, "uwhale")),
+            ExecuteMsg::CreateVault {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fees: None,
+                metadata: None,
+            },
+        )
+        .unwrap();
+
+        // the fee is sent to the fee collector, and the vault is created with the default fees
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "fee_collector".to_string(),
+                amount: coins(1_000, "uwhale"),
+            })
+        );
+        match &res.messages[1].msg {
+            CosmosMsg::Wasm(WasmMsg::Instantiate { msg, .. }) => {
+                let msg: white_whale::vault_network::vault::InstantiateMsg =
+                    cosmwasm_std::from_binary(msg).unwrap();
+                assert_eq!(msg.vault_fees, get_fees());
+            }
+            _ => panic!("expected the vault instantiation"),
+        }
+    }
+
+    #[test]
+    fn can_create_vault_burning_cw20_creation_fee() {
+        let (mut deps, env) = mock_permissionless_instantiate(
+            AssetInfo::Token {
+                contract_addr: "fee_token".to_string(),
+            },
+            CreationFeeDestination::Burn,
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::CreateVault {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fees: Some(get_fees()),
+                metadata: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "fee_token".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::BurnFrom {
+                    owner: "anyone".to_string(),
+                    amount: Uint128::new(1_000),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn cannot_create_vault_without_paying_creation_fee() {
+        let (mut deps, env) = mock_permissionless_instantiate(
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            CreationFeeDestination::Burn,
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("anyone", &coins(999, "uwhale")),
+            ExecuteMsg::CreateVault {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fees: None,
+                metadata: None,
+            },
+        );
+
+        assert_eq!(
+            res.unwrap_err(),
+            VaultFactoryError::Std(StdError::generic_err(
+                "Native token balance mismatch between the argument and the transferred"
+            ))
+        );
+    }
+
+    #[test]
+    fn cannot_create_vault_with_fees_out_of_bounds() {
+        let (mut deps, env) = mock_permissionless_instantiate(
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            CreationFeeDestination::FeeCollector,
+        );
+
+        let mut fees = get_fees();
+        fees.flash_loan_fee.share = Decimal::percent(50);
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone", &coins(1_000, "uwhale")),
+            ExecuteMsg::CreateVault {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fees: Some(fees.clone()),
+                metadata: None,
+            },
+        );
+        assert_eq!(res.unwrap_err(), VaultFactoryError::VaultFeesOutOfBounds {});
+
+        // the bounds don't apply to the owner
+        execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            ExecuteMsg::CreateVault {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fees: Some(fees),
+                metadata: None,
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn cannot_create_vault_without_fees_if_no_default() {
+        let (res, ..) = mock_execute(
+            5,
+            6,
+            ExecuteMsg::CreateVault {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fees: None,
+                metadata: None,
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), VaultFactoryError::NoVaultFees {});
+    }
 }
@@ -23,7 +23,7 @@ pub fn migrate_vaults(
                 vault_code_id,
             )?))
     } else {
-        let vaults = read_vaults(deps.storage, deps.api, None, Some(30u32))?;
+        let vaults = read_vaults(deps.storage, deps.api, None, Some(30u32), None)?;
         for vault in vaults {
             res = res
                 .add_attribute("vault", &vault.clone().vault)
@@ -3,9 +3,13 @@ mod migrate_vaults;
 mod remove_vault;
 mod update_config;
 mod update_vault_config;
+mod update_vault_creation_config;
+mod update_vault_metadata;
 
-pub use create_vault::create_vault;
+pub use create_vault::{create_vault, create_vaults};
 pub use migrate_vaults::migrate_vaults;
 pub use remove_vault::remove_vault;
 pub use update_config::update_config;
 pub use update_vault_config::update_vault_config;
+pub use update_vault_creation_config::update_vault_creation_config;
+pub use update_vault_metadata::update_vault_metadata;
@@ -4,7 +4,7 @@ use white_whale::pool_network::asset::AssetInfo;
 
 use crate::asset::AssetReference;
 use crate::err::{StdResult, VaultFactoryError};
-use crate::state::VAULTS;
+use crate::state::{VAULTS, VAULT_METADATA};
 
 pub fn remove_vault(deps: DepsMut, asset_info: AssetInfo) -> StdResult<Response> {
     if let Ok(None) = VAULTS.may_load(deps.storage, asset_info.get_reference()) {
@@ -12,6 +12,7 @@ pub fn remove_vault(deps: DepsMut, asset_info: AssetInfo) -> StdResult<Response>
     }
 
     VAULTS.remove(deps.storage, asset_info.get_reference());
+    VAULT_METADATA.remove(deps.storage, asset_info.get_reference());
 
     Ok(Response::new().add_attributes(vec![("method", "remove_vault")]))
 }
@@ -47,7 +48,8 @@ mod tests {
             factory_addr.clone(),
             &white_whale::vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info_1.clone(),
-                fees: get_fees(),
+                fees: Some(get_fees()),
+                metadata: None,
             },
             &[],
         )
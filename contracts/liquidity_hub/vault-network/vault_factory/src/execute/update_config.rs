@@ -157,6 +157,9 @@ mod tests {
             owner: mock_creator().sender,
             vault_id: 3,
             token_id: 4,
+            vault_creation_fee: None,
+            default_vault_fees: None,
+            vault_fee_bounds: None,
         };
 
         let config: Config =
@@ -199,6 +202,9 @@ mod tests {
             owner: mock_creator().sender,
             vault_id: 1,
             token_id: 2,
+            vault_creation_fee: None,
+            default_vault_fees: None,
+            vault_fee_bounds: None,
         };
 
         let config: Config =
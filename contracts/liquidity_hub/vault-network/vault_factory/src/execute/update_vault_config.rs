@@ -46,7 +46,8 @@ mod tests {
             factory_addr.clone(),
             &white_whale::vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
-                fees: get_fees(),
+                fees: Some(get_fees()),
+                metadata: None,
             },
             &[],
         )
@@ -125,7 +126,8 @@ mod tests {
             factory_addr.clone(),
             &white_whale::vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
-                fees: get_fees(),
+                fees: Some(get_fees()),
+                metadata: None,
             },
             &[],
         )
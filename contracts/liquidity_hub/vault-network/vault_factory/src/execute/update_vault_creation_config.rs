@@ -0,0 +1,201 @@
+use cosmwasm_std::{DepsMut, Response};
+use white_whale::fee::VaultFee;
+use white_whale::vault_network::vault_factory::{VaultCreationFee, VaultFeeBounds};
+
+use crate::{
+    err::{StdResult, VaultFactoryError},
+    state::CONFIG,
+};
+
+pub fn update_vault_creation_config(
+    deps: DepsMut,
+    vault_creation_fee: Option<VaultCreationFee>,
+    default_vault_fees: Option<VaultFee>,
+    vault_fee_bounds: Option<VaultFeeBounds>,
+) -> StdResult<Response> {
+    if let Some(default_vault_fees) = &default_vault_fees {
+        default_vault_fees.is_valid()?;
+    }
+
+    if let Some(vault_fee_bounds) = &vault_fee_bounds {
+        vault_fee_bounds.max.is_valid()?;
+
+        if !vault_fee_bounds.contains(&vault_fee_bounds.min) {
+            return Err(VaultFactoryError::InvalidVaultFeeBounds {});
+        }
+
+        // the default fees have to be usable by anyone creating a vault
+        if let Some(default_vault_fees) = &default_vault_fees {
+            if !vault_fee_bounds.contains(default_vault_fees) {
+                return Err(VaultFactoryError::VaultFeesOutOfBounds {});
+            }
+        }
+    }
+
+    let vault_creation_fee_attr = vault_creation_fee
+        .as_ref()
+        .map_or_else(|| "None".to_string(), |fee| fee.fee.to_string());
+
+    CONFIG.update::<_, VaultFactoryError>(deps.storage, |mut config| {
+        config.vault_creation_fee = vault_creation_fee;
+        config.default_vault_fees = default_vault_fees;
+        config.vault_fee_bounds = vault_fee_bounds;
+
+        Ok(config)
+    })?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("method", "update_vault_creation_config"),
+        ("vault_creation_fee", &vault_creation_fee_attr),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_info, Addr, Decimal, Response, Uint128};
+    use white_whale::fee::{Fee, VaultFee};
+    use white_whale::pool_network::asset::{Asset, AssetInfo};
+    use white_whale::vault_network::vault_factory::{
+        Config, CreationFeeDestination, ExecuteMsg, VaultCreationFee, VaultFeeBounds,
+    };
+
+    use crate::{
+        contract::execute,
+        err::VaultFactoryError,
+        state::CONFIG,
+        tests::{get_fees, mock_creator, mock_execute, mock_instantiate::mock_instantiate},
+    };
+
+    fn get_fee_bounds() -> VaultFeeBounds {
+        let zero_fee = Fee {
+            share: Decimal::zero(),
+        };
+
+        VaultFeeBounds {
+            min: VaultFee {
+                protocol_fee: zero_fee.clone(),
+                flash_loan_fee: zero_fee.clone(),
+                burn_fee: zero_fee,
+            },
+            max: get_fees(),
+        }
+    }
+
+    #[test]
+    fn does_update_vault_creation_config() {
+        let vault_creation_fee = VaultCreationFee {
+            fee: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(1_000),
+            },
+            destination: CreationFeeDestination::FeeCollector,
+        };
+
+        let (res, mut deps, env) = mock_execute(
+            1,
+            2,
+            ExecuteMsg::UpdateVaultCreationConfig {
+                vault_creation_fee: Some(vault_creation_fee.clone()),
+                default_vault_fees: Some(get_fees()),
+                vault_fee_bounds: Some(get_fee_bounds()),
+            },
+        );
+
+        assert_eq!(
+            res.unwrap(),
+            Response::new().add_attributes(vec![
+                ("method", "update_vault_creation_config"),
+                ("vault_creation_fee", "1000uwhale"),
+            ])
+        );
+        assert_eq!(
+            CONFIG.load(&deps.storage).unwrap(),
+            Config {
+                owner: mock_creator().sender,
+                vault_id: 1,
+                token_id: 2,
+                fee_collector_addr: Addr::unchecked("fee_collector"),
+                vault_creation_fee: Some(vault_creation_fee),
+                default_vault_fees: Some(get_fees()),
+                vault_fee_bounds: Some(get_fee_bounds()),
+            }
+        );
+
+        // the settings that are not specified are removed
+        execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            ExecuteMsg::UpdateVaultCreationConfig {
+                vault_creation_fee: None,
+                default_vault_fees: None,
+                vault_fee_bounds: None,
+            },
+        )
+        .unwrap();
+
+        let config = CONFIG.load(&deps.storage).unwrap();
+        assert_eq!(config.vault_creation_fee, None);
+        assert_eq!(config.default_vault_fees, None);
+        assert_eq!(config.vault_fee_bounds, None);
+    }
+
+    #[test]
+    fn does_error_if_invalid_bounds() {
+        let mut vault_fee_bounds = get_fee_bounds();
+        vault_fee_bounds.min.burn_fee.share = Decimal::percent(1);
+
+        let (res, ..) = mock_execute(
+            1,
+            2,
+            ExecuteMsg::UpdateVaultCreationConfig {
+                vault_creation_fee: None,
+                default_vault_fees: None,
+                vault_fee_bounds: Some(vault_fee_bounds),
+            },
+        );
+
+        assert_eq!(
+            res.unwrap_err(),
+            VaultFactoryError::InvalidVaultFeeBounds {}
+        );
+    }
+
+    #[test]
+    fn does_error_if_default_fees_out_of_bounds() {
+        let mut default_vault_fees = get_fees();
+        default_vault_fees.protocol_fee.share = Decimal::percent(10);
+
+        let (res, ..) = mock_execute(
+            1,
+            2,
+            ExecuteMsg::UpdateVaultCreationConfig {
+                vault_creation_fee: None,
+                default_vault_fees: Some(default_vault_fees),
+                vault_fee_bounds: Some(get_fee_bounds()),
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), VaultFactoryError::VaultFeesOutOfBounds {});
+    }
+
+    #[test]
+    fn unauthorized_update_errors() {
+        let (mut deps, env) = mock_instantiate(1, 2);
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("bad_actor", &[]),
+            ExecuteMsg::UpdateVaultCreationConfig {
+                vault_creation_fee: None,
+                default_vault_fees: Some(get_fees()),
+                vault_fee_bounds: None,
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), VaultFactoryError::Unauthorized {});
+    }
+}
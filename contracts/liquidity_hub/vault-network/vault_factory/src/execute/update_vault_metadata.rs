@@ -0,0 +1,167 @@
+use cosmwasm_std::{DepsMut, Response};
+
+use white_whale::pool_network::asset::AssetInfo;
+use white_whale::vault_network::vault_factory::VaultMetadata;
+
+use crate::asset::AssetReference;
+use crate::err::{StdResult, VaultFactoryError};
+use crate::state::{VAULTS, VAULT_METADATA};
+
+pub fn update_vault_metadata(
+    deps: DepsMut,
+    asset_info: AssetInfo,
+    metadata: Option<VaultMetadata>,
+) -> StdResult<Response> {
+    if !VAULTS.has(deps.storage, asset_info.get_reference()) {
+        return Err(VaultFactoryError::NonExistentVault {});
+    }
+
+    match metadata {
+        Some(metadata) => {
+            VAULT_METADATA.save(deps.storage, asset_info.get_reference(), &metadata)?
+        }
+        None => VAULT_METADATA.remove(deps.storage, asset_info.get_reference()),
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        ("method", "update_vault_metadata"),
+        ("asset_info", &asset_info.to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cw_multi_test::Executor;
+
+    use white_whale::pool_network::asset::AssetInfo;
+    use white_whale::vault_network::vault_factory::{
+        ExecuteMsg, QueryMsg, RiskTier, VaultMetadata, VaultsFilter, VaultsResponse,
+    };
+
+    use crate::{
+        contract::execute,
+        err::VaultFactoryError,
+        tests::{
+            get_fees, mock_app, mock_creator,
+            mock_instantiate::{app_mock_instantiate, mock_instantiate},
+        },
+    };
+
+    #[test]
+    fn can_update_vault_metadata() {
+        let mut app = mock_app();
+        let factory_addr = app_mock_instantiate(&mut app);
+
+        // create vaults without metadata
+        for denom in ["uluna", "uwhale"] {
+            app.execute_contract(
+                mock_creator().sender,
+                factory_addr.clone(),
+                &ExecuteMsg::CreateVault {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: denom.to_string(),
+                    },
+                    fees: Some(get_fees()),
+                    metadata: None,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        let metadata = VaultMetadata {
+            display_name: "Whale vault".to_string(),
+            description: Some("Flash-loans WHALE".to_string()),
+            risk_tier: Some(RiskTier::Medium),
+        };
+        app.execute_contract(
+            mock_creator().sender,
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateVaultMetadata {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                metadata: Some(metadata.clone()),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // only the vault with the metadata matches the risk tier
+        let vaults_response: VaultsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr.clone(),
+                &QueryMsg::Vaults {
+                    start_after: None,
+                    limit: None,
+                    filter: Some(VaultsFilter {
+                        risk_tier: Some(RiskTier::Medium),
+                    }),
+                },
+            )
+            .unwrap();
+        assert_eq!(vaults_response.vaults.len(), 1);
+        assert_eq!(
+            vaults_response.vaults[0].asset_info,
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            }
+        );
+        assert_eq!(vaults_response.vaults[0].metadata, Some(metadata));
+
+        let vaults_response: VaultsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr,
+                &QueryMsg::Vaults {
+                    start_after: None,
+                    limit: None,
+                    filter: Some(VaultsFilter {
+                        risk_tier: Some(RiskTier::High),
+                    }),
+                },
+            )
+            .unwrap();
+        assert!(vaults_response.vaults.is_empty());
+    }
+
+    #[test]
+    fn cannot_update_metadata_of_non_existent_vault() {
+        let (mut deps, env) = mock_instantiate(5, 6);
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            ExecuteMsg::UpdateVaultMetadata {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                metadata: None,
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), VaultFactoryError::NonExistentVault {})
+    }
+
+    #[test]
+    fn cannot_update_vault_metadata_unauthorized() {
+        let (mut deps, env) = mock_instantiate(5, 6);
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("not_owner", &[]),
+            ExecuteMsg::UpdateVaultMetadata {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                metadata: None,
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), VaultFactoryError::Unauthorized {})
+    }
+}
@@ -1,9 +1,9 @@
 use cosmwasm_std::{Addr, DepsMut, Order, StdError};
-use cw_storage_plus::Map;
+use cw_storage_plus::{Item, Map};
 
 use white_whale::pool_network::asset::AssetInfo;
 
-use crate::state::{TMP_VAULT_ASSET, VAULTS};
+use crate::state::VAULTS;
 
 /// Migrates the VAULTS state from v1.0.9 and lower to v1.1.0, which includes the asset info.
 pub fn migrate_to_v110(deps: DepsMut) -> Result<(), StdError> {
@@ -29,6 +29,7 @@ pub fn migrate_to_v110(deps: DepsMut) -> Result<(), StdError> {
             Ok(())
         })?;
 
+    const TMP_VAULT_ASSET: Item<(Vec<u8>, AssetInfo)> = Item::new("tmp_vault_asset");
     TMP_VAULT_ASSET.remove(deps.storage);
 
     Ok(())
@@ -25,7 +25,10 @@ mod test {
                 owner: mock_creator().sender,
                 vault_id: 5,
                 token_id: 6,
-                fee_collector_addr: Addr::unchecked("fee_collector")
+                fee_collector_addr: Addr::unchecked("fee_collector"),
+                vault_creation_fee: None,
+                default_vault_fees: None,
+                vault_fee_bounds: None,
             }
         )
     }
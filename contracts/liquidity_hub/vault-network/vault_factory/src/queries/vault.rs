@@ -1,7 +1,7 @@
 use cosmwasm_std::{to_binary, Binary, Deps};
 
 use white_whale::pool_network::asset::AssetInfo;
-use white_whale::vault_network::vault_factory::{VaultInfo, VaultsResponse};
+use white_whale::vault_network::vault_factory::{VaultInfo, VaultsFilter, VaultsResponse};
 
 use crate::state::read_vaults;
 use crate::{asset::AssetReference, err::StdResult, state::VAULTS};
@@ -19,8 +19,9 @@ pub fn get_vaults(
     deps: Deps,
     start_after: Option<Vec<u8>>,
     limit: Option<u32>,
+    filter: Option<VaultsFilter>,
 ) -> StdResult<Binary> {
-    let vaults: Vec<VaultInfo> = read_vaults(deps.storage, deps.api, start_after, limit)?;
+    let vaults: Vec<VaultInfo> = read_vaults(deps.storage, deps.api, start_after, limit, filter)?;
     Ok(to_binary(&VaultsResponse { vaults })?)
 }
 
@@ -68,7 +69,8 @@ mod tests {
                 factory_addr.clone(),
                 &ExecuteMsg::CreateVault {
                     asset_info: asset_info.clone(),
-                    fees: get_fees(),
+                    fees: Some(get_fees()),
+                    metadata: None,
                 },
                 &[],
             )
@@ -110,7 +112,8 @@ mod tests {
                     factory_addr.clone(),
                     &ExecuteMsg::CreateVault {
                         asset_info: asset_info.clone(),
-                        fees: get_fees().clone(),
+                        fees: Some(get_fees()),
+                        metadata: None,
                     },
                     &[],
                 )
@@ -135,6 +138,7 @@ mod tests {
                 &QueryMsg::Vaults {
                     start_after: None,
                     limit: None,
+                    filter: None,
                 },
             )
             .unwrap();
@@ -160,6 +164,7 @@ mod tests {
                     &QueryMsg::Vaults {
                         start_after: start_after.clone(),
                         limit: Some(u32::try_from(4).unwrap()),
+                        filter: None,
                     },
                 )
                 .unwrap();
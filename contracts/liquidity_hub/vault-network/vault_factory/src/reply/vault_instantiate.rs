@@ -1,10 +1,10 @@
 use cosmwasm_std::{DepsMut, Env, Reply, Response, StdError};
 use protobuf::Message;
 
+use white_whale::vault_network::vault;
+
 use crate::{
-    err::StdResult,
-    response::MsgInstantiateContractResponse,
-    state::{TMP_VAULT_ASSET, VAULTS},
+    asset::AssetReference, err::StdResult, response::MsgInstantiateContractResponse, state::VAULTS,
 };
 
 pub fn vault_instantiate(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
@@ -29,14 +29,17 @@ pub fn vault_instantiate(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Resp
 
     let vault_address = deps.api.addr_validate(&res.contract_address)?;
 
-    // retrieve stored key from temp storage
-    let (asset_info_key, asset_info) = TMP_VAULT_ASSET.load(deps.storage)?;
+    // retrieve the asset from the vault itself, as multiple vaults can be created in the same tx
+    let vault_config: vault::Config = deps
+        .querier
+        .query_wasm_smart(vault_address.clone(), &vault::QueryMsg::Config {})?;
+    let asset_info = vault_config.asset_info;
 
     // save to vault storage
     VAULTS.save(
         deps.storage,
-        asset_info_key.as_slice(),
-        &(vault_address.clone(), asset_info),
+        asset_info.get_reference(),
+        &(vault_address.clone(), asset_info.clone()),
     )?;
 
     Ok(Response::new().add_attributes(vec![
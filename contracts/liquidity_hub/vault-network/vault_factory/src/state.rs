@@ -2,14 +2,14 @@ use cosmwasm_std::{Addr, Api, Order, StdResult, Storage};
 use cw_storage_plus::{Bound, Item, Map};
 use white_whale::pool_network::asset::AssetInfo;
 
-use white_whale::vault_network::vault_factory::{Config, VaultInfo};
+use white_whale::vault_network::vault_factory::{Config, VaultInfo, VaultMetadata, VaultsFilter};
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
 pub const VAULTS: Map<&[u8], (Addr, AssetInfo)> = Map::new("vaults");
 
-/// Used to temporarily store the asset being instantiated between `create_vault` and `reply` callback
-pub const TMP_VAULT_ASSET: Item<(Vec<u8>, AssetInfo)> = Item::new("tmp_vault_asset");
+/// The metadata of the vaults, keyed by the reference of the asset they manage
+pub const VAULT_METADATA: Map<&[u8], VaultMetadata> = Map::new("vault_metadata");
 
 // settings for pagination
 const MAX_LIMIT: u32 = 30;
@@ -20,13 +20,13 @@ pub fn read_vaults(
     _api: &dyn Api,
     start_after: Option<Vec<u8>>,
     limit: Option<u32>,
+    filter: Option<VaultsFilter>,
 ) -> StdResult<Vec<VaultInfo>> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
     let start = calc_range_start(start_after).map(Bound::ExclusiveRaw);
 
     VAULTS
         .range(storage, start, None, Order::Ascending)
-        .take(limit)
         .map(|item| {
             let (key, vault_data) = item?;
             let (vault_addr, asset_info) = vault_data;
@@ -34,12 +34,34 @@ pub fn read_vaults(
             Ok(VaultInfo {
                 vault: vault_addr.to_string(),
                 asset_info,
+                metadata: VAULT_METADATA.may_load(storage, &key)?,
                 asset_info_reference: key,
             })
         })
+        .filter(
+            |vault_info: &StdResult<VaultInfo>| match (vault_info, &filter) {
+                (Ok(vault_info), Some(filter)) => matches_filter(vault_info, filter),
+                _ => true,
+            },
+        )
+        .take(limit)
         .collect()
 }
 
+/// Checks whether the given vault matches the filter
+fn matches_filter(vault_info: &VaultInfo, filter: &VaultsFilter) -> bool {
+    match &filter.risk_tier {
+        Some(risk_tier) => {
+            vault_info
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.risk_tier.as_ref())
+                == Some(risk_tier)
+        }
+        None => true,
+    }
+}
+
 // this will set the first key after the provided key, by appending a 1 byte
 fn calc_range_start(start_after: Option<Vec<u8>>) -> Option<Vec<u8>> {
     start_after.map(|asset_info| {
@@ -91,7 +91,8 @@ pub fn app_mock_instantiate(app: &mut App) -> AppInstantiateResponse {
             asset_info: AssetInfo::NativeToken {
                 denom: "uluna".to_string(),
             },
-            fees: get_fees(),
+            fees: Some(get_fees()),
+            metadata: None,
         },
         &[],
     )
@@ -145,7 +146,8 @@ pub fn app_mock_instantiate(app: &mut App) -> AppInstantiateResponse {
             asset_info: AssetInfo::Token {
                 contract_addr: token_addr.clone().into_string(),
             },
-            fees: get_fees(),
+            fees: Some(get_fees()),
+            metadata: None,
         },
         &[],
     )
@@ -179,6 +181,8 @@ pub fn app_mock_instantiate(app: &mut App) -> AppInstantiateResponse {
         token_vault_addr.clone(),
         &white_whale::vault_network::vault::ExecuteMsg::Deposit {
             amount: Uint128::new(10_000),
+            receiver: None,
+            min_lp_out: None,
         },
         &[],
     )
@@ -1,81 +1,270 @@
 use cosmwasm_std::{
-    Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, MessageInfo, Order, Response, StdResult,
-    Timestamp, Uint128, Uint64,
+    coins, from_binary, to_binary, Addr, Decimal, DepsMut, Env, MessageInfo, Order, Response,
+    StdError, StdResult, Storage, Timestamp, Uint128, Uint64, WasmMsg,
 };
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use white_whale::fee_distributor;
+use white_whale::fee_distributor::ClaimableEpochsResponse;
 use white_whale::pool_network::asset;
 use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::pool_network::router;
+use white_whale::pool_network::router::SwapOperation;
 
-use white_whale::whale_lair::Bond;
+use white_whale::whale_lair::{
+    Bond, BondingAsset, CallbackMsg, CompoundConfig, Cw20HookMsg, Delegation, DelegationType,
+    ExecuteMsg, LockedBond, PenaltyRecipient,
+};
 
-use crate::helpers::validate_growth_rate;
-use crate::queries::MAX_PAGE_LIMIT;
-use crate::state::{update_global_weight, update_local_weight, BOND, CONFIG, GLOBAL, UNBOND};
+use crate::helpers::{
+    query_exchange_rate, validate_bonding_asset, validate_compound_config, validate_growth_rate,
+    validate_instant_unbond_penalty, validate_penalty_recipient,
+};
+use crate::state::{
+    delegations, get_lock_boost, get_lock_multiplier, get_lock_weight, get_matured_unbondings,
+    snapshot_legacy_weight, snapshot_weight, update_global_weight, update_local_weight,
+    ADDRESS_LOCK_EXPIRIES, AUTO_COMPOUND, BOND, BONDING_ASSETS_LIMIT, CONFIG, EXCHANGE_RATES,
+    GLOBAL, LOCK, LOCKED_BOOST, LOCK_COUNTER, LOCK_EXPIRIES, MAX_DELEGATIONS_PER_DELEGATE,
+    MAX_LOCK_DURATION, MIN_LOCK_DURATION, UNBOND, WEIGHT_DELEGATE, WEIGHT_DELEGATORS,
+    WEIGHT_FACTORS,
+};
 use crate::ContractError;
 
-/// Bonds the provided asset.
+/// Receives cw20 tokens sent to the contract through the cw20 Receive hook.
+pub(crate) fn receive_cw20(
+    deps: DepsMut,
+    timestamp: Timestamp,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let lock_duration = match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::Bond {} => None,
+        Cw20HookMsg::BondLocked { duration } => Some(duration),
+    };
+
+    let address = deps.api.addr_validate(&cw20_msg.sender)?;
+    let asset = Asset {
+        info: AssetInfo::Token {
+            contract_addr: info.sender.to_string(),
+        },
+        amount: cw20_msg.amount,
+    };
+
+    bond_asset(deps, timestamp, address, asset, lock_duration)
+}
+
+/// Bonds the provided native asset, locking it if a lock duration is provided.
 pub(crate) fn bond(
-    mut deps: DepsMut,
+    deps: DepsMut,
     timestamp: Timestamp,
     info: MessageInfo,
     asset: Asset,
+    lock_duration: Option<Uint64>,
 ) -> Result<Response, ContractError> {
-    // validate the denom sent is the whitelisted one for bonding
-    let bonding_assets = CONFIG.load(deps.storage)?.bonding_assets;
-
+    // cw20 tokens are bonded through the Receive hook
     let denom = match asset.info.clone() {
         AssetInfo::NativeToken { denom } => denom,
         AssetInfo::Token { .. } => return Err(ContractError::InvalidBondingAsset {}),
     };
 
-    if info.funds.len() != 1
-        || info.funds[0].amount != asset.amount
-        || info.funds[0].denom != denom
-        || !bonding_assets.iter().any(|asset_info| {
-            let d = match asset_info {
-                AssetInfo::NativeToken { denom } => denom.clone(),
-                AssetInfo::Token { .. } => String::new(), //shouldn't reach this point
-            };
-            d == denom
-        })
+    if info.funds.len() != 1 || info.funds[0].amount != asset.amount || info.funds[0].denom != denom
     {
         return Err(ContractError::AssetMismatch {});
     }
 
-    let mut bond = BOND
-        .key((&info.sender, &denom))
-        .may_load(deps.storage)?
-        .unwrap_or_default();
+    bond_asset(deps, timestamp, info.sender, asset, lock_duration)
+}
 
-    if bond == Bond::default() {
-        bond = Bond {
-            asset: Asset {
-                amount: Uint128::zero(),
-                ..asset.clone()
-            },
-            ..bond
-        };
+/// Bonds the given asset on behalf of the provided address, once the tokens have been received.
+fn bond_asset(
+    mut deps: DepsMut,
+    timestamp: Timestamp,
+    address: Addr,
+    asset: Asset,
+    lock_duration: Option<Uint64>,
+) -> Result<Response, ContractError> {
+    // validate the asset sent is whitelisted for bonding
+    let bonding_assets = CONFIG.load(deps.storage)?.bonding_assets;
+    if !bonding_assets
+        .iter()
+        .any(|bonding_asset| bonding_asset.info == asset.info)
+    {
+        return Err(ContractError::AssetMismatch {});
     }
 
+    if let Some(duration) = lock_duration {
+        return lock_asset(deps, timestamp, address, asset, duration);
+    }
+
+    add_bond(&mut deps, timestamp, &address, &asset)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "bond".to_string()),
+        ("address", address.to_string()),
+        ("asset", asset.to_string()),
+    ]))
+}
+
+/// Adds the given asset to the bond of the address and updates the global index accordingly.
+fn add_bond(
+    deps: &mut DepsMut,
+    timestamp: Timestamp,
+    address: &Addr,
+    asset: &Asset,
+) -> Result<(), ContractError> {
+    let denom = asset.clone().get_id();
+    let mut bond = load_bond(deps, address, asset)?;
+
     // update local values
-    bond = update_local_weight(&mut deps, info.sender.clone(), timestamp, bond)?;
+    bond = update_local_weight(deps, address.clone(), timestamp, bond)?;
     bond.asset.amount = bond.asset.amount.checked_add(asset.amount)?;
-    BOND.save(deps.storage, (&info.sender, &denom), &bond)?;
+    BOND.save(deps.storage, (address, &denom), &bond)?;
+    snapshot_weight(deps.storage, address, timestamp)?;
 
     // update global values
     let mut global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
+    global_index = update_global_weight(deps, timestamp, global_index)?;
+    global_index.bonded_amount = global_index.bonded_amount.checked_add(asset.amount)?;
+    global_index.bonded_assets =
+        asset::aggregate_assets(global_index.bonded_assets, vec![asset.clone()])?;
+    GLOBAL.save(deps.storage, &global_index, timestamp.seconds())?;
+
+    Ok(())
+}
+
+/// Locks the given asset on behalf of the provided address for the given duration.
+fn lock_asset(
+    mut deps: DepsMut,
+    timestamp: Timestamp,
+    address: Addr,
+    asset: Asset,
+    duration: Uint64,
+) -> Result<Response, ContractError> {
+    if duration.u64() < MIN_LOCK_DURATION || duration.u64() > MAX_LOCK_DURATION {
+        return Err(ContractError::InvalidLockDuration(
+            MIN_LOCK_DURATION,
+            MAX_LOCK_DURATION,
+        ));
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    snapshot_legacy_weight(deps.storage, &config, &address)?;
+
+    // update global values before adding the boost of the new lock
+    let mut global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
     global_index = update_global_weight(&mut deps, timestamp, global_index)?;
     global_index.bonded_amount = global_index.bonded_amount.checked_add(asset.amount)?;
     global_index.bonded_assets =
         asset::aggregate_assets(global_index.bonded_assets, vec![asset.clone()])?;
-    GLOBAL.save(deps.storage, &global_index)?;
+    GLOBAL.save(deps.storage, &global_index, timestamp.seconds())?;
+
+    let multiplier = get_lock_multiplier(duration);
+    let unlock_time = timestamp.plus_nanos(duration.u64());
+
+    let lock_id = LOCK_COUNTER
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .checked_add(1u64)
+        .ok_or_else(|| StdError::generic_err("Lock counter overflow"))?;
+    LOCK_COUNTER.save(deps.storage, &lock_id)?;
+
+    let lock = LockedBond {
+        id: lock_id,
+        asset: asset.clone(),
+        timestamp,
+        weight: Uint128::zero(),
+        multiplier,
+        unlock_time,
+    };
+
+    // the boost is the weighted amount the lock adds on top of the locked amount, which expires
+    // at the unlock time
+    let boost = get_lock_boost(deps.storage, &config, &lock)?;
+    let locked_boost = LOCKED_BOOST.may_load(deps.storage)?.unwrap_or_default();
+    LOCKED_BOOST.save(
+        deps.storage,
+        &locked_boost.checked_add(boost)?,
+        timestamp.seconds(),
+    )?;
+    LOCK_EXPIRIES.update(
+        deps.storage,
+        unlock_time.seconds(),
+        |expiring| -> StdResult<_> { Ok(expiring.unwrap_or_default().checked_add(boost)?) },
+    )?;
+    ADDRESS_LOCK_EXPIRIES.update(
+        deps.storage,
+        (&address, unlock_time.seconds()),
+        |expiring| -> StdResult<_> { Ok(expiring.unwrap_or_default().checked_add(boost)?) },
+    )?;
+
+    LOCK.save(deps.storage, (&address, lock_id), &lock)?;
+    snapshot_weight(deps.storage, &address, timestamp)?;
 
     Ok(Response::default().add_attributes(vec![
-        ("action", "bond".to_string()),
-        ("address", info.sender.to_string()),
+        ("action", "bond_locked".to_string()),
+        ("address", address.to_string()),
         ("asset", asset.to_string()),
+        ("lock_id", lock_id.to_string()),
+        ("multiplier", multiplier.to_string()),
+        ("unlock_time", unlock_time.to_string()),
+    ]))
+}
+
+/// Turns an expired lock into a regular bond, carrying over the weight accrued by the lock.
+pub(crate) fn unlock(
+    mut deps: DepsMut,
+    timestamp: Timestamp,
+    info: MessageInfo,
+    lock_id: u64,
+) -> Result<Response, ContractError> {
+    let mut lock = LOCK
+        .may_load(deps.storage, (&info.sender, lock_id))?
+        .ok_or(ContractError::LockNotFound {})?;
+
+    if timestamp < lock.unlock_time {
+        return Err(ContractError::LockNotExpired(lock.unlock_time));
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    lock.weight = get_lock_weight(deps.storage, &config, &lock, timestamp)?;
+    LOCK.remove(deps.storage, (&info.sender, lock_id));
+
+    // the global boost of the lock expired at its unlock time
+    let global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
+    update_global_weight(&mut deps, timestamp, global_index)?;
+
+    // merge the lock into the regular bond of the address
+    let denom = lock.asset.clone().get_id();
+    let mut bond = load_bond(&deps, &info.sender, &lock.asset)?;
+    bond = update_local_weight(&mut deps, info.sender.clone(), timestamp, bond)?;
+    bond.asset.amount = bond.asset.amount.checked_add(lock.asset.amount)?;
+    bond.weight = bond.weight.checked_add(lock.weight)?;
+    BOND.save(deps.storage, (&info.sender, &denom), &bond)?;
+    snapshot_weight(deps.storage, &info.sender, timestamp)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "unlock".to_string()),
+        ("address", info.sender.to_string()),
+        ("lock_id", lock_id.to_string()),
+        ("asset", lock.asset.to_string()),
     ]))
 }
 
+/// Loads the bond of the given address for the given asset, or an empty one if there's none.
+fn load_bond(deps: &DepsMut, address: &Addr, asset: &Asset) -> StdResult<Bond> {
+    let bond = BOND
+        .key((address, &asset.clone().get_id()))
+        .may_load(deps.storage)?
+        .unwrap_or_else(|| Bond {
+            asset: Asset {
+                info: asset.info.clone(),
+                amount: Uint128::zero(),
+            },
+            ..Bond::default()
+        });
+
+    Ok(bond)
+}
+
 /// Unbonds the provided amount of tokens
 pub(crate) fn unbond(
     mut deps: DepsMut,
@@ -83,101 +272,351 @@ pub(crate) fn unbond(
     info: MessageInfo,
     asset: Asset,
 ) -> Result<Response, ContractError> {
-    if asset.amount.is_zero() {
-        return Err(ContractError::InvalidUnbondingAmount {});
+    deduct_bond(&mut deps, timestamp, &info.sender, &asset)?;
+
+    // record the unbonding, merging it with any other one made within the same second
+    UNBOND.update(
+        deps.storage,
+        (&info.sender, &asset.clone().get_id(), timestamp.seconds()),
+        |unbonding| -> StdResult<_> {
+            let mut unbonding = unbonding.unwrap_or_else(|| Bond {
+                asset: Asset {
+                    info: asset.info.clone(),
+                    amount: Uint128::zero(),
+                },
+                weight: Uint128::zero(),
+                timestamp,
+            });
+            unbonding.asset.amount = unbonding.asset.amount.checked_add(asset.amount)?;
+            unbonding.timestamp = timestamp;
+
+            Ok(unbonding)
+        },
+    )?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "unbond".to_string()),
+        ("address", info.sender.to_string()),
+        ("asset", asset.to_string()),
+    ]))
+}
+
+/// Moves pending unbondings of the given denom back into the bond of the sender. If no unbonding
+/// timestamps are provided, all the pending unbondings are rebonded.
+///
+/// The weight slashed when unbonding is not restored, the rebonded tokens start accruing weight
+/// from the moment they are rebonded, as if they were bonded anew.
+pub(crate) fn rebond(
+    mut deps: DepsMut,
+    timestamp: Timestamp,
+    info: MessageInfo,
+    denom: String,
+    unbonding_timestamps: Option<Vec<u64>>,
+) -> Result<Response, ContractError> {
+    let unbondings: Vec<(u64, Bond)> = match unbonding_timestamps {
+        Some(unbonding_timestamps) => unbonding_timestamps
+            .into_iter()
+            .map(|ts| {
+                UNBOND
+                    .may_load(deps.storage, (&info.sender, &denom, ts))?
+                    .map(|bond| (ts, bond))
+                    .ok_or(ContractError::UnbondingNotFound(ts))
+            })
+            .collect::<Result<Vec<(u64, Bond)>, ContractError>>()?,
+        None => UNBOND
+            .prefix((&info.sender, &denom))
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<(u64, Bond)>>>()?,
+    };
+
+    if unbondings.is_empty() {
+        return Err(ContractError::NothingToRebond {});
     }
 
-    let denom = match asset.info.clone() {
-        AssetInfo::NativeToken { denom } => denom,
-        AssetInfo::Token { .. } => return Err(ContractError::InvalidBondingAsset {}),
+    let mut rebond_asset = Asset {
+        info: unbondings[0].1.asset.info.clone(),
+        amount: Uint128::zero(),
     };
 
-    if let Some(mut unbond) = BOND.key((&info.sender, &denom)).may_load(deps.storage)? {
-        // check if the address has enough bond
-        if unbond.asset.amount < asset.amount {
-            return Err(ContractError::InsufficientBond {});
+    for (ts, unbonding) in unbondings {
+        // the same timestamp could be provided more than once
+        if UNBOND.has(deps.storage, (&info.sender, &denom, ts)) {
+            rebond_asset.amount = rebond_asset.amount.checked_add(unbonding.asset.amount)?;
+            UNBOND.remove(deps.storage, (&info.sender, &denom, ts));
         }
+    }
 
-        // update local values, decrease the bond
-        unbond = update_local_weight(&mut deps, info.sender.clone(), timestamp, unbond.clone())?;
-        let weight_slash = unbond
-            .weight
-            .checked_mul(asset.amount.checked_div(unbond.asset.amount)?)?;
-        unbond.asset.amount = unbond.asset.amount.checked_sub(asset.amount)?;
-        unbond.weight = unbond.weight.checked_sub(weight_slash)?;
-        BOND.save(deps.storage, (&info.sender, &denom), &unbond)?;
-
-        // record the unbonding
-        UNBOND.save(
-            deps.storage,
-            (&info.sender, &denom, timestamp.seconds()),
-            &Bond {
-                asset: asset.clone(),
-                weight: Uint128::zero(),
-                timestamp,
-            },
-        )?;
+    add_bond(&mut deps, timestamp, &info.sender, &rebond_asset)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "rebond".to_string()),
+        ("address", info.sender.to_string()),
+        ("asset", rebond_asset.to_string()),
+    ]))
+}
+
+/// Unbonds the provided amount of tokens without waiting for the unbonding period, sending them
+/// right away minus the instant unbonding penalty.
+pub(crate) fn instant_unbond(
+    mut deps: DepsMut,
+    timestamp: Timestamp,
+    info: MessageInfo,
+    asset: Asset,
+) -> Result<Response, ContractError> {
+    deduct_bond(&mut deps, timestamp, &info.sender, &asset)?;
 
-        // update global values
-        let mut global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
-        global_index = update_global_weight(&mut deps, timestamp, global_index)?;
-        global_index.bonded_amount = global_index.bonded_amount.checked_sub(asset.amount)?;
-        global_index.bonded_assets =
-            asset::deduct_assets(global_index.bonded_assets, vec![asset.clone()])?;
-        global_index.weight = global_index.weight.checked_sub(weight_slash)?;
-        GLOBAL.save(deps.storage, &global_index)?;
-
-        Ok(Response::default().add_attributes(vec![
-            ("action", "unbond".to_string()),
+    let config = CONFIG.load(deps.storage)?;
+    let penalty = Asset {
+        info: asset.info.clone(),
+        amount: asset.amount * config.instant_unbond_penalty,
+    };
+    let refund = Asset {
+        info: asset.info.clone(),
+        amount: asset.amount.checked_sub(penalty.amount)?,
+    };
+
+    let mut messages = vec![];
+    if !refund.amount.is_zero() {
+        messages.push(refund.clone().into_msg(info.sender.clone())?);
+    }
+    if !penalty.amount.is_zero() {
+        messages.push(match &config.penalty_recipient {
+            PenaltyRecipient::Burn => penalty.clone().into_burn_msg()?,
+            PenaltyRecipient::FeeCollector { address } => {
+                penalty.clone().into_msg(deps.api.addr_validate(address)?)?
+            }
+        });
+    }
+
+    Ok(Response::default()
+        .add_messages(messages)
+        .add_attributes(vec![
+            ("action", "instant_unbond".to_string()),
             ("address", info.sender.to_string()),
             ("asset", asset.to_string()),
+            ("refund", refund.to_string()),
+            ("penalty", penalty.to_string()),
+            ("penalty_recipient", config.penalty_recipient.to_string()),
         ]))
+}
+
+/// Deducts the given asset from the bond of the address, slashing its weight proportionally, and
+/// updates the global index accordingly.
+fn deduct_bond(
+    deps: &mut DepsMut,
+    timestamp: Timestamp,
+    address: &Addr,
+    asset: &Asset,
+) -> Result<(), ContractError> {
+    if asset.amount.is_zero() {
+        return Err(ContractError::InvalidUnbondingAmount {});
+    }
+
+    let denom = asset.clone().get_id();
+
+    let mut unbond = BOND
+        .key((address, &denom))
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NothingToUnbond {})?;
+
+    // check if the address has enough bond
+    if unbond.asset.amount < asset.amount {
+        return Err(ContractError::InsufficientBond {});
+    }
+
+    // update local values, decrease the bond
+    unbond = update_local_weight(deps, address.clone(), timestamp, unbond)?;
+    let weight_slash = unbond
+        .weight
+        .multiply_ratio(asset.amount, unbond.asset.amount);
+    unbond.asset.amount = unbond.asset.amount.checked_sub(asset.amount)?;
+    unbond.weight = unbond.weight.checked_sub(weight_slash)?;
+    BOND.save(deps.storage, (address, &denom), &unbond)?;
+    snapshot_weight(deps.storage, address, timestamp)?;
+
+    // update global values
+    let mut global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
+    global_index = update_global_weight(deps, timestamp, global_index)?;
+    global_index.bonded_amount = global_index.bonded_amount.checked_sub(asset.amount)?;
+    global_index.bonded_assets =
+        asset::deduct_assets(global_index.bonded_assets, vec![asset.clone()])?;
+    global_index.weight = global_index.weight.checked_sub(weight_slash)?;
+    GLOBAL.save(deps.storage, &global_index, timestamp.seconds())?;
+
+    Ok(())
+}
+
+/// Delegates the bonding weight or the reward-claim rights of the sender to the given address.
+pub(crate) fn delegate(
+    deps: DepsMut,
+    timestamp: Timestamp,
+    info: MessageInfo,
+    delegate: String,
+    delegation_type: DelegationType,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    if delegate == info.sender {
+        return Err(ContractError::InvalidDelegate {});
+    }
+
+    // only bonders can delegate, so that delegates can't be spammed with empty delegations
+    let mut has_bond = LOCK
+        .prefix(&info.sender)
+        .range(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    for item in BOND
+        .prefix(&info.sender)
+        .range(deps.storage, None, None, Order::Ascending)
+    {
+        let (_, bond) = item?;
+        has_bond |= !bond.asset.amount.is_zero();
+    }
+    if !has_bond {
+        return Err(ContractError::NothingToDelegate {});
+    }
+
+    let received_delegations = delegations()
+        .idx
+        .delegate
+        .prefix(delegate.to_string())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(MAX_DELEGATIONS_PER_DELEGATE)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    if received_delegations.len() == MAX_DELEGATIONS_PER_DELEGATE
+        && !received_delegations.contains(&info.sender)
+    {
+        return Err(ContractError::TooManyDelegations(
+            MAX_DELEGATIONS_PER_DELEGATE,
+        ));
+    }
+
+    // replace the previous delegation of the sender, if any
+    if let Some(previous_delegation) = delegations().may_load(deps.storage, &info.sender)? {
+        remove_weight_delegation(deps.storage, &previous_delegation, timestamp)?;
+    }
+
+    let delegation = Delegation {
+        delegator: info.sender.clone(),
+        delegate,
+        delegation_type,
+    };
+    delegations().save(deps.storage, &info.sender, &delegation)?;
+
+    // weight delegations are snapshotted so that the weight is also honored in past weight queries
+    if delegation.delegation_type == DelegationType::Weight {
+        WEIGHT_DELEGATE.save(
+            deps.storage,
+            &delegation.delegator,
+            &delegation.delegate,
+            timestamp.seconds(),
+        )?;
+
+        let mut delegators = WEIGHT_DELEGATORS
+            .may_load(deps.storage, &delegation.delegate)?
+            .unwrap_or_default();
+        delegators.push(delegation.delegator.clone());
+        WEIGHT_DELEGATORS.save(
+            deps.storage,
+            &delegation.delegate,
+            &delegators,
+            timestamp.seconds(),
+        )?;
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "delegate".to_string()),
+        ("delegator", delegation.delegator.to_string()),
+        ("delegate", delegation.delegate.to_string()),
+        ("delegation_type", delegation.delegation_type.to_string()),
+    ]))
+}
+
+/// Removes the delegation of the sender.
+pub(crate) fn undelegate(
+    deps: DepsMut,
+    timestamp: Timestamp,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let delegation = delegations()
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::NothingToUndelegate {})?;
+    delegations().remove(deps.storage, &info.sender)?;
+    remove_weight_delegation(deps.storage, &delegation, timestamp)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "undelegate".to_string()),
+        ("delegator", delegation.delegator.to_string()),
+        ("delegate", delegation.delegate.to_string()),
+    ]))
+}
+
+/// Removes the given delegation from the weight delegation snapshots if it delegated the weight.
+fn remove_weight_delegation(
+    storage: &mut dyn Storage,
+    delegation: &Delegation,
+    timestamp: Timestamp,
+) -> StdResult<()> {
+    if delegation.delegation_type != DelegationType::Weight {
+        return Ok(());
+    }
+
+    WEIGHT_DELEGATE.remove(storage, &delegation.delegator, timestamp.seconds())?;
+
+    let mut delegators = WEIGHT_DELEGATORS
+        .may_load(storage, &delegation.delegate)?
+        .unwrap_or_default();
+    delegators.retain(|delegator| delegator != &delegation.delegator);
+    if delegators.is_empty() {
+        WEIGHT_DELEGATORS.remove(storage, &delegation.delegate, timestamp.seconds())
     } else {
-        Err(ContractError::NothingToUnbond {})
+        WEIGHT_DELEGATORS.save(
+            storage,
+            &delegation.delegate,
+            &delegators,
+            timestamp.seconds(),
+        )
     }
 }
 
-/// Withdraws the rewards for the provided address
+/// Withdraws the unbondings of the given denom that have passed the unbonding period, oldest
+/// first, up to the given limit if any.
 pub(crate) fn withdraw(
     deps: DepsMut,
     timestamp: Timestamp,
     address: Addr,
     denom: String,
+    limit: Option<u8>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
-    let unbondings: Vec<(u64, Bond)> = UNBOND
-        .prefix((&address, &denom))
-        .range(deps.storage, None, None, Order::Ascending)
-        .take(MAX_PAGE_LIMIT as usize)
-        .collect::<StdResult<Vec<(u64, Bond)>>>()?;
-
-    let mut refund_amount = Uint128::zero();
+    let unbondings = get_matured_unbondings(
+        deps.storage,
+        &config,
+        &address,
+        &denom,
+        timestamp,
+        limit.map(usize::from),
+    )?;
 
     if unbondings.is_empty() {
         return Err(ContractError::NothingToWithdraw {});
     }
 
-    for unbonding in unbondings {
-        let (ts, bond) = unbonding;
-        if timestamp.minus_nanos(config.unbonding_period.u64()) >= bond.timestamp {
-            let denom = match bond.asset.info {
-                AssetInfo::Token { .. } => return Err(ContractError::InvalidBondingAsset {}),
-                AssetInfo::NativeToken { denom } => denom,
-            };
+    // the denom is either a native denom or a cw20 token address, take the asset info from the
+    // unbonding records to build the refund
+    let mut refund_asset = Asset {
+        info: unbondings[0].1.asset.info.clone(),
+        amount: Uint128::zero(),
+    };
 
-            refund_amount = refund_amount.checked_add(bond.asset.amount)?;
-            UNBOND.remove(deps.storage, (&address, &denom, ts));
-        }
+    for (ts, bond) in unbondings {
+        refund_asset.amount = refund_asset.amount.checked_add(bond.asset.amount)?;
+        UNBOND.remove(deps.storage, (&address, &denom, ts));
     }
 
-    let refund_msg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: address.to_string(),
-        amount: vec![Coin {
-            denom: denom.clone(),
-            amount: refund_amount,
-        }],
-    });
+    let refund_amount = refund_asset.amount;
+    let refund_msg = refund_asset.into_msg(address.clone())?;
 
     Ok(Response::default()
         .add_message(refund_msg)
@@ -189,13 +628,462 @@ pub(crate) fn withdraw(
         ]))
 }
 
+/// Opts the sender in or out of the auto-compounding of its rewards.
+pub(crate) fn set_auto_compound(
+    deps: DepsMut,
+    info: MessageInfo,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    if enabled {
+        AUTO_COMPOUND.save(deps.storage, &info.sender, &true)?;
+    } else {
+        AUTO_COMPOUND.remove(deps.storage, &info.sender);
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "set_auto_compound".to_string()),
+        ("address", info.sender.to_string()),
+        ("enabled", enabled.to_string()),
+    ]))
+}
+
+/// Compounds the rewards of the given addresses that opted in to auto-compounding. The addresses
+/// that didn't opt in or have nothing to claim are skipped. Only the keepers can do this.
+pub(crate) fn compound(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    let compound_config = CONFIG
+        .load(deps.storage)?
+        .compound_config
+        .ok_or(ContractError::CompoundNotConfigured {})?;
+
+    if !compound_config
+        .keepers
+        .iter()
+        .any(|keeper| keeper == info.sender.as_str())
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut messages = vec![];
+    for address in addresses {
+        let address = deps.api.addr_validate(&address)?;
+        if !AUTO_COMPOUND.has(deps.storage, &address) {
+            continue;
+        }
+
+        let claimable: ClaimableEpochsResponse = deps.querier.query_wasm_smart(
+            &compound_config.fee_distributor_addr,
+            &fee_distributor::QueryMsg::Claimable {
+                address: address.to_string(),
+            },
+        )?;
+        if claimable.epochs.is_empty() {
+            continue;
+        }
+
+        messages.push(get_callback_msg(
+            &env,
+            CallbackMsg::ClaimRewards {
+                address,
+                keeper: info.sender.clone(),
+            },
+        )?);
+    }
+
+    Ok(Response::default()
+        .add_attributes(vec![
+            ("action", "compound".to_string()),
+            ("keeper", info.sender.to_string()),
+            ("compounded_addresses", messages.len().to_string()),
+        ])
+        .add_messages(messages))
+}
+
+/// Handles the callback messages, which can only be sent by the contract itself.
+pub(crate) fn callback(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: CallbackMsg,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match msg {
+        CallbackMsg::ClaimRewards { address, keeper } => claim_rewards(deps, env, address, keeper),
+        CallbackMsg::CompoundRewards {
+            address,
+            keeper,
+            asset_info,
+            prev_balance,
+        } => compound_rewards(deps, env, address, keeper, asset_info, prev_balance),
+    }
+}
+
+/// Claims the rewards of the given address from the fee distributor, which sends them to the
+/// contract, and compounds them right after.
+fn claim_rewards(
+    deps: DepsMut,
+    env: Env,
+    address: Addr,
+    keeper: Addr,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let compound_config = config
+        .compound_config
+        .ok_or(ContractError::CompoundNotConfigured {})?;
+
+    let distributor_config: fee_distributor::Config = deps.querier.query_wasm_smart(
+        &compound_config.fee_distributor_addr,
+        &fee_distributor::QueryMsg::Config {},
+    )?;
+
+    let claim_msg = WasmMsg::Execute {
+        contract_addr: compound_config.fee_distributor_addr,
+        msg: to_binary(&fee_distributor::ExecuteMsg::Claim {
+            delegator: Some(address.to_string()),
+        })?,
+        funds: vec![],
+    };
+
+    // each of the distributed assets is compounded separately. The bonding assets go first, as the
+    // swapped rewards bonded afterwards would otherwise be counted as rewards of the bonding asset
+    let (bonding_asset_infos, swapped_asset_infos): (Vec<_>, Vec<_>) = distributor_config
+        .distribution_assets
+        .into_iter()
+        .partition(|asset_info| {
+            config
+                .bonding_assets
+                .iter()
+                .any(|bonding_asset| &bonding_asset.info == asset_info)
+        });
+
+    let mut compound_msgs = vec![];
+    for asset_info in bonding_asset_infos.into_iter().chain(swapped_asset_infos) {
+        let prev_balance =
+            asset_info.query_pool(&deps.querier, deps.api, env.contract.address.clone())?;
+
+        compound_msgs.push(get_callback_msg(
+            &env,
+            CallbackMsg::CompoundRewards {
+                address: address.clone(),
+                keeper: keeper.clone(),
+                asset_info,
+                prev_balance,
+            },
+        )?);
+    }
+
+    Ok(Response::default()
+        .add_message(claim_msg)
+        .add_messages(compound_msgs)
+        .add_attributes(vec![
+            ("action", "claim_rewards".to_string()),
+            ("address", address.to_string()),
+        ]))
+}
+
+/// Compounds the rewards received since the balance of the given asset was `prev_balance`. If the
+/// asset is a bonding asset, the rewards are bonded on behalf of the address minus the keeper tip.
+/// Otherwise, they are swapped to the first bonding asset to be compounded afterwards.
+fn compound_rewards(
+    mut deps: DepsMut,
+    env: Env,
+    address: Addr,
+    keeper: Addr,
+    asset_info: AssetInfo,
+    prev_balance: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let compound_config = config
+        .compound_config
+        .ok_or(ContractError::CompoundNotConfigured {})?;
+
+    let rewards = Asset {
+        info: asset_info.clone(),
+        amount: asset_info
+            .query_pool(&deps.querier, deps.api, env.contract.address.clone())?
+            .checked_sub(prev_balance)?,
+    };
+
+    if rewards.amount.is_zero() {
+        return Ok(Response::default().add_attributes(vec![
+            ("action", "compound_rewards".to_string()),
+            ("address", address.to_string()),
+            ("rewards", rewards.to_string()),
+        ]));
+    }
+
+    if !config
+        .bonding_assets
+        .iter()
+        .any(|bonding_asset| bonding_asset.info == asset_info)
+    {
+        let bonding_asset_info = config
+            .bonding_assets
+            .first()
+            .ok_or(ContractError::InvalidBondingAsset {})?
+            .info
+            .clone();
+
+        return swap_rewards(
+            deps,
+            env,
+            &compound_config,
+            address,
+            keeper,
+            rewards,
+            bonding_asset_info,
+        );
+    }
+
+    let tip = Asset {
+        info: asset_info.clone(),
+        amount: rewards.amount * compound_config.keeper_tip,
+    };
+    let compounded = Asset {
+        info: asset_info,
+        amount: rewards.amount.checked_sub(tip.amount)?,
+    };
+
+    add_bond(&mut deps, env.block.time, &address, &compounded)?;
+
+    let mut messages = vec![];
+    if !tip.amount.is_zero() {
+        messages.push(tip.clone().into_msg(keeper.clone())?);
+    }
+
+    Ok(Response::default()
+        .add_messages(messages)
+        .add_attributes(vec![
+            ("action", "compound_rewards".to_string()),
+            ("address", address.to_string()),
+            ("rewards", rewards.to_string()),
+            ("compounded", compounded.to_string()),
+            ("keeper", keeper.to_string()),
+            ("tip", tip.to_string()),
+        ]))
+}
+
+/// Swaps the given rewards to the given bonding asset through the router, and compounds them
+/// right after.
+fn swap_rewards(
+    deps: DepsMut,
+    env: Env,
+    compound_config: &CompoundConfig,
+    address: Addr,
+    keeper: Addr,
+    rewards: Asset,
+    bonding_asset_info: AssetInfo,
+) -> Result<Response, ContractError> {
+    let router_addr = &compound_config.router_addr;
+    let operations: Vec<SwapOperation> = deps.querier.query_wasm_smart(
+        router_addr,
+        &router::QueryMsg::SwapRoute {
+            offer_asset_info: rewards.info.clone(),
+            ask_asset_info: bonding_asset_info.clone(),
+        },
+    )?;
+
+    // the swap can't return less than the simulated amount minus the max spread
+    let simulation: router::SimulateSwapOperationsResponse = deps.querier.query_wasm_smart(
+        router_addr,
+        &router::QueryMsg::SimulateSwapOperations {
+            offer_amount: rewards.amount,
+            operations: operations.clone(),
+        },
+    )?;
+    let minimum_receive =
+        simulation.amount * Decimal::one().checked_sub(compound_config.max_spread)?;
+
+    let swap_msg = match &rewards.info {
+        AssetInfo::NativeToken { denom } => WasmMsg::Execute {
+            contract_addr: router_addr.to_string(),
+            msg: to_binary(&router::ExecuteMsg::ExecuteSwapOperations {
+                operations,
+                minimum_receive: Some(minimum_receive),
+                to: None,
+            })?,
+            funds: coins(rewards.amount.u128(), denom),
+        },
+        AssetInfo::Token { contract_addr } => WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Send {
+                contract: router_addr.to_string(),
+                amount: rewards.amount,
+                msg: to_binary(&router::Cw20HookMsg::ExecuteSwapOperations {
+                    operations,
+                    minimum_receive: Some(minimum_receive),
+                    to: None,
+                })?,
+            })?,
+            funds: vec![],
+        },
+    };
+
+    let prev_balance =
+        bonding_asset_info.query_pool(&deps.querier, deps.api, env.contract.address.clone())?;
+
+    Ok(Response::default()
+        .add_message(swap_msg)
+        .add_message(get_callback_msg(
+            &env,
+            CallbackMsg::CompoundRewards {
+                address: address.clone(),
+                keeper,
+                asset_info: bonding_asset_info,
+                prev_balance,
+            },
+        )?)
+        .add_attributes(vec![
+            ("action", "swap_rewards".to_string()),
+            ("address", address.to_string()),
+            ("rewards", rewards.to_string()),
+        ]))
+}
+
+/// Creates the message to call the contract itself with the given callback.
+fn get_callback_msg(env: &Env, callback: CallbackMsg) -> StdResult<WasmMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::Callback(callback))?,
+        funds: vec![],
+    })
+}
+
+/// Refreshes the exchange rates of the bonding assets that have an exchange rate oracle. The new
+/// rates are recorded at the given timestamp, so the weight accrued before it isn't affected.
+pub(crate) fn update_exchange_rates(
+    deps: DepsMut,
+    timestamp: Timestamp,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    let mut exchange_rates = vec![];
+    for bonding_asset in config.bonding_assets.iter_mut() {
+        if let Some(oracle) = &bonding_asset.exchange_rate_oracle {
+            let exchange_rate = query_exchange_rate(deps.as_ref(), oracle)?;
+            EXCHANGE_RATES.save(
+                deps.storage,
+                (&bonding_asset.info.to_string(), timestamp.seconds()),
+                &exchange_rate,
+            )?;
+            bonding_asset.exchange_rate = exchange_rate;
+            exchange_rates.push(format!("{}:{}", bonding_asset.info, exchange_rate));
+        }
+    }
+
+    if exchange_rates.is_empty() {
+        return Err(ContractError::NoExchangeRateOracle {});
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "update_exchange_rates".to_string()),
+        ("exchange_rates", exchange_rates.join(", ")),
+    ]))
+}
+
+/// Adds new bonding assets, or updates the weight factors of the existing ones. For the assets that
+/// can already be bonded, only the weight factor is updated. The new weight factors are recorded at
+/// the given timestamp, so the weight accrued before it isn't affected.
+pub(crate) fn update_bonding_assets(
+    deps: DepsMut,
+    timestamp: Timestamp,
+    info: MessageInfo,
+    bonding_assets: Vec<BondingAsset>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if config.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for bonding_asset in bonding_assets {
+        let bonding_asset = validate_bonding_asset(deps.as_ref(), bonding_asset)?;
+        let denom = bonding_asset.info.to_string();
+
+        match config
+            .bonding_assets
+            .iter_mut()
+            .find(|existing| existing.info == bonding_asset.info)
+        {
+            Some(existing) => {
+                // the weight factor in effect until now is recorded the first time it's updated
+                let has_history = WEIGHT_FACTORS
+                    .prefix(&denom)
+                    .keys(deps.storage, None, None, Order::Ascending)
+                    .next()
+                    .is_some();
+                if !has_history {
+                    WEIGHT_FACTORS.save(deps.storage, (&denom, 0u64), &existing.weight_factor)?;
+                }
+                WEIGHT_FACTORS.save(
+                    deps.storage,
+                    (&denom, timestamp.seconds()),
+                    &bonding_asset.weight_factor,
+                )?;
+
+                // the new exchange rate, from the oracle if any, applies from now on as well
+                EXCHANGE_RATES.save(
+                    deps.storage,
+                    (&denom, timestamp.seconds()),
+                    &bonding_asset.exchange_rate,
+                )?;
+
+                existing.weight_factor = bonding_asset.weight_factor;
+                existing.exchange_rate = bonding_asset.exchange_rate;
+                existing.exchange_rate_oracle = bonding_asset.exchange_rate_oracle;
+            }
+            None => {
+                if config.bonding_assets.len() >= BONDING_ASSETS_LIMIT {
+                    return Err(ContractError::InvalidBondingAssetsLimit(
+                        BONDING_ASSETS_LIMIT,
+                        config.bonding_assets.len() + 1,
+                    ));
+                }
+
+                EXCHANGE_RATES.save(
+                    deps.storage,
+                    (&denom, timestamp.seconds()),
+                    &bonding_asset.exchange_rate,
+                )?;
+                config.bonding_assets.push(bonding_asset);
+            }
+        }
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    let bonding_assets = config
+        .bonding_assets
+        .iter()
+        .map(|a| format!("{}:{}:{}", a.info, a.weight_factor, a.exchange_rate))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "update_bonding_assets".to_string()),
+        ("bonding_assets", bonding_assets),
+    ]))
+}
+
 /// Updates the configuration of the contract
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn update_config(
     deps: DepsMut,
     info: MessageInfo,
     owner: Option<String>,
     unbonding_period: Option<Uint64>,
     growth_rate: Option<Decimal>,
+    instant_unbond_penalty: Option<Decimal>,
+    penalty_recipient: Option<PenaltyRecipient>,
+    compound_config: Option<CompoundConfig>,
 ) -> Result<Response, ContractError> {
     // check the owner is the one who sent the message
     let mut config = CONFIG.load(deps.storage)?;
@@ -216,6 +1104,19 @@ pub(crate) fn update_config(
         config.growth_rate = growth_rate;
     }
 
+    if let Some(instant_unbond_penalty) = instant_unbond_penalty {
+        validate_instant_unbond_penalty(instant_unbond_penalty)?;
+        config.instant_unbond_penalty = instant_unbond_penalty;
+    }
+
+    if let Some(penalty_recipient) = penalty_recipient {
+        config.penalty_recipient = validate_penalty_recipient(deps.as_ref(), penalty_recipient)?;
+    }
+
+    if let Some(compound_config) = compound_config {
+        config.compound_config = Some(validate_compound_config(deps.as_ref(), compound_config)?);
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::default().add_attributes(vec![
@@ -223,5 +1124,25 @@ pub(crate) fn update_config(
         ("owner", config.owner.to_string()),
         ("unbonding_period", config.unbonding_period.to_string()),
         ("growth_rate", config.growth_rate.to_string()),
+        (
+            "instant_unbond_penalty",
+            config.instant_unbond_penalty.to_string(),
+        ),
+        ("penalty_recipient", config.penalty_recipient.to_string()),
+        (
+            "compound_config",
+            config
+                .compound_config
+                .map_or("none".to_string(), |compound_config| {
+                    format!(
+                        "fee_distributor:{}, router:{}, keeper_tip:{}, max_spread:{}, keepers:{}",
+                        compound_config.fee_distributor_addr,
+                        compound_config.router_addr,
+                        compound_config.keeper_tip,
+                        compound_config.max_spread,
+                        compound_config.keepers.join(",")
+                    )
+                }),
+        ),
     ]))
 }
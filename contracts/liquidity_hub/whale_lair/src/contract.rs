@@ -2,14 +2,16 @@ use cosmwasm_std::entry_point;
 use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
-use white_whale::pool_network::asset::AssetInfo;
 
 use white_whale::whale_lair::{Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 use crate::error::ContractError;
-use crate::helpers::validate_growth_rate;
-use crate::state::{BONDING_ASSETS_LIMIT, CONFIG};
-use crate::{commands, queries};
+use crate::helpers::{
+    validate_bonding_asset, validate_growth_rate, validate_instant_unbond_penalty,
+    validate_penalty_recipient,
+};
+use crate::state::{BLOCK_TIMES, BONDING_ASSETS_LIMIT, CONFIG, EXCHANGE_RATES};
+use crate::{commands, migrations, queries};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "white_whale-whale_lair";
@@ -18,7 +20,7 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 #[entry_point]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
@@ -30,12 +32,19 @@ pub fn instantiate(
     }
 
     validate_growth_rate(msg.growth_rate)?;
-
-    for asset in &msg.bonding_assets {
-        match asset {
-            AssetInfo::Token { .. } => return Err(ContractError::InvalidBondingAsset {}),
-            AssetInfo::NativeToken { .. } => {}
-        };
+    validate_instant_unbond_penalty(msg.instant_unbond_penalty)?;
+    let penalty_recipient = validate_penalty_recipient(deps.as_ref(), msg.penalty_recipient)?;
+
+    let mut bonding_assets = Vec::with_capacity(msg.bonding_assets.len());
+    for bonding_asset in msg.bonding_assets {
+        let bonding_asset = validate_bonding_asset(deps.as_ref(), bonding_asset)?;
+        EXCHANGE_RATES.save(
+            deps.storage,
+            (&bonding_asset.info.to_string(), env.block.time.seconds()),
+            &bonding_asset.exchange_rate,
+        )?;
+
+        bonding_assets.push(bonding_asset);
     }
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -44,7 +53,10 @@ pub fn instantiate(
         owner: deps.api.addr_validate(info.sender.as_str())?,
         unbonding_period: msg.unbonding_period,
         growth_rate: msg.growth_rate,
-        bonding_assets: msg.bonding_assets,
+        bonding_assets,
+        instant_unbond_penalty: msg.instant_unbond_penalty,
+        penalty_recipient,
+        compound_config: None,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -52,7 +64,7 @@ pub fn instantiate(
     let bonding_assets = config
         .bonding_assets
         .iter()
-        .map(|a| a.to_string())
+        .map(|a| format!("{}:{}", a.info, a.weight_factor))
         .collect::<Vec<_>>()
         .join(", ");
 
@@ -62,6 +74,11 @@ pub fn instantiate(
         ("unbonding_period", config.unbonding_period.to_string()),
         ("growth_rate", config.growth_rate.to_string()),
         ("bonding_assets", bonding_assets),
+        (
+            "instant_unbond_penalty",
+            config.instant_unbond_penalty.to_string(),
+        ),
+        ("penalty_recipient", config.penalty_recipient.to_string()),
     ]))
 }
 
@@ -72,17 +89,56 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    // the weight snapshots are recorded by timestamp, keep track of the time of each height
+    BLOCK_TIMES.save(deps.storage, env.block.height, &env.block.time)?;
+
     match msg {
-        ExecuteMsg::Bond { asset } => commands::bond(deps, env.block.time, info, asset),
+        ExecuteMsg::Receive(msg) => commands::receive_cw20(deps, env.block.time, info, msg),
+        ExecuteMsg::Bond { asset } => commands::bond(deps, env.block.time, info, asset, None),
+        ExecuteMsg::BondLocked { asset, duration } => {
+            commands::bond(deps, env.block.time, info, asset, Some(duration))
+        }
         ExecuteMsg::Unbond { asset } => commands::unbond(deps, env.block.time, info, asset),
-        ExecuteMsg::Withdraw { denom } => {
-            commands::withdraw(deps, env.block.time, info.sender, denom)
+        ExecuteMsg::InstantUnbond { asset } => {
+            commands::instant_unbond(deps, env.block.time, info, asset)
+        }
+        ExecuteMsg::Rebond {
+            denom,
+            unbonding_timestamps,
+        } => commands::rebond(deps, env.block.time, info, denom, unbonding_timestamps),
+        ExecuteMsg::Unlock { lock_id } => commands::unlock(deps, env.block.time, info, lock_id),
+        ExecuteMsg::Delegate {
+            delegate,
+            delegation_type,
+        } => commands::delegate(deps, env.block.time, info, delegate, delegation_type),
+        ExecuteMsg::Undelegate {} => commands::undelegate(deps, env.block.time, info),
+        ExecuteMsg::Withdraw { denom, limit } => {
+            commands::withdraw(deps, env.block.time, info.sender, denom, limit)
+        }
+        ExecuteMsg::SetAutoCompound { enabled } => commands::set_auto_compound(deps, info, enabled),
+        ExecuteMsg::Compound { addresses } => commands::compound(deps, env, info, addresses),
+        ExecuteMsg::UpdateExchangeRates {} => commands::update_exchange_rates(deps, env.block.time),
+        ExecuteMsg::UpdateBondingAssets { bonding_assets } => {
+            commands::update_bonding_assets(deps, env.block.time, info, bonding_assets)
         }
         ExecuteMsg::UpdateConfig {
             owner,
             unbonding_period,
             growth_rate,
-        } => commands::update_config(deps, info, owner, unbonding_period, growth_rate),
+            instant_unbond_penalty,
+            penalty_recipient,
+            compound_config,
+        } => commands::update_config(
+            deps,
+            info,
+            owner,
+            unbonding_period,
+            growth_rate,
+            instant_unbond_penalty,
+            penalty_recipient,
+            compound_config,
+        ),
+        ExecuteMsg::Callback(msg) => commands::callback(deps, env, info, msg),
     }
 }
 
@@ -109,16 +165,56 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             address,
             denom,
         )?),
+        QueryMsg::Locks {
+            address,
+            start_after,
+            limit,
+        } => to_binary(&queries::query_locks(deps, address, start_after, limit)?),
+        QueryMsg::Delegation { delegator } => {
+            to_binary(&queries::query_delegation(deps, delegator)?)
+        }
+        QueryMsg::Delegations {
+            delegate,
+            start_after,
+            limit,
+        } => to_binary(&queries::query_delegations(
+            deps,
+            delegate,
+            start_after,
+            limit,
+        )?),
         QueryMsg::Weight { address } => {
             to_binary(&queries::query_weight(deps, env.block.time, address)?)
         }
+        QueryMsg::WeightAt { address, timestamp } => to_binary(&queries::query_weight_at(
+            deps,
+            env.block.time,
+            address,
+            timestamp,
+        )?),
+        QueryMsg::TotalWeightAt { timestamp } => to_binary(&queries::query_total_weight_at(
+            deps,
+            env.block.time,
+            timestamp,
+        )?),
         QueryMsg::TotalBonded {} => to_binary(&queries::query_total_bonded(deps)?),
+        QueryMsg::TotalBondedByDenom {} => to_binary(&queries::query_total_bonded_by_denom(deps)?),
+        QueryMsg::AutoCompound { address } => {
+            to_binary(&queries::query_auto_compound(deps, address)?)
+        }
+        QueryMsg::VotingPowerAtHeight { address, height } => to_binary(
+            &queries::query_voting_power_at_height(deps, env.block.height, address, height)?,
+        ),
+        QueryMsg::TotalPowerAtHeight { height } => to_binary(
+            &queries::query_total_power_at_height(deps, env.block.height, height)?,
+        ),
+        QueryMsg::Info {} => to_binary(&queries::query_info(deps)?),
     }
 }
 
 #[cfg(not(tarpaulin_include))]
 #[entry_point]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let version: Version = CONTRACT_VERSION.parse()?;
     let storage_version: Version = get_contract_version(deps.storage)?.version.parse()?;
 
@@ -129,6 +225,11 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
         });
     }
 
+    if storage_version < Version::parse("1.1.0")? {
+        migrations::migrate_to_v110(deps.branch(), env.block.time)?;
+        BLOCK_TIMES.save(deps.storage, env.block.height, &env.block.time)?;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::{DivideByZeroError, OverflowError, StdError};
+use cosmwasm_std::{DivideByZeroError, OverflowError, StdError, Timestamp};
 use semver::Version;
 use thiserror::Error;
 
@@ -31,20 +31,68 @@ pub enum ContractError {
     #[error("The growth rate must be between 0 and 1. i.e. 0.5 for 50%")]
     InvalidGrowthRate {},
 
+    #[error("The instant unbond penalty must be between 0 and 1. i.e. 0.1 for 10%")]
+    InvalidInstantUnbondPenalty {},
+
+    #[error("The keeper tip must be between 0 and 1. i.e. 0.01 for 1%")]
+    InvalidKeeperTip {},
+
+    #[error("The max spread must be between 0 and 1. i.e. 0.01 for 1%")]
+    InvalidMaxSpread {},
+
+    #[error("Auto-compounding is not configured.")]
+    CompoundNotConfigured {},
+
     #[error(
         "The amount of bonding assets is greater than the limit allowed. Limit is {0}, sent {1}."
     )]
     InvalidBondingAssetsLimit(usize, usize),
 
-    #[error("Can only bond native assets.")]
+    #[error("Cw20 tokens can only be bonded through the cw20 Receive hook.")]
     InvalidBondingAsset {},
 
+    #[error("The weight factor of a bonding asset must be greater than zero.")]
+    InvalidWeightFactor {},
+
+    #[error("The exchange rate of a bonding asset must be greater than zero.")]
+    InvalidExchangeRate {},
+
+    #[error("None of the bonding assets has an exchange rate oracle.")]
+    NoExchangeRateOracle {},
+
+    #[error("The lock duration must be between {0} and {1} nanoseconds.")]
+    InvalidLockDuration(u64, u64),
+
+    #[error("Lock not found.")]
+    LockNotFound {},
+
+    #[error("The lock can't be unlocked before {0}.")]
+    LockNotExpired(Timestamp),
+
+    #[error("Can't delegate to oneself.")]
+    InvalidDelegate {},
+
+    #[error("Nothing to undelegate.")]
+    NothingToUndelegate {},
+
+    #[error("Only addresses with bonded tokens can delegate.")]
+    NothingToDelegate {},
+
+    #[error("The delegate can't receive more than {0} delegations.")]
+    TooManyDelegations(usize),
+
     #[error("Nothing to unbond.")]
     NothingToUnbond {},
 
     #[error("Nothing to withdraw.")]
     NothingToWithdraw {},
 
+    #[error("Nothing to rebond.")]
+    NothingToRebond {},
+
+    #[error("No unbonding found at timestamp {0}.")]
+    UnbondingNotFound(u64),
+
     #[error("Attempt to migrate to version {new_version}, but contract is on a higher version {current_version}")]
     MigrateInvalidVersion {
         new_version: Version,
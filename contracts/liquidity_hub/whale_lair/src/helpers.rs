@@ -1,4 +1,8 @@
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{Decimal, Deps};
+use white_whale::whale_lair::{
+    BondingAsset, CompoundConfig, ExchangeRateOracleQueryMsg, ExchangeRateOracleResponse,
+    PenaltyRecipient,
+};
 
 use crate::error::ContractError;
 
@@ -9,3 +13,94 @@ pub fn validate_growth_rate(growth_rate: Decimal) -> Result<(), ContractError> {
     }
     Ok(())
 }
+
+/// Validates that the instant unbonding penalty is between 0 and 1.
+pub fn validate_instant_unbond_penalty(penalty: Decimal) -> Result<(), ContractError> {
+    if penalty > Decimal::percent(100) {
+        return Err(ContractError::InvalidInstantUnbondPenalty {});
+    }
+    Ok(())
+}
+
+/// Validates the address of the penalty recipient, if any.
+pub fn validate_penalty_recipient(
+    deps: Deps,
+    penalty_recipient: PenaltyRecipient,
+) -> Result<PenaltyRecipient, ContractError> {
+    if let PenaltyRecipient::FeeCollector { address } = &penalty_recipient {
+        deps.api.addr_validate(address)?;
+    }
+    Ok(penalty_recipient)
+}
+
+/// Validates that the exchange rate of a bonding asset is greater than zero.
+pub fn validate_exchange_rate(exchange_rate: Decimal) -> Result<(), ContractError> {
+    if exchange_rate.is_zero() {
+        return Err(ContractError::InvalidExchangeRate {});
+    }
+    Ok(())
+}
+
+/// Queries the exchange rate of a liquid staking derivative from its hub contract.
+pub fn query_exchange_rate(deps: Deps, oracle: &str) -> Result<Decimal, ContractError> {
+    let response: ExchangeRateOracleResponse = deps
+        .querier
+        .query_wasm_smart(oracle, &ExchangeRateOracleQueryMsg::State {})?;
+    validate_exchange_rate(response.exchange_rate)?;
+
+    Ok(response.exchange_rate)
+}
+
+/// Validates a bonding asset. The exchange rate is taken from the oracle if there's one.
+pub fn validate_bonding_asset(
+    deps: Deps,
+    bonding_asset: BondingAsset,
+) -> Result<BondingAsset, ContractError> {
+    if bonding_asset.weight_factor.is_zero() {
+        return Err(ContractError::InvalidWeightFactor {});
+    }
+
+    let exchange_rate_oracle = bonding_asset
+        .exchange_rate_oracle
+        .map(|oracle| {
+            deps.api
+                .addr_validate(&oracle)
+                .map(|oracle| oracle.to_string())
+        })
+        .transpose()?;
+    let exchange_rate = match &exchange_rate_oracle {
+        Some(oracle) => query_exchange_rate(deps, oracle)?,
+        None => {
+            validate_exchange_rate(bonding_asset.exchange_rate)?;
+            bonding_asset.exchange_rate
+        }
+    };
+
+    Ok(BondingAsset {
+        info: bonding_asset.info.validate(&deps)?,
+        weight_factor: bonding_asset.weight_factor,
+        exchange_rate,
+        exchange_rate_oracle,
+    })
+}
+
+/// Validates the addresses of the compound config and that the keeper tip and the max spread are
+/// between 0 and 1.
+pub fn validate_compound_config(
+    deps: Deps,
+    compound_config: CompoundConfig,
+) -> Result<CompoundConfig, ContractError> {
+    deps.api
+        .addr_validate(&compound_config.fee_distributor_addr)?;
+    deps.api.addr_validate(&compound_config.router_addr)?;
+    for keeper in compound_config.keepers.iter() {
+        deps.api.addr_validate(keeper)?;
+    }
+    if compound_config.keeper_tip > Decimal::percent(100) {
+        return Err(ContractError::InvalidKeeperTip {});
+    }
+    if compound_config.max_spread > Decimal::percent(100) {
+        return Err(ContractError::InvalidMaxSpread {});
+    }
+    Ok(compound_config)
+}
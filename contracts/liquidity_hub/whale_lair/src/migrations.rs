@@ -0,0 +1,52 @@
+#![cfg(not(tarpaulin_include))]
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, DepsMut, StdError, Timestamp, Uint64};
+use cw_storage_plus::Item;
+use white_whale::pool_network::asset::AssetInfo;
+use white_whale::whale_lair::{BondingAsset, Config, PenaltyRecipient};
+
+use crate::state::{CONFIG, WEIGHT_HISTORY_START};
+
+/// Migrates state from v1.0.0 to v1.1.0, which adds a weight factor to the bonding assets in the
+/// Config. Existing bonding assets get a weight factor and an exchange rate of 1 without any
+/// oracle, which the owner can update through UpdateBondingAssets. Instant unbonding is disabled
+/// with a penalty of 100%. The weight history starts at the time of the migration, the weight of
+/// the existing bonders is computed from their bonds until they change.
+pub fn migrate_to_v110(deps: DepsMut, timestamp: Timestamp) -> Result<(), StdError> {
+    #[cw_serde]
+    struct ConfigV100 {
+        pub owner: Addr,
+        pub unbonding_period: Uint64,
+        pub growth_rate: Decimal,
+        pub bonding_assets: Vec<AssetInfo>,
+    }
+
+    const CONFIGV100: Item<ConfigV100> = Item::new("config");
+    let config_v100 = CONFIGV100.load(deps.storage)?;
+
+    let config = Config {
+        owner: config_v100.owner,
+        unbonding_period: config_v100.unbonding_period,
+        growth_rate: config_v100.growth_rate,
+        bonding_assets: config_v100
+            .bonding_assets
+            .into_iter()
+            .map(|info| BondingAsset {
+                info,
+                weight_factor: Decimal::one(),
+                exchange_rate: Decimal::one(),
+                exchange_rate_oracle: None,
+            })
+            .collect(),
+        instant_unbond_penalty: Decimal::one(),
+        penalty_recipient: PenaltyRecipient::Burn,
+        compound_config: None,
+    };
+
+    CONFIG.save(deps.storage, &config)?;
+
+    // the weight of the existing bonders is computed from their bonds until they change
+    WEIGHT_HISTORY_START.save(deps.storage, &timestamp)?;
+
+    Ok(())
+}
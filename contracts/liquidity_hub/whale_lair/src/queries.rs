@@ -1,12 +1,19 @@
-use cosmwasm_std::{Decimal, Deps, Order, StdError, StdResult, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Decimal, Deps, Order, StdError, StdResult, Timestamp, Uint128};
 use cw_storage_plus::Bound;
+use white_whale::pool_network::asset::Asset;
 
 use white_whale::whale_lair::{
-    Bond, BondedResponse, BondingWeightResponse, Config, GlobalIndex, UnbondingResponse,
-    WithdrawableResponse,
+    AutoCompoundResponse, Bond, BondedDenom, BondedResponse, BondingWeightResponse, Config,
+    Delegation, DelegationResponse, DelegationsResponse, GlobalIndex, InfoResponse, LockedBond,
+    LocksResponse, TotalBondedByDenomResponse, TotalPowerAtHeightResponse, TotalWeightResponse,
+    UnbondingResponse, VotingPowerAtHeightResponse, WithdrawableResponse,
 };
 
-use crate::state::{get_weight, BOND, BONDING_ASSETS_LIMIT, CONFIG, GLOBAL, UNBOND};
+use crate::state::{
+    delegations, get_global_weight, get_matured_unbondings, get_timestamp_before_height,
+    get_total_weight_at, get_weight_index, get_weight_with_delegations_at, AUTO_COMPOUND, BOND,
+    BONDING_ASSETS_LIMIT, CONFIG, GLOBAL, LOCK, UNBOND, WEIGHT_DELEGATE, WEIGHT_DELEGATORS,
+};
 
 /// Queries the current configuration of the contract.
 pub(crate) fn query_config(deps: Deps) -> StdResult<Config> {
@@ -94,17 +101,13 @@ pub(crate) fn query_withdrawable(
     denom: String,
 ) -> StdResult<WithdrawableResponse> {
     let config = CONFIG.load(deps.storage)?;
-    let unbonding: StdResult<Vec<_>> = UNBOND
-        .prefix((&deps.api.addr_validate(address.as_str())?, &denom))
-        .range(deps.storage, None, None, Order::Ascending)
-        .take(MAX_PAGE_LIMIT as usize)
-        .collect();
+    let address = deps.api.addr_validate(address.as_str())?;
 
     let mut withdrawable_amount = Uint128::zero();
-    for (_, bond) in unbonding? {
-        if timestamp.minus_nanos(config.unbonding_period.u64()) >= bond.timestamp {
-            withdrawable_amount = withdrawable_amount.checked_add(bond.asset.amount)?;
-        }
+    for (_, bond) in
+        get_matured_unbondings(deps.storage, &config, &address, &denom, timestamp, None)?
+    {
+        withdrawable_amount = withdrawable_amount.checked_add(bond.asset.amount)?;
     }
 
     Ok(WithdrawableResponse {
@@ -112,62 +115,253 @@ pub(crate) fn query_withdrawable(
     })
 }
 
-/// Queries the current weight of the given address.
+/// Queries the current weight of the given address, including the weight delegated to it.
 pub(crate) fn query_weight(
     deps: Deps,
     timestamp: Timestamp,
     address: String,
 ) -> StdResult<BondingWeightResponse> {
     let address = deps.api.addr_validate(&address)?;
-
-    let bonds: StdResult<Vec<_>> = BOND
-        .prefix(&address)
-        .range(deps.storage, None, None, Order::Ascending)
-        .take(MAX_PAGE_LIMIT as usize)
-        .collect();
-
     let config = CONFIG.load(deps.storage)?;
 
-    let mut total_bond_weight = Uint128::zero();
-
-    for (_, mut bond) in bonds? {
-        bond.weight = get_weight(
-            timestamp,
-            bond.weight,
-            bond.asset.amount,
-            config.growth_rate,
-            bond.timestamp,
-        )?;
+    // the weight of an address that delegated it counts towards its delegate instead
+    let own_weight = if WEIGHT_DELEGATE.may_load(deps.storage, &address)?.is_some() {
+        Uint128::zero()
+    } else {
+        get_bonding_weight(deps, timestamp, &config, &address)?
+    };
 
-        // Aggregate the weights of all the bonds for the given address.
-        // This assumes bonding assets are fungible.
-        total_bond_weight = total_bond_weight.checked_add(bond.weight)?;
+    let mut delegated_weight = Uint128::zero();
+    for delegator in WEIGHT_DELEGATORS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default()
+    {
+        delegated_weight = delegated_weight
+            .checked_add(get_bonding_weight(deps, timestamp, &config, &delegator)?)?;
     }
 
+    let total_bond_weight = own_weight.checked_add(delegated_weight)?;
+
     let mut global_index = GLOBAL
         .may_load(deps.storage)
         .unwrap_or_else(|_| Some(GlobalIndex::default()))
         .ok_or_else(|| StdError::generic_err("Global index not found"))?;
 
-    global_index.weight = get_weight(
-        timestamp,
-        global_index.weight,
-        global_index.bonded_amount,
-        config.growth_rate,
-        global_index.timestamp,
-    )?;
+    (global_index.weight, _) = get_global_weight(deps.storage, &config, &global_index, timestamp)?;
 
     let share = Decimal::from_ratio(total_bond_weight, global_index.weight);
 
     Ok(BondingWeightResponse {
         address: address.to_string(),
         weight: total_bond_weight,
+        delegated_weight,
         global_weight: global_index.weight,
         share,
         timestamp,
     })
 }
 
+/// Calculates the weight of the bonds and locks of the given address at the given timestamp.
+fn get_bonding_weight(
+    deps: Deps,
+    timestamp: Timestamp,
+    config: &Config,
+    address: &Addr,
+) -> StdResult<Uint128> {
+    Ok(get_weight_index(deps.storage, config, address, timestamp)?.weight)
+}
+
+/// Queries the weight of the given address at the given past timestamp, from the recorded
+/// snapshots.
+pub(crate) fn query_weight_at(
+    deps: Deps,
+    current_timestamp: Timestamp,
+    address: String,
+    timestamp: Timestamp,
+) -> StdResult<BondingWeightResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+    validate_past_timestamp(current_timestamp, timestamp)?;
+
+    let (weight, delegated_weight) =
+        get_weight_with_delegations_at(deps.storage, &config, &address, timestamp)?;
+    let global_weight = get_total_weight_at(deps.storage, &config, timestamp)?;
+    let share = if global_weight.is_zero() {
+        Decimal::zero()
+    } else {
+        Decimal::from_ratio(weight, global_weight)
+    };
+
+    Ok(BondingWeightResponse {
+        address: address.to_string(),
+        weight,
+        delegated_weight,
+        global_weight,
+        share,
+        timestamp,
+    })
+}
+
+/// Queries the global weight at the given past timestamp, from the recorded snapshots.
+pub(crate) fn query_total_weight_at(
+    deps: Deps,
+    current_timestamp: Timestamp,
+    timestamp: Timestamp,
+) -> StdResult<TotalWeightResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    validate_past_timestamp(current_timestamp, timestamp)?;
+
+    Ok(TotalWeightResponse {
+        weight: get_total_weight_at(deps.storage, &config, timestamp)?,
+        timestamp,
+    })
+}
+
+/// Validates the given timestamp is not in the future, as the weight can't be known in advance.
+fn validate_past_timestamp(current_timestamp: Timestamp, timestamp: Timestamp) -> StdResult<()> {
+    if timestamp > current_timestamp {
+        return Err(StdError::generic_err(format!(
+            "Can't query the weight at {timestamp}, which is in the future"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Queries the voting power of the given address at the given height, i.e. its bonding weight as
+/// of the start of that block. The weight is taken at the last time the contract was executed
+/// before that height, so that the voting power of all addresses and the total power are measured
+/// at the same point in time. The weight delegated to the address at the time is included, and the
+/// weight it had delegated away is not.
+pub(crate) fn query_voting_power_at_height(
+    deps: Deps,
+    current_height: u64,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<VotingPowerAtHeightResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let height = height.unwrap_or(current_height);
+    validate_past_height(current_height, height)?;
+
+    let power = match get_timestamp_before_height(deps.storage, height)? {
+        Some(timestamp) => {
+            let config = CONFIG.load(deps.storage)?;
+            get_weight_with_delegations_at(deps.storage, &config, &address, timestamp)?.0
+        }
+        None => Uint128::zero(),
+    };
+
+    Ok(VotingPowerAtHeightResponse { power, height })
+}
+
+/// Queries the total voting power at the given height, i.e. the global weight as of the start of
+/// that block.
+pub(crate) fn query_total_power_at_height(
+    deps: Deps,
+    current_height: u64,
+    height: Option<u64>,
+) -> StdResult<TotalPowerAtHeightResponse> {
+    let height = height.unwrap_or(current_height);
+    validate_past_height(current_height, height)?;
+
+    let power = match get_timestamp_before_height(deps.storage, height)? {
+        Some(timestamp) => {
+            let config = CONFIG.load(deps.storage)?;
+            get_total_weight_at(deps.storage, &config, timestamp)?
+        }
+        None => Uint128::zero(),
+    };
+
+    Ok(TotalPowerAtHeightResponse { power, height })
+}
+
+/// Validates the given height is not in the future, as the voting power can't be known in advance.
+fn validate_past_height(current_height: u64, height: u64) -> StdResult<()> {
+    if height > current_height {
+        return Err(StdError::generic_err(format!(
+            "Can't query the voting power at height {height}, which is in the future"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Queries the contract name and version.
+pub(crate) fn query_info(deps: Deps) -> StdResult<InfoResponse> {
+    Ok(InfoResponse {
+        info: cw2::get_contract_version(deps.storage)?,
+    })
+}
+
+/// Queries the delegation of the given delegator.
+pub(crate) fn query_delegation(deps: Deps, delegator: String) -> StdResult<DelegationResponse> {
+    let delegator = deps.api.addr_validate(&delegator)?;
+
+    Ok(DelegationResponse {
+        delegation: delegations().may_load(deps.storage, &delegator)?,
+    })
+}
+
+/// Queries the delegations made to the given delegate.
+pub(crate) fn query_delegations(
+    deps: Deps,
+    delegate: String,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<DelegationsResponse> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    let start_after = start_after
+        .map(|delegator| deps.api.addr_validate(&delegator))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    let delegations = delegations()
+        .idx
+        .delegate
+        .prefix(delegate.to_string())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, delegation) = item?;
+            Ok(delegation)
+        })
+        .collect::<StdResult<Vec<Delegation>>>()?;
+
+    Ok(DelegationsResponse { delegations })
+}
+
+/// Queries the locked bonds of the given address.
+pub(crate) fn query_locks(
+    deps: Deps,
+    address: String,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<LocksResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let locks = LOCK
+        .prefix(&address)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, lock) = item?;
+            Ok(lock)
+        })
+        .collect::<StdResult<Vec<LockedBond>>>()?;
+
+    let total_locked = locks.iter().try_fold(Uint128::zero(), |acc, lock| {
+        acc.checked_add(lock.asset.amount)
+    })?;
+
+    Ok(LocksResponse {
+        total_locked,
+        locks,
+    })
+}
+
 /// Queries the total amount of assets that have been bonded to the contract.
 pub fn query_total_bonded(deps: Deps) -> StdResult<BondedResponse> {
     GLOBAL.load(deps.storage).map(|global| BondedResponse {
@@ -175,3 +369,45 @@ pub fn query_total_bonded(deps: Deps) -> StdResult<BondedResponse> {
         bonded_assets: global.bonded_assets,
     })
 }
+
+/// Queries the total amount bonded of each bonding asset, along with its value in the underlying
+/// token at the current exchange rate.
+pub(crate) fn query_total_bonded_by_denom(deps: Deps) -> StdResult<TotalBondedByDenomResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
+
+    let mut total_underlying_amount = Uint128::zero();
+    let mut bonded = Vec::with_capacity(config.bonding_assets.len());
+    for bonding_asset in config.bonding_assets {
+        let amount = global_index
+            .bonded_assets
+            .iter()
+            .find(|asset| asset.info == bonding_asset.info)
+            .map_or(Uint128::zero(), |asset| asset.amount);
+        let underlying_amount = amount * bonding_asset.exchange_rate;
+        total_underlying_amount = total_underlying_amount.checked_add(underlying_amount)?;
+
+        bonded.push(BondedDenom {
+            asset: Asset {
+                info: bonding_asset.info,
+                amount,
+            },
+            exchange_rate: bonding_asset.exchange_rate,
+            underlying_amount,
+        });
+    }
+
+    Ok(TotalBondedByDenomResponse {
+        bonded,
+        total_underlying_amount,
+    })
+}
+
+/// Queries whether the given address opted in to auto-compounding.
+pub(crate) fn query_auto_compound(deps: Deps, address: String) -> StdResult<AutoCompoundResponse> {
+    let address = deps.api.addr_validate(&address)?;
+
+    Ok(AutoCompoundResponse {
+        enabled: AUTO_COMPOUND.has(deps.storage, &address),
+    })
+}
@@ -1,18 +1,135 @@
-use cosmwasm_std::{Addr, Decimal, DepsMut, StdError, StdResult, Timestamp, Uint128};
-use cw_storage_plus::{Item, Map};
-use white_whale::pool_network::asset::AssetInfo;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    Addr, Decimal, DepsMut, Order, StdError, StdResult, Storage, Timestamp, Uint128, Uint64,
+};
+use cw_storage_plus::{
+    Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex, SnapshotItem, SnapshotMap, Strategy,
+};
+use white_whale::pool_network::asset;
+use white_whale::pool_network::asset::Asset;
 
-use white_whale::whale_lair::{Bond, Config, GlobalIndex};
+use white_whale::whale_lair::{Bond, Config, Delegation, GlobalIndex, LockedBond};
 
 use crate::ContractError;
 
 type Denom = str;
 
-pub const BONDING_ASSETS_LIMIT: usize = 2;
+pub const BONDING_ASSETS_LIMIT: usize = 5;
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const BOND: Map<(&Addr, &Denom), Bond> = Map::new("bond");
+/// The pending unbondings, keyed by the unbonding timestamp in seconds. As all of them share the
+/// same unbonding period, the entries of an address and denom are sorted by maturity.
 pub const UNBOND: Map<(&Addr, &Denom, u64), Bond> = Map::new("unbond");
-pub const GLOBAL: Item<GlobalIndex> = Item::new("global");
+/// The global index, snapshotted on every change at the timestamp in seconds it was made.
+pub const GLOBAL: SnapshotItem<GlobalIndex> = SnapshotItem::new(
+    "global",
+    "global__checkpoints",
+    "global__changelog",
+    Strategy::EveryBlock,
+);
+pub const LOCK: Map<(&Addr, u64), LockedBond> = Map::new("lock");
+pub const LOCK_COUNTER: Item<u64> = Item::new("lock_counter");
+/// The weighted amount added to the global index by the active locks, on top of the locked amounts
+/// themselves. Snapshotted along with [GLOBAL].
+pub const LOCKED_BOOST: SnapshotItem<Uint128> = SnapshotItem::new(
+    "locked_boost",
+    "locked_boost__checkpoints",
+    "locked_boost__changelog",
+    Strategy::EveryBlock,
+);
+/// The lock boosts expiring at a given timestamp, in seconds. The expired entries are kept to be
+/// able to compute the global weight at any point in time.
+pub const LOCK_EXPIRIES: Map<u64, Uint128> = Map::new("lock_expiries");
+/// The lock boosts of an address expiring at a given timestamp, in seconds.
+pub const ADDRESS_LOCK_EXPIRIES: Map<(&Addr, u64), Uint128> = Map::new("address_lock_expiries");
+/// The exchange rates of the bonding assets, keyed by denom and the timestamp in seconds they took
+/// effect at. The past rates are kept to be able to compute the weight at any point in time.
+pub const EXCHANGE_RATES: Map<(&Denom, u64), Decimal> = Map::new("exchange_rates");
+/// The weight factors of the bonding assets, keyed by denom and the timestamp in seconds they took
+/// effect at. Only recorded once the weight factor of an asset is updated, the one in the [Config]
+/// applies to the assets without any.
+pub const WEIGHT_FACTORS: Map<(&Denom, u64), Decimal> = Map::new("weight_factors");
+/// The weight index of each address, snapshotted on every change at the timestamp in seconds it
+/// was made.
+pub const WEIGHT: SnapshotMap<&Addr, WeightIndex> = SnapshotMap::new(
+    "weight",
+    "weight__checkpoints",
+    "weight__changelog",
+    Strategy::EveryBlock,
+);
+
+/// The weight of an address at a given timestamp, along with what's needed to compute its weight
+/// at any later point until it changes again.
+#[cw_serde]
+#[derive(Default)]
+pub struct WeightIndex {
+    /// The weight at the given timestamp.
+    pub weight: Uint128,
+    /// The bonded and locked assets, which are scaled by their weight factors and exchange rates.
+    pub bonded_assets: Vec<Asset>,
+    /// The weighted amount the active locks add on top of the locked amounts.
+    pub locked_boost: Uint128,
+    pub timestamp: Timestamp,
+}
+
+/// The time the weight history started at. The bonders from before it have no snapshot until
+/// their bonds change, their weight is computed from their bonds until then.
+pub const WEIGHT_HISTORY_START: Item<Timestamp> = Item::new("weight_history_start");
+
+/// The addresses that opted in to the auto-compounding of their rewards.
+pub const AUTO_COMPOUND: Map<&Addr, bool> = Map::new("auto_compound");
+
+/// The block time of every block height the contract was executed at, to map the heights used by
+/// the voting power queries to the timestamps the weight snapshots are recorded at.
+pub const BLOCK_TIMES: Map<u64, Timestamp> = Map::new("block_times");
+
+pub struct DelegationIndexes<'a> {
+    pub delegate: MultiIndex<'a, String, Delegation, &'a Addr>,
+}
+
+impl<'a> IndexList<Delegation> for DelegationIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Delegation>> + '_> {
+        let v: Vec<&dyn Index<Delegation>> = vec![&self.delegate];
+        Box::new(v.into_iter())
+    }
+}
+
+/// The delegations made by bonders, keyed by delegator and indexed by delegate.
+pub fn delegations<'a>() -> IndexedMap<'a, &'a Addr, Delegation, DelegationIndexes<'a>> {
+    let indexes = DelegationIndexes {
+        delegate: MultiIndex::new(
+            |_, delegation| delegation.delegate.to_string(),
+            "delegation",
+            "delegation__delegate",
+        ),
+    };
+
+    IndexedMap::new("delegation", indexes)
+}
+
+/// The maximum amount of delegations an address can receive.
+pub const MAX_DELEGATIONS_PER_DELEGATE: usize = 30;
+/// The delegate of each address that delegated its weight, snapshotted on every change at the
+/// timestamp in seconds it was made.
+pub const WEIGHT_DELEGATE: SnapshotMap<&Addr, Addr> = SnapshotMap::new(
+    "weight_delegate",
+    "weight_delegate__checkpoints",
+    "weight_delegate__changelog",
+    Strategy::EveryBlock,
+);
+/// The addresses that delegated their weight to each delegate, snapshotted on every change at the
+/// timestamp in seconds it was made.
+pub const WEIGHT_DELEGATORS: SnapshotMap<&Addr, Vec<Addr>> = SnapshotMap::new(
+    "weight_delegators",
+    "weight_delegators__checkpoints",
+    "weight_delegators__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Minimum lock duration, 1 week in nanoseconds.
+pub const MIN_LOCK_DURATION: u64 = 604_800_000_000_000;
+/// Maximum lock duration, 52 weeks in nanoseconds.
+pub const MAX_LOCK_DURATION: u64 = 52 * MIN_LOCK_DURATION;
 
 /// Updates the local weight of the given address.
 pub fn update_local_weight(
@@ -22,28 +139,26 @@ pub fn update_local_weight(
     mut bond: Bond,
 ) -> Result<Bond, ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    snapshot_legacy_weight(deps.storage, &config, &address)?;
 
-    bond.weight = get_weight(
-        timestamp,
+    bond.weight = get_asset_weight(
+        deps.storage,
+        &config,
+        &bond.asset,
         bond.weight,
-        bond.asset.amount,
-        config.growth_rate,
         bond.timestamp,
+        timestamp,
     )?;
 
     bond.timestamp = timestamp;
 
-    let denom: &String = match &bond.asset.info {
-        AssetInfo::Token { .. } => return Err(ContractError::AssetMismatch {}),
-        AssetInfo::NativeToken { denom } => denom,
-    };
-
-    BOND.save(deps.storage, (&address, denom), &bond)?;
+    let denom = bond.asset.clone().get_id();
+    BOND.save(deps.storage, (&address, &denom), &bond)?;
 
     Ok(bond)
 }
 
-/// Updates the global weight of the contract.
+/// Updates the global weight of the contract, expiring the lock boosts whose unlock time has passed.
 pub fn update_global_weight(
     deps: &mut DepsMut,
     timestamp: Timestamp,
@@ -51,19 +166,337 @@ pub fn update_global_weight(
 ) -> Result<GlobalIndex, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
-    global_index.weight = get_weight(
+    let (weight, locked_boost) =
+        get_global_weight(deps.storage, &config, &global_index, timestamp)?;
+    LOCKED_BOOST.save(deps.storage, &locked_boost, timestamp.seconds())?;
+
+    global_index.weight = weight;
+    global_index.timestamp = timestamp;
+
+    GLOBAL.save(deps.storage, &global_index, timestamp.seconds())?;
+
+    Ok(global_index)
+}
+
+/// Calculates the global weight at the given timestamp, from the given global index and the lock
+/// boost active when it was last updated. Returns the global weight and the lock boost still
+/// active at the given timestamp.
+pub fn get_global_weight(
+    storage: &dyn Storage,
+    config: &Config,
+    global_index: &GlobalIndex,
+    timestamp: Timestamp,
+) -> StdResult<(Uint128, Uint128)> {
+    // the boost active when the global index was last updated
+    let locked_boost = LOCKED_BOOST
+        .may_load_at_height(storage, global_index.timestamp.seconds().saturating_add(1))?
+        .unwrap_or_default();
+
+    let expiries = LOCK_EXPIRIES
+        .range(
+            storage,
+            Some(Bound::exclusive(global_index.timestamp.seconds())),
+            Some(Bound::inclusive(timestamp.seconds())),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<(u64, Uint128)>>>()?;
+
+    extrapolate_weight(
+        storage,
+        config,
+        &WeightIndex {
+            weight: global_index.weight,
+            bonded_assets: global_index.bonded_assets.clone(),
+            locked_boost,
+            timestamp: global_index.timestamp,
+        },
+        &expiries,
+        timestamp,
+    )
+}
+
+/// A change in the weighted amount of a weight index, either a lock boost expiring or a new
+/// weight multiplier for one of its assets.
+enum WeightIndexChange {
+    LockExpiry(Uint128),
+    WeightMultiplier(usize, Decimal),
+}
+
+/// Calculates the weight of the given index at a later timestamp. The boost of the locks expiring
+/// in between only counts until their unlock time, and the bonded assets are weighted by the
+/// weight factors and exchange rates in effect over the period. Returns the weight and the lock boost still active at
+/// the given timestamp.
+pub fn extrapolate_weight(
+    storage: &dyn Storage,
+    config: &Config,
+    index: &WeightIndex,
+    expiries: &[(u64, Uint128)],
+    timestamp: Timestamp,
+) -> StdResult<(Uint128, Uint128)> {
+    let mut weight = index.weight;
+    let mut locked_boost = index.locked_boost;
+    let mut last_timestamp = index.timestamp;
+
+    let mut multipliers = Vec::with_capacity(index.bonded_assets.len());
+    let mut changes = expiries
+        .iter()
+        .map(|(expiry, expired_boost)| (*expiry, WeightIndexChange::LockExpiry(*expired_boost)))
+        .collect::<Vec<_>>();
+    for (i, asset) in index.bonded_assets.iter().enumerate() {
+        let denom = asset.clone().get_id();
+        multipliers.push(get_weight_multiplier_at(
+            storage,
+            config,
+            &denom,
+            index.timestamp,
+        )?);
+        for (changed_at, multiplier) in
+            get_weight_multiplier_changes(storage, config, &denom, index.timestamp, timestamp)?
+        {
+            changes.push((
+                changed_at,
+                WeightIndexChange::WeightMultiplier(i, multiplier),
+            ));
+        }
+    }
+    changes.sort_by_key(|(changed_at, _)| *changed_at);
+
+    for (changed_at, change) in changes {
+        let changed_at = Timestamp::from_seconds(changed_at).max(last_timestamp);
+        let weighted_amount = get_total_weighted_amount(&index.bonded_assets, &multipliers)?;
+        weight = get_weight(
+            changed_at,
+            weight,
+            weighted_amount.checked_add(locked_boost)?,
+            config.growth_rate,
+            last_timestamp,
+        )?;
+        match change {
+            WeightIndexChange::LockExpiry(expired_boost) => {
+                locked_boost = locked_boost.checked_sub(expired_boost)?;
+            }
+            WeightIndexChange::WeightMultiplier(i, multiplier) => {
+                multipliers[i] = multiplier;
+            }
+        }
+        last_timestamp = changed_at;
+    }
+
+    let weighted_amount = get_total_weighted_amount(&index.bonded_assets, &multipliers)?;
+    weight = get_weight(
         timestamp,
-        global_index.weight,
-        global_index.bonded_amount,
+        weight,
+        weighted_amount.checked_add(locked_boost)?,
         config.growth_rate,
-        global_index.timestamp,
+        last_timestamp,
     )?;
 
-    global_index.timestamp = timestamp;
+    Ok((weight, locked_boost))
+}
+
+/// Calculates the weight index of the given address at the given timestamp, from its bonds and
+/// locks.
+pub fn get_weight_index(
+    storage: &dyn Storage,
+    config: &Config,
+    address: &Addr,
+    timestamp: Timestamp,
+) -> StdResult<WeightIndex> {
+    let mut index = WeightIndex {
+        timestamp,
+        ..WeightIndex::default()
+    };
 
-    GLOBAL.save(deps.storage, &global_index)?;
+    let bonds = BOND
+        .prefix(address)
+        .range(storage, None, None, Order::Ascending)
+        .take(BONDING_ASSETS_LIMIT)
+        .collect::<StdResult<Vec<(String, Bond)>>>()?;
 
-    Ok(global_index)
+    for (_, bond) in bonds {
+        let weight = get_asset_weight(
+            storage,
+            config,
+            &bond.asset,
+            bond.weight,
+            bond.timestamp,
+            timestamp,
+        )?;
+
+        index.weight = index.weight.checked_add(weight)?;
+        index.bonded_assets = asset::aggregate_assets(index.bonded_assets, vec![bond.asset])?;
+    }
+
+    // locked bonds are boosted by their lock multiplier until their unlock time
+    let locks = LOCK
+        .prefix(address)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, LockedBond)>>>()?;
+
+    for (_, lock) in locks {
+        let weight = get_lock_weight(storage, config, &lock, timestamp)?;
+
+        index.weight = index.weight.checked_add(weight)?;
+        if timestamp.seconds() < lock.unlock_time.seconds() {
+            let boost = get_lock_boost(storage, config, &lock)?;
+            index.locked_boost = index.locked_boost.checked_add(boost)?;
+        }
+        index.bonded_assets = asset::aggregate_assets(index.bonded_assets, vec![lock.asset])?;
+    }
+
+    Ok(index)
+}
+
+/// Records the weight index of the given address at the given timestamp, to be able to query its
+/// weight at any point in time.
+pub fn snapshot_weight(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    timestamp: Timestamp,
+) -> StdResult<()> {
+    let config = CONFIG.load(storage)?;
+    let index = get_weight_index(storage, &config, address, timestamp)?;
+
+    WEIGHT.save(storage, address, &index, timestamp.seconds())
+}
+
+/// Gets the weight index of the given address at the start of the weight history, if it bonded
+/// before the history started and hasn't changed its bonds since, i.e. if it has no snapshot.
+fn get_legacy_weight_index(
+    storage: &dyn Storage,
+    config: &Config,
+    address: &Addr,
+) -> StdResult<Option<WeightIndex>> {
+    if WEIGHT.may_load(storage, address)?.is_some() {
+        return Ok(None);
+    }
+
+    let history_start = match WEIGHT_HISTORY_START.may_load(storage)? {
+        Some(history_start) => history_start,
+        None => return Ok(None),
+    };
+
+    let index = get_weight_index(storage, config, address, history_start)?;
+    if index.bonded_assets.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(index))
+}
+
+/// Snapshots the weight of the given address at the start of the weight history if it bonded
+/// before the history started, so that its past weight is kept once its bonds change.
+pub fn snapshot_legacy_weight(
+    storage: &mut dyn Storage,
+    config: &Config,
+    address: &Addr,
+) -> StdResult<()> {
+    match get_legacy_weight_index(storage, config, address)? {
+        Some(index) => WEIGHT.save(storage, address, &index, index.timestamp.seconds()),
+        None => Ok(()),
+    }
+}
+
+/// Calculates the weight of the given address at the given timestamp from its snapshots, or from
+/// its bonds if it bonded before the weight history started and has no snapshot. The weight is
+/// zero if the address had nothing bonded at that time.
+pub fn get_weight_at(
+    storage: &dyn Storage,
+    config: &Config,
+    address: &Addr,
+    timestamp: Timestamp,
+) -> StdResult<Uint128> {
+    let index =
+        match WEIGHT.may_load_at_height(storage, address, timestamp.seconds().saturating_add(1))? {
+            Some(index) => index,
+            None => match get_legacy_weight_index(storage, config, address)? {
+                Some(index) if index.timestamp <= timestamp => index,
+                _ => return Ok(Uint128::zero()),
+            },
+        };
+
+    let expiries = ADDRESS_LOCK_EXPIRIES
+        .prefix(address)
+        .range(
+            storage,
+            Some(Bound::exclusive(index.timestamp.seconds())),
+            Some(Bound::inclusive(timestamp.seconds())),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<(u64, Uint128)>>>()?;
+
+    Ok(extrapolate_weight(storage, config, &index, &expiries, timestamp)?.0)
+}
+
+/// Calculates the weight of the given address at the given timestamp from the snapshots, counting
+/// the weight delegated to it at the time instead of its own if it had delegated it. Returns the
+/// weight along with the part of it that was delegated to the address.
+pub fn get_weight_with_delegations_at(
+    storage: &dyn Storage,
+    config: &Config,
+    address: &Addr,
+    timestamp: Timestamp,
+) -> StdResult<(Uint128, Uint128)> {
+    let height = timestamp.seconds().saturating_add(1);
+
+    let own_weight = if WEIGHT_DELEGATE
+        .may_load_at_height(storage, address, height)?
+        .is_some()
+    {
+        Uint128::zero()
+    } else {
+        get_weight_at(storage, config, address, timestamp)?
+    };
+
+    let mut delegated_weight = Uint128::zero();
+    for delegator in WEIGHT_DELEGATORS
+        .may_load_at_height(storage, address, height)?
+        .unwrap_or_default()
+    {
+        delegated_weight =
+            delegated_weight.checked_add(get_weight_at(storage, config, &delegator, timestamp)?)?;
+    }
+
+    Ok((own_weight.checked_add(delegated_weight)?, delegated_weight))
+}
+
+/// Calculates the global weight at the given timestamp from the snapshots of the global index.
+pub fn get_total_weight_at(
+    storage: &dyn Storage,
+    config: &Config,
+    timestamp: Timestamp,
+) -> StdResult<Uint128> {
+    let global_index = GLOBAL
+        .may_load_at_height(storage, timestamp.seconds().saturating_add(1))?
+        .unwrap_or_default();
+
+    // the global index was last updated before any history was recorded
+    if global_index.timestamp > timestamp {
+        return Err(StdError::generic_err(format!(
+            "No weight recorded at {timestamp}"
+        )));
+    }
+
+    Ok(get_global_weight(storage, config, &global_index, timestamp)?.0)
+}
+
+/// Gets the block time of the last execution of the contract before the given height, i.e. the
+/// timestamp the weight snapshots were last updated at by the start of that block. Returns None if
+/// the contract wasn't executed before.
+pub fn get_timestamp_before_height(
+    storage: &dyn Storage,
+    height: u64,
+) -> StdResult<Option<Timestamp>> {
+    BLOCK_TIMES
+        .range(
+            storage,
+            None,
+            Some(Bound::exclusive(height)),
+            Order::Descending,
+        )
+        .next()
+        .transpose()
+        .map(|item| item.map(|(_, timestamp)| timestamp))
 }
 
 /// Calculates the bonding weight of the given amount for the provided timestamps.
@@ -86,3 +519,270 @@ pub fn get_weight(
 
     Ok(weight.checked_add(amount.checked_mul(time_factor)? * growth_rate)?)
 }
+
+/// Calculates the bonding weight of the given asset for the provided timestamps, scaling its amount
+/// by the weight factors and exchange rates in effect over the period.
+pub fn get_asset_weight(
+    storage: &dyn Storage,
+    config: &Config,
+    asset: &Asset,
+    weight: Uint128,
+    timestamp: Timestamp,
+    current_timestamp: Timestamp,
+) -> StdResult<Uint128> {
+    let denom = asset.clone().get_id();
+    let mut weight = weight;
+    let mut last_timestamp = timestamp;
+    let mut multiplier = get_weight_multiplier_at(storage, config, &denom, timestamp)?;
+
+    for (changed_at, new_multiplier) in
+        get_weight_multiplier_changes(storage, config, &denom, timestamp, current_timestamp)?
+    {
+        let changed_at = Timestamp::from_seconds(changed_at).max(last_timestamp);
+        weight = get_weight(
+            changed_at,
+            weight,
+            get_weighted_amount(asset, multiplier),
+            config.growth_rate,
+            last_timestamp,
+        )?;
+        multiplier = new_multiplier;
+        last_timestamp = changed_at;
+    }
+
+    get_weight(
+        current_timestamp,
+        weight,
+        get_weighted_amount(asset, multiplier),
+        config.growth_rate,
+        last_timestamp,
+    )
+}
+
+/// Calculates the bonding weight of the given lock at the given timestamp. On top of the weight of
+/// the locked asset, the lock boost grows the weight until the unlock time.
+pub fn get_lock_weight(
+    storage: &dyn Storage,
+    config: &Config,
+    lock: &LockedBond,
+    current_timestamp: Timestamp,
+) -> StdResult<Uint128> {
+    let weight = get_asset_weight(
+        storage,
+        config,
+        &lock.asset,
+        lock.weight,
+        lock.timestamp,
+        current_timestamp,
+    )?;
+
+    if lock.timestamp >= lock.unlock_time {
+        return Ok(weight);
+    }
+
+    get_weight(
+        current_timestamp.min(lock.unlock_time),
+        weight,
+        get_lock_boost(storage, config, lock)?,
+        config.growth_rate,
+        lock.timestamp,
+    )
+}
+
+/// Gets the weighted amount the given lock adds on top of the locked asset while it's active. It's
+/// fixed at the weight factor and exchange rate in effect when the lock was made.
+pub fn get_lock_boost(
+    storage: &dyn Storage,
+    config: &Config,
+    lock: &LockedBond,
+) -> StdResult<Uint128> {
+    let multiplier = get_weight_multiplier_at(
+        storage,
+        config,
+        &lock.asset.clone().get_id(),
+        lock.timestamp,
+    )?;
+    let weighted_amount = get_weighted_amount(&lock.asset, multiplier);
+
+    Ok((weighted_amount * lock.multiplier).checked_sub(weighted_amount)?)
+}
+
+/// Gets the weight multiplier of a lock of the given duration, going linearly from 1x for no lock
+/// to 2x for a lock of [MAX_LOCK_DURATION].
+pub fn get_lock_multiplier(duration: Uint64) -> Decimal {
+    Decimal::one() + Decimal::from_ratio(duration, MAX_LOCK_DURATION)
+}
+
+/// Gets the weight factor of the given denom in the config. Defaults to 1 if the asset is not in
+/// the config.
+fn get_weight_factor(config: &Config, denom: &Denom) -> Decimal {
+    config
+        .bonding_assets
+        .iter()
+        .find(|bonding_asset| bonding_asset.info.to_string() == denom)
+        .map_or(Decimal::one(), |bonding_asset| bonding_asset.weight_factor)
+}
+
+/// Gets the weight factor of the given denom in effect at the given timestamp. Defaults to the one
+/// in the config if none was recorded by then.
+pub fn get_weight_factor_at(
+    storage: &dyn Storage,
+    config: &Config,
+    denom: &Denom,
+    timestamp: Timestamp,
+) -> StdResult<Decimal> {
+    Ok(WEIGHT_FACTORS
+        .prefix(denom)
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(timestamp.seconds())),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?
+        .map_or_else(
+            || get_weight_factor(config, denom),
+            |(_, weight_factor)| weight_factor,
+        ))
+}
+
+/// Gets the multiplier applied to the amount of the given denom to compute its bonding weight at
+/// the given timestamp, i.e. its weight factor times its exchange rate.
+fn get_weight_multiplier_at(
+    storage: &dyn Storage,
+    config: &Config,
+    denom: &Denom,
+    timestamp: Timestamp,
+) -> StdResult<Decimal> {
+    Ok(get_weight_factor_at(storage, config, denom, timestamp)?
+        * get_exchange_rate_at(storage, denom, timestamp)?)
+}
+
+/// Gets the weight multipliers of the given denom that took effect after `timestamp` and up to
+/// `current_timestamp`, in ascending order. The multiplier changes whenever the weight factor or the
+/// exchange rate of the denom does.
+fn get_weight_multiplier_changes(
+    storage: &dyn Storage,
+    config: &Config,
+    denom: &Denom,
+    timestamp: Timestamp,
+    current_timestamp: Timestamp,
+) -> StdResult<Vec<(u64, Decimal)>> {
+    let mut changed_at = get_exchange_rate_changes(storage, denom, timestamp, current_timestamp)?
+        .into_iter()
+        .map(|(changed_at, _)| changed_at)
+        .collect::<Vec<_>>();
+    for item in WEIGHT_FACTORS.prefix(denom).keys(
+        storage,
+        Some(Bound::exclusive(timestamp.seconds())),
+        Some(Bound::inclusive(current_timestamp.seconds())),
+        Order::Ascending,
+    ) {
+        changed_at.push(item?);
+    }
+    changed_at.sort_unstable();
+    changed_at.dedup();
+
+    changed_at
+        .into_iter()
+        .map(|changed_at| {
+            let multiplier = get_weight_multiplier_at(
+                storage,
+                config,
+                denom,
+                Timestamp::from_seconds(changed_at),
+            )?;
+            Ok((changed_at, multiplier))
+        })
+        .collect()
+}
+
+/// Gets the amount of the given asset scaled by the given weight multiplier, used to compute the
+/// bonding weight.
+pub fn get_weighted_amount(asset: &Asset, multiplier: Decimal) -> Uint128 {
+    asset.amount * multiplier
+}
+
+/// Gets the sum of the amounts of the given assets scaled by the given weight multipliers, in the
+/// same order as the assets.
+pub fn get_total_weighted_amount(assets: &[Asset], multipliers: &[Decimal]) -> StdResult<Uint128> {
+    Ok(assets
+        .iter()
+        .zip(multipliers)
+        .try_fold(Uint128::zero(), |acc, (asset, multiplier)| {
+            acc.checked_add(get_weighted_amount(asset, *multiplier))
+        })?)
+}
+
+/// Gets the exchange rate of the given denom in effect at the given timestamp. Defaults to 1 if
+/// none was recorded by then.
+pub fn get_exchange_rate_at(
+    storage: &dyn Storage,
+    denom: &Denom,
+    timestamp: Timestamp,
+) -> StdResult<Decimal> {
+    Ok(EXCHANGE_RATES
+        .prefix(denom)
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(timestamp.seconds())),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?
+        .map_or(Decimal::one(), |(_, exchange_rate)| exchange_rate))
+}
+
+/// Gets the exchange rates of the given denom that took effect after `timestamp` and up to
+/// `current_timestamp`, in ascending order.
+fn get_exchange_rate_changes(
+    storage: &dyn Storage,
+    denom: &Denom,
+    timestamp: Timestamp,
+    current_timestamp: Timestamp,
+) -> StdResult<Vec<(u64, Decimal)>> {
+    EXCHANGE_RATES
+        .prefix(denom)
+        .range(
+            storage,
+            Some(Bound::exclusive(timestamp.seconds())),
+            Some(Bound::inclusive(current_timestamp.seconds())),
+            Order::Ascending,
+        )
+        .collect()
+}
+
+/// Gets the unbondings of the given address and denom that have passed the unbonding period at the
+/// given timestamp, oldest first, up to the given limit if any.
+pub fn get_matured_unbondings(
+    storage: &dyn Storage,
+    config: &Config,
+    address: &Addr,
+    denom: &str,
+    timestamp: Timestamp,
+    limit: Option<usize>,
+) -> StdResult<Vec<(u64, Bond)>> {
+    let matured_before = timestamp.minus_nanos(config.unbonding_period.u64());
+
+    // the keys are in seconds, so the entries unbonded during the last second need to be checked
+    // against the exact timestamp
+    let matured = UNBOND
+        .prefix((address, denom))
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(matured_before.seconds())),
+            Order::Ascending,
+        )
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |(_, bond)| bond.timestamp <= matured_before)
+        });
+
+    match limit {
+        Some(limit) => matured.take(limit).collect(),
+        None => matured.collect(),
+    }
+}
@@ -1,9 +1,10 @@
-use cosmwasm_std::{coin, coins, Decimal, Timestamp, Uint128};
+use cosmwasm_std::{coin, coins, Decimal, Timestamp, Uint128, Uint64};
 
 use white_whale::pool_network::asset::{Asset, AssetInfo};
-use white_whale::whale_lair::{BondedResponse, BondingWeightResponse};
+use white_whale::whale_lair::{BondedResponse, BondingAsset, BondingWeightResponse};
 
-use crate::tests::robot::TestingRobot;
+use crate::tests::robot::{instantiate_cw20_token, TestingRobot};
+use crate::ContractError;
 
 #[test]
 fn test_bond_successfully() {
@@ -45,6 +46,7 @@ fn test_bond_successfully() {
                 global_weight: Uint128::new(10_000u128),
                 share: Decimal::one(),
                 timestamp: Timestamp::from_nanos(1571797429879305533u64),
+                delegated_weight: Uint128::zero(),
             },
         )
         .fast_forward(10u64)
@@ -88,6 +90,7 @@ fn test_bond_successfully() {
                 global_weight: Uint128::new(60_000u128),
                 share: Decimal::one(),
                 timestamp: Timestamp::from_nanos(1571797449879305533u64),
+                delegated_weight: Uint128::zero(),
             },
         )
         .bond(
@@ -110,6 +113,7 @@ fn test_bond_successfully() {
                 global_weight: Uint128::new(150_000u128),
                 share: Decimal::from_ratio(100_000u128, 150_000u128),
                 timestamp: Timestamp::from_nanos(1571797459879305533u64),
+                delegated_weight: Uint128::zero(),
             },
         )
         .assert_bonding_weight_response(
@@ -120,6 +124,7 @@ fn test_bond_successfully() {
                 global_weight: Uint128::new(150_000u128),
                 share: Decimal::from_ratio(50_000u128, 150_000u128),
                 timestamp: Timestamp::from_nanos(1571797459879305533u64),
+                delegated_weight: Uint128::zero(),
             },
         )
         .query_total_bonded(|res| {
@@ -242,3 +247,160 @@ fn test_bond_wrong_asset() {
             },
         );
 }
+
+#[test]
+fn test_bond_cw20_with_weight_factor() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+    let lp_token = instantiate_cw20_token(&mut robot, "WHALEUSDCLP");
+    let other_token = instantiate_cw20_token(&mut robot, "OTHER");
+
+    robot
+        .instantiate(
+            Uint64::new(1_000_000_000_000u64),
+            Decimal::one(),
+            vec![
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
+                },
+                BondingAsset {
+                    info: AssetInfo::Token {
+                        contract_addr: lp_token.to_string(),
+                    },
+                    weight_factor: Decimal::from_ratio(2u128, 1u128),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
+                },
+            ],
+            &vec![],
+        )
+        .bond_cw20(
+            sender.clone(),
+            lp_token.clone(),
+            Uint128::new(1_000u128),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(1_000u128),
+                bonded_assets: vec![Asset {
+                    info: AssetInfo::Token {
+                        contract_addr: lp_token.to_string(),
+                    },
+                    amount: Uint128::new(1_000u128),
+                }],
+            },
+        )
+        .fast_forward(10u64)
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(20_000u128),
+                global_weight: Uint128::new(20_000u128),
+                share: Decimal::one(),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+                delegated_weight: Uint128::zero(),
+            },
+        )
+        .bond(
+            another_sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(10u64)
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(40_000u128),
+                global_weight: Uint128::new(50_000u128),
+                share: Decimal::from_ratio(40_000u128, 50_000u128),
+                timestamp: Timestamp::from_nanos(1571797439879305533u64),
+                delegated_weight: Uint128::zero(),
+            },
+        )
+        .assert_bonding_weight_response(
+            another_sender.to_string(),
+            BondingWeightResponse {
+                address: another_sender.to_string(),
+                weight: Uint128::new(10_000u128),
+                global_weight: Uint128::new(50_000u128),
+                share: Decimal::from_ratio(10_000u128, 50_000u128),
+                timestamp: Timestamp::from_nanos(1571797439879305533u64),
+                delegated_weight: Uint128::zero(),
+            },
+        )
+        .bond_cw20(
+            sender.clone(),
+            other_token,
+            Uint128::new(1_000u128),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::AssetMismatch {}.to_string()
+                );
+            },
+        )
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::Token {
+                    contract_addr: lp_token.to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &[],
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidBondingAsset {}.to_string()
+                );
+            },
+        )
+        .unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::Token {
+                    contract_addr: lp_token.to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(1_000u64)
+        .withdraw(sender.clone(), lp_token.to_string(), None, |res| {
+            res.unwrap();
+        })
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::zero(),
+                bonded_assets: vec![Asset {
+                    info: AssetInfo::Token {
+                        contract_addr: lp_token.to_string(),
+                    },
+                    amount: Uint128::zero(),
+                }],
+            },
+        );
+}
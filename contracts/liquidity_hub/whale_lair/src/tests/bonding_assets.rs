@@ -0,0 +1,243 @@
+use cosmwasm_std::{coins, Decimal, Timestamp, Uint128};
+
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::whale_lair::BondingAsset;
+
+use crate::state::BONDING_ASSETS_LIMIT;
+use crate::tests::robot::{instantiate_mock_exchange_rate_oracle, TestingRobot};
+use crate::ContractError;
+
+fn bonding_asset(denom: &str, weight_factor: Decimal) -> BondingAsset {
+    BondingAsset {
+        info: AssetInfo::NativeToken {
+            denom: denom.to_string(),
+        },
+        weight_factor,
+        exchange_rate: Decimal::one(),
+        exchange_rate_oracle: None,
+    }
+}
+
+#[test]
+fn test_update_bonding_assets_successfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        // uwhale can't be bonded yet
+        .bond(
+            another_sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "uwhale"),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::AssetMismatch {}.to_string()
+                );
+            },
+        )
+        .fast_forward(10u64)
+        .update_bonding_assets(
+            sender.clone(),
+            vec![
+                bonding_asset("ampWHALE", Decimal::percent(200)),
+                bonding_asset("uwhale", Decimal::one()),
+            ],
+            |res| {
+                res.unwrap();
+            },
+        )
+        .query_config(|res| {
+            let config = res.unwrap().1;
+            assert_eq!(
+                config.bonding_assets,
+                vec![
+                    bonding_asset("ampWHALE", Decimal::percent(200)),
+                    bonding_asset("bWHALE", Decimal::one()),
+                    bonding_asset("uwhale", Decimal::one()),
+                ]
+            );
+        })
+        .bond(
+            another_sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "uwhale"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(10u64)
+        // the new weight factor only applies to the weight accrued since it was updated
+        .query_weight(sender.to_string(), |res| {
+            let bonding_weight_response = res.unwrap().1;
+            assert_eq!(bonding_weight_response.weight, Uint128::new(30_000u128));
+            assert_eq!(
+                bonding_weight_response.global_weight,
+                Uint128::new(40_000u128)
+            );
+        })
+        .query_weight(another_sender.to_string(), |res| {
+            assert_eq!(res.unwrap().1.weight, Uint128::new(10_000u128));
+        })
+        .query_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1571797429879305533u64),
+            |res| {
+                assert_eq!(res.unwrap().1.weight, Uint128::new(10_000u128));
+            },
+        )
+        .assert_total_weight_at(
+            Timestamp::from_nanos(1571797429879305533u64),
+            Uint128::new(10_000u128),
+        );
+}
+
+#[test]
+fn test_update_exchange_rates_of_bonding_assets() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot.instantiate_default();
+    let oracle = instantiate_mock_exchange_rate_oracle(&mut robot, Decimal::percent(300));
+
+    robot
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "bWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "bWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        // no bonding asset has an oracle yet
+        .update_exchange_rates(sender.clone(), |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::NoExchangeRateOracle {}.to_string()
+            );
+        })
+        .fast_forward(10u64)
+        .update_bonding_assets(
+            sender.clone(),
+            vec![BondingAsset {
+                exchange_rate: Decimal::percent(200),
+                ..bonding_asset("bWHALE", Decimal::one())
+            }],
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(10u64)
+        // the new exchange rate only applies to the weight accrued since it was updated
+        .query_weight(sender.to_string(), |res| {
+            assert_eq!(res.unwrap().1.weight, Uint128::new(30_000u128));
+        })
+        // the exchange rate is taken from the oracle once set
+        .update_bonding_assets(
+            sender.clone(),
+            vec![BondingAsset {
+                exchange_rate_oracle: Some(oracle.to_string()),
+                ..bonding_asset("bWHALE", Decimal::one())
+            }],
+            |res| {
+                res.unwrap();
+            },
+        )
+        .query_config(|res| {
+            let config = res.unwrap().1;
+            assert_eq!(
+                config.bonding_assets[1],
+                BondingAsset {
+                    exchange_rate: Decimal::percent(300),
+                    exchange_rate_oracle: Some(oracle.to_string()),
+                    ..bonding_asset("bWHALE", Decimal::one())
+                }
+            );
+        })
+        .fast_forward(10u64)
+        .query_weight(sender.to_string(), |res| {
+            assert_eq!(res.unwrap().1.weight, Uint128::new(60_000u128));
+        })
+        .update_exchange_rates(sender.clone(), |res| {
+            res.unwrap();
+        });
+}
+
+#[test]
+fn test_update_bonding_assets_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .update_bonding_assets(
+            another_sender,
+            vec![bonding_asset("uwhale", Decimal::one())],
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::Unauthorized {}.to_string()
+                );
+            },
+        )
+        .update_bonding_assets(
+            sender.clone(),
+            vec![bonding_asset("ampWHALE", Decimal::zero())],
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidWeightFactor {}.to_string()
+                );
+            },
+        )
+        .update_bonding_assets(
+            sender,
+            vec![
+                bonding_asset("uwhale", Decimal::one()),
+                bonding_asset("uusdc", Decimal::one()),
+                bonding_asset("uatom", Decimal::one()),
+                bonding_asset("uluna", Decimal::one()),
+            ],
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidBondingAssetsLimit(
+                        BONDING_ASSETS_LIMIT,
+                        BONDING_ASSETS_LIMIT + 1
+                    )
+                    .to_string()
+                );
+            },
+        );
+}
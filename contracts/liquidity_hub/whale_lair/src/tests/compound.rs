@@ -0,0 +1,434 @@
+use cosmwasm_std::{coin, coins, Addr, Decimal, Uint128};
+
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::whale_lair::{BondedResponse, CallbackMsg, CompoundConfig};
+
+use crate::tests::robot::{
+    instantiate_mock_fee_distributor, instantiate_mock_router, TestingRobot,
+};
+use crate::ContractError;
+
+#[test]
+fn test_compound_bonding_asset_rewards() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let keeper = robot.another_sender.clone();
+
+    robot.instantiate_default();
+    let fee_distributor =
+        instantiate_mock_fee_distributor(&mut robot, vec![coin(100, "ampWHALE")], 5);
+    let router = instantiate_mock_router(&mut robot, &[], Decimal::one());
+
+    robot
+        .update_config(
+            sender.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(CompoundConfig {
+                fee_distributor_addr: fee_distributor.to_string(),
+                router_addr: router.to_string(),
+                keeper_tip: Decimal::percent(10),
+                max_spread: Decimal::percent(1),
+                keepers: vec![keeper.to_string()],
+            }),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |_res| {},
+        )
+        .set_auto_compound(sender.clone(), true, |res| {
+            res.unwrap();
+        })
+        .query_auto_compound(sender.to_string(), |res| {
+            assert!(res.unwrap().1.enabled);
+        })
+        // the keeper didn't opt in, so its rewards are not compounded
+        .compound(
+            keeper.clone(),
+            vec![sender.to_string(), keeper.to_string()],
+            |res| {
+                res.unwrap();
+            },
+        )
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(1_090u128),
+                bonded_assets: vec![Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    amount: Uint128::new(1_090u128),
+                }],
+            },
+        )
+        .assert_balance(
+            keeper.to_string(),
+            "ampWHALE".to_string(),
+            Uint128::new(1_000_000_010u128),
+        )
+        // once opted out, the rewards are not compounded anymore
+        .set_auto_compound(sender.clone(), false, |res| {
+            res.unwrap();
+        })
+        .query_auto_compound(sender.to_string(), |res| {
+            assert!(!res.unwrap().1.enabled);
+        })
+        .compound(keeper.clone(), vec![sender.to_string()], |res| {
+            res.unwrap();
+        })
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(1_090u128),
+                bonded_assets: vec![Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    amount: Uint128::new(1_090u128),
+                }],
+            },
+        );
+}
+
+#[test]
+fn test_compound_swapped_rewards() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let keeper = robot.another_sender.clone();
+
+    robot.instantiate_default();
+    let fee_distributor =
+        instantiate_mock_fee_distributor(&mut robot, vec![coin(100, "uwhale")], 5);
+    let router = instantiate_mock_router(&mut robot, &coins(1_000, "ampWHALE"), Decimal::one());
+
+    robot
+        .update_config(
+            sender.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(CompoundConfig {
+                fee_distributor_addr: fee_distributor.to_string(),
+                router_addr: router.to_string(),
+                keeper_tip: Decimal::percent(10),
+                max_spread: Decimal::percent(1),
+                keepers: vec![keeper.to_string()],
+            }),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "bWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "bWHALE"),
+            |_res| {},
+        )
+        .set_auto_compound(sender.clone(), true, |res| {
+            res.unwrap();
+        })
+        .compound(keeper.clone(), vec![sender.to_string()], |res| {
+            res.unwrap();
+        })
+        // the uwhale rewards are swapped to ampWHALE, the first bonding asset, before bonding
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(1_090u128),
+                bonded_assets: vec![
+                    Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "ampWHALE".to_string(),
+                        },
+                        amount: Uint128::new(90u128),
+                    },
+                    Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "bWHALE".to_string(),
+                        },
+                        amount: Uint128::new(1_000u128),
+                    },
+                ],
+            },
+        )
+        .assert_balance(
+            keeper.to_string(),
+            "ampWHALE".to_string(),
+            Uint128::new(1_000_000_010u128),
+        );
+}
+
+#[test]
+fn test_compound_swapped_and_bonding_asset_rewards() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let keeper = robot.another_sender.clone();
+
+    robot.instantiate_default();
+    let whale_lair = robot.whale_lair_addr.clone();
+    // the uusdc rewards are distributed before the ampWHALE ones
+    let fee_distributor = instantiate_mock_fee_distributor(
+        &mut robot,
+        vec![coin(100, "uusdc"), coin(100, "ampWHALE")],
+        5,
+    );
+    let router = instantiate_mock_router(&mut robot, &coins(1_000, "ampWHALE"), Decimal::one());
+
+    robot
+        .update_config(
+            sender.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(CompoundConfig {
+                fee_distributor_addr: fee_distributor.to_string(),
+                router_addr: router.to_string(),
+                keeper_tip: Decimal::percent(10),
+                max_spread: Decimal::percent(1),
+                keepers: vec![keeper.to_string()],
+            }),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |_res| {},
+        )
+        .set_auto_compound(sender.clone(), true, |res| {
+            res.unwrap();
+        })
+        .compound(keeper.clone(), vec![sender.to_string()], |res| {
+            res.unwrap();
+        })
+        // the swapped uusdc rewards are bonded once, on top of the ampWHALE rewards
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(1_180u128),
+                bonded_assets: vec![Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    amount: Uint128::new(1_180u128),
+                }],
+            },
+        )
+        // all the bonded tokens are held by the contract
+        .assert_balance(
+            whale_lair.to_string(),
+            "ampWHALE".to_string(),
+            Uint128::new(1_180u128),
+        )
+        .assert_balance(
+            keeper.to_string(),
+            "ampWHALE".to_string(),
+            Uint128::new(1_000_000_020u128),
+        );
+}
+
+#[test]
+fn test_compound_swapped_rewards_with_max_spread() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let keeper = robot.another_sender.clone();
+
+    robot.instantiate_default();
+    let fee_distributor =
+        instantiate_mock_fee_distributor(&mut robot, vec![coin(100, "uwhale")], 5);
+    // the swaps return 2% less than the offered amount, as simulated
+    let router =
+        instantiate_mock_router(&mut robot, &coins(1_000, "ampWHALE"), Decimal::percent(98));
+
+    robot
+        .update_config(
+            sender.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(CompoundConfig {
+                fee_distributor_addr: fee_distributor.to_string(),
+                router_addr: router.to_string(),
+                keeper_tip: Decimal::percent(10),
+                max_spread: Decimal::zero(),
+                keepers: vec![keeper.to_string()],
+            }),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "bWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "bWHALE"),
+            |_res| {},
+        )
+        .set_auto_compound(sender.clone(), true, |res| {
+            res.unwrap();
+        })
+        // the swap returns the simulated amount, so no spread is needed
+        .compound(keeper.clone(), vec![sender.to_string()], |res| {
+            res.unwrap();
+        })
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(1_089u128),
+                bonded_assets: vec![
+                    Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "ampWHALE".to_string(),
+                        },
+                        amount: Uint128::new(89u128),
+                    },
+                    Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "bWHALE".to_string(),
+                        },
+                        amount: Uint128::new(1_000u128),
+                    },
+                ],
+            },
+        )
+        .assert_balance(
+            keeper.to_string(),
+            "ampWHALE".to_string(),
+            Uint128::new(1_000_000_009u128),
+        );
+}
+
+#[test]
+fn test_compound_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let keeper = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .set_auto_compound(sender.clone(), true, |res| {
+            res.unwrap();
+        })
+        .compound(keeper.clone(), vec![sender.to_string()], |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::CompoundNotConfigured {}.to_string()
+            );
+        })
+        .update_config(
+            sender.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(CompoundConfig {
+                fee_distributor_addr: "fee_distributor".to_string(),
+                router_addr: "router".to_string(),
+                keeper_tip: Decimal::percent(101),
+                max_spread: Decimal::percent(1),
+                keepers: vec![keeper.to_string()],
+            }),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidKeeperTip {}.to_string()
+                );
+            },
+        )
+        .update_config(
+            sender.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(CompoundConfig {
+                fee_distributor_addr: "fee_distributor".to_string(),
+                router_addr: "router".to_string(),
+                keeper_tip: Decimal::percent(10),
+                max_spread: Decimal::percent(101),
+                keepers: vec![keeper.to_string()],
+            }),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidMaxSpread {}.to_string()
+                );
+            },
+        )
+        .update_config(
+            sender.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(CompoundConfig {
+                fee_distributor_addr: "fee_distributor".to_string(),
+                router_addr: "router".to_string(),
+                keeper_tip: Decimal::percent(10),
+                max_spread: Decimal::percent(1),
+                keepers: vec![keeper.to_string()],
+            }),
+            |res| {
+                res.unwrap();
+            },
+        )
+        // only the keepers can compound
+        .compound(sender.clone(), vec![sender.to_string()], |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+        })
+        .callback(
+            keeper.clone(),
+            CallbackMsg::ClaimRewards {
+                address: sender,
+                keeper: Addr::unchecked("keeper"),
+            },
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::Unauthorized {}.to_string()
+                );
+            },
+        );
+}
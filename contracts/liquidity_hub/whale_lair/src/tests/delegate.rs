@@ -0,0 +1,373 @@
+use cosmwasm_std::{coins, Addr, Decimal, Timestamp, Uint128};
+
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::whale_lair::{
+    BondingWeightResponse, Delegation, DelegationResponse, DelegationType, DelegationsResponse,
+};
+
+use crate::state::MAX_DELEGATIONS_PER_DELEGATE;
+use crate::tests::robot::TestingRobot;
+use crate::ContractError;
+
+#[test]
+fn test_delegate_weight() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+    let delegate = Addr::unchecked("delegate");
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .bond(
+            another_sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "bWHALE".to_string(),
+                },
+                amount: Uint128::new(3_000u128),
+            },
+            &coins(3_000u128, "bWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .delegate(
+            sender.clone(),
+            delegate.to_string(),
+            DelegationType::Weight,
+            |res| {
+                res.unwrap();
+            },
+        )
+        .delegate(
+            another_sender.clone(),
+            delegate.to_string(),
+            DelegationType::Claim,
+            |res| {
+                res.unwrap();
+            },
+        )
+        .assert_delegation_response(
+            sender.to_string(),
+            DelegationResponse {
+                delegation: Some(Delegation {
+                    delegator: sender.clone(),
+                    delegate: delegate.clone(),
+                    delegation_type: DelegationType::Weight,
+                }),
+            },
+        )
+        .assert_delegations_response(
+            delegate.to_string(),
+            DelegationsResponse {
+                delegations: vec![
+                    Delegation {
+                        delegator: sender.clone(),
+                        delegate: delegate.clone(),
+                        delegation_type: DelegationType::Weight,
+                    },
+                    Delegation {
+                        delegator: another_sender.clone(),
+                        delegate: delegate.clone(),
+                        delegation_type: DelegationType::Claim,
+                    },
+                ],
+            },
+        )
+        .fast_forward(10u64)
+        // only the weight delegation counts towards the delegate
+        .assert_bonding_weight_response(
+            delegate.to_string(),
+            BondingWeightResponse {
+                address: delegate.to_string(),
+                weight: Uint128::new(10_000u128),
+                delegated_weight: Uint128::new(10_000u128),
+                global_weight: Uint128::new(40_000u128),
+                share: Decimal::from_ratio(10_000u128, 40_000u128),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+            },
+        )
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::zero(),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(40_000u128),
+                share: Decimal::zero(),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+            },
+        )
+        .assert_bonding_weight_response(
+            another_sender.to_string(),
+            BondingWeightResponse {
+                address: another_sender.to_string(),
+                weight: Uint128::new(30_000u128),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(40_000u128),
+                share: Decimal::from_ratio(30_000u128, 40_000u128),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+            },
+        )
+        .undelegate(sender.clone(), |res| {
+            res.unwrap();
+        })
+        .assert_delegation_response(sender.to_string(), DelegationResponse { delegation: None })
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(10_000u128),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(40_000u128),
+                share: Decimal::from_ratio(10_000u128, 40_000u128),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+            },
+        )
+        .assert_delegations_response(
+            delegate.to_string(),
+            DelegationsResponse {
+                delegations: vec![Delegation {
+                    delegator: another_sender,
+                    delegate: delegate.clone(),
+                    delegation_type: DelegationType::Claim,
+                }],
+            },
+        );
+}
+
+#[test]
+fn test_delegated_weight_at() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let delegate = Addr::unchecked("delegate");
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(10u64)
+        .delegate(
+            sender.clone(),
+            delegate.to_string(),
+            DelegationType::Weight,
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(10u64)
+        .undelegate(sender.clone(), |res| {
+            res.unwrap();
+        })
+        .fast_forward(10u64)
+        // before the delegation, the weight belongs to the delegator
+        .assert_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1571797424879305533u64),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(5_000u128),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(5_000u128),
+                share: Decimal::one(),
+                timestamp: Timestamp::from_nanos(1571797424879305533u64),
+            },
+        )
+        .assert_weight_at(
+            delegate.to_string(),
+            Timestamp::from_nanos(1571797424879305533u64),
+            BondingWeightResponse {
+                address: delegate.to_string(),
+                weight: Uint128::zero(),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(5_000u128),
+                share: Decimal::zero(),
+                timestamp: Timestamp::from_nanos(1571797424879305533u64),
+            },
+        )
+        // while delegated, it belongs to the delegate
+        .assert_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1571797434879305533u64),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::zero(),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(15_000u128),
+                share: Decimal::zero(),
+                timestamp: Timestamp::from_nanos(1571797434879305533u64),
+            },
+        )
+        .assert_weight_at(
+            delegate.to_string(),
+            Timestamp::from_nanos(1571797434879305533u64),
+            BondingWeightResponse {
+                address: delegate.to_string(),
+                weight: Uint128::new(15_000u128),
+                delegated_weight: Uint128::new(15_000u128),
+                global_weight: Uint128::new(15_000u128),
+                share: Decimal::one(),
+                timestamp: Timestamp::from_nanos(1571797434879305533u64),
+            },
+        )
+        // and back to the delegator once undelegated
+        .assert_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1571797444879305533u64),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(25_000u128),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(25_000u128),
+                share: Decimal::one(),
+                timestamp: Timestamp::from_nanos(1571797444879305533u64),
+            },
+        )
+        .assert_weight_at(
+            delegate.to_string(),
+            Timestamp::from_nanos(1571797444879305533u64),
+            BondingWeightResponse {
+                address: delegate.to_string(),
+                weight: Uint128::zero(),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(25_000u128),
+                share: Decimal::zero(),
+                timestamp: Timestamp::from_nanos(1571797444879305533u64),
+            },
+        )
+        // the voting power follows the delegation as well
+        .query_voting_power_at_height(delegate.to_string(), Some(12347u64), |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(10_000u128));
+        })
+        .query_voting_power_at_height(sender.to_string(), Some(12347u64), |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::zero());
+        });
+}
+
+#[test]
+fn test_delegate_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot
+        .instantiate_default()
+        .delegate(
+            sender.clone(),
+            sender.to_string(),
+            DelegationType::Weight,
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidDelegate {}.to_string()
+                );
+            },
+        )
+        // only bonders can delegate
+        .delegate(
+            sender.clone(),
+            "delegate".to_string(),
+            DelegationType::Weight,
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::NothingToDelegate {}.to_string()
+                );
+            },
+        )
+        .undelegate(sender, |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::NothingToUndelegate {}.to_string()
+            );
+        });
+}
+
+#[test]
+fn test_delegate_over_the_limit() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let delegate = Addr::unchecked("delegate");
+
+    robot.instantiate_default();
+
+    for i in 0..MAX_DELEGATIONS_PER_DELEGATE {
+        let delegator = Addr::unchecked(format!("delegator{i}"));
+        robot
+            .send_tokens(sender.clone(), delegator.clone(), &coins(1u128, "ampWHALE"))
+            .bond(
+                delegator.clone(),
+                Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    amount: Uint128::one(),
+                },
+                &coins(1u128, "ampWHALE"),
+                |res| {
+                    res.unwrap();
+                },
+            )
+            .delegate(
+                delegator,
+                delegate.to_string(),
+                DelegationType::Weight,
+                |res| {
+                    res.unwrap();
+                },
+            );
+    }
+
+    robot
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .delegate(sender, delegate.to_string(), DelegationType::Claim, |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::TooManyDelegations(MAX_DELEGATIONS_PER_DELEGATE).to_string()
+            );
+        })
+        // the existing delegators can still change their delegation
+        .delegate(
+            Addr::unchecked("delegator0"),
+            delegate.to_string(),
+            DelegationType::Claim,
+            |res| {
+                res.unwrap();
+            },
+        );
+}
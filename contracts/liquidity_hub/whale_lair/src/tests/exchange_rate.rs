@@ -0,0 +1,280 @@
+use cosmwasm_std::{coins, Decimal, Timestamp, Uint128, Uint64};
+
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::whale_lair::{BondedDenom, BondingAsset, TotalBondedByDenomResponse};
+
+use crate::state::MIN_LOCK_DURATION;
+use crate::tests::robot::{instantiate_mock_exchange_rate_oracle, TestingRobot};
+use crate::ContractError;
+
+#[test]
+fn test_exchange_rates_weight_bonds_by_underlying_value() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+    let oracle = instantiate_mock_exchange_rate_oracle(&mut robot, Decimal::percent(120));
+
+    robot
+        .instantiate(
+            Uint64::new(1_000_000_000_000u64),
+            Decimal::one(),
+            vec![
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
+                },
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "bWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: Some(oracle.to_string()),
+                },
+            ],
+            &vec![],
+        )
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .bond(
+            another_sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "bWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "bWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        // the exchange rate of bWHALE is taken from the oracle on instantiation
+        .query_total_bonded_by_denom(|res| {
+            assert_eq!(
+                res.unwrap().1,
+                TotalBondedByDenomResponse {
+                    bonded: vec![
+                        BondedDenom {
+                            asset: Asset {
+                                info: AssetInfo::NativeToken {
+                                    denom: "ampWHALE".to_string(),
+                                },
+                                amount: Uint128::new(1_000u128),
+                            },
+                            exchange_rate: Decimal::one(),
+                            underlying_amount: Uint128::new(1_000u128),
+                        },
+                        BondedDenom {
+                            asset: Asset {
+                                info: AssetInfo::NativeToken {
+                                    denom: "bWHALE".to_string(),
+                                },
+                                amount: Uint128::new(1_000u128),
+                            },
+                            exchange_rate: Decimal::percent(120),
+                            underlying_amount: Uint128::new(1_200u128),
+                        },
+                    ],
+                    total_underlying_amount: Uint128::new(2_200u128),
+                }
+            );
+        })
+        .fast_forward(10u64)
+        .query_weight(another_sender.to_string(), |res| {
+            let bonding_weight_response = res.unwrap().1;
+            assert_eq!(bonding_weight_response.weight, Uint128::new(12_000u128));
+            assert_eq!(
+                bonding_weight_response.global_weight,
+                Uint128::new(22_000u128)
+            );
+        })
+        .set_mock_exchange_rate(oracle, Decimal::percent(150))
+        .update_exchange_rates(another_sender.clone(), |res| {
+            res.unwrap();
+        })
+        .fast_forward(10u64)
+        // the new exchange rate only applies to the weight accrued since it was updated
+        .query_weight(another_sender.to_string(), |res| {
+            let bonding_weight_response = res.unwrap().1;
+            assert_eq!(bonding_weight_response.weight, Uint128::new(27_000u128));
+            assert_eq!(
+                bonding_weight_response.global_weight,
+                Uint128::new(47_000u128)
+            );
+        })
+        .query_weight(sender.to_string(), |res| {
+            assert_eq!(res.unwrap().1.weight, Uint128::new(20_000u128));
+        })
+        .assert_total_weight_at(
+            Timestamp::from_nanos(1571797429879305533u64),
+            Uint128::new(22_000u128),
+        )
+        .query_weight_at(
+            another_sender.to_string(),
+            Timestamp::from_nanos(1571797439879305533u64),
+            |res| {
+                assert_eq!(res.unwrap().1.weight, Uint128::new(27_000u128));
+            },
+        )
+        .query_total_bonded_by_denom(|res| {
+            let total_bonded_response = res.unwrap().1;
+            assert_eq!(
+                total_bonded_response.bonded[1].underlying_amount,
+                Uint128::new(1_500u128)
+            );
+            assert_eq!(
+                total_bonded_response.total_underlying_amount,
+                Uint128::new(2_500u128)
+            );
+        });
+}
+
+#[test]
+fn test_exchange_rates_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let oracle = instantiate_mock_exchange_rate_oracle(&mut robot, Decimal::zero());
+
+    robot
+        .instantiate_err(
+            Uint64::new(1_000_000_000_000u64),
+            Decimal::one(),
+            vec![BondingAsset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                weight_factor: Decimal::one(),
+                exchange_rate: Decimal::zero(),
+                exchange_rate_oracle: None,
+            }],
+            &vec![],
+            |error| {
+                assert_eq!(
+                    error.root_cause().to_string(),
+                    ContractError::InvalidExchangeRate {}.to_string()
+                );
+            },
+        )
+        .instantiate_err(
+            Uint64::new(1_000_000_000_000u64),
+            Decimal::one(),
+            vec![BondingAsset {
+                info: AssetInfo::NativeToken {
+                    denom: "bWHALE".to_string(),
+                },
+                weight_factor: Decimal::one(),
+                exchange_rate: Decimal::one(),
+                exchange_rate_oracle: Some(oracle.to_string()),
+            }],
+            &vec![],
+            |error| {
+                assert_eq!(
+                    error.root_cause().to_string(),
+                    ContractError::InvalidExchangeRate {}.to_string()
+                );
+            },
+        )
+        .instantiate_default()
+        .update_exchange_rates(sender, |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::NoExchangeRateOracle {}.to_string()
+            );
+        });
+}
+
+#[test]
+fn test_exchange_rate_changes_with_locks() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let oracle = instantiate_mock_exchange_rate_oracle(&mut robot, Decimal::percent(120));
+
+    robot
+        .instantiate(
+            Uint64::new(1_000_000_000_000u64),
+            Decimal::one(),
+            vec![BondingAsset {
+                info: AssetInfo::NativeToken {
+                    denom: "bWHALE".to_string(),
+                },
+                weight_factor: Decimal::one(),
+                exchange_rate: Decimal::one(),
+                exchange_rate_oracle: Some(oracle.to_string()),
+            }],
+            &vec![],
+        )
+        .bond_locked(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "bWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            Uint64::new(MIN_LOCK_DURATION),
+            &coins(1_000u128, "bWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(10u64)
+        .set_mock_exchange_rate(oracle, Decimal::one())
+        .update_exchange_rates(sender.clone(), |res| {
+            res.unwrap();
+        })
+        // the lock boost is fixed at the exchange rate the lock was made at
+        .query_weight(sender.to_string(), |res| {
+            let bonding_weight_response = res.unwrap().1;
+            assert_eq!(bonding_weight_response.weight, Uint128::new(12_230u128));
+            assert_eq!(
+                bonding_weight_response.global_weight,
+                bonding_weight_response.weight
+            );
+        })
+        .fast_forward(604_800u64)
+        .query_weight(sender.to_string(), |res| {
+            let bonding_weight_response = res.unwrap().1;
+            assert_eq!(
+                bonding_weight_response.global_weight,
+                bonding_weight_response.weight
+            );
+        })
+        .unlock(sender.clone(), 1u64, |res| {
+            res.unwrap();
+        })
+        .fast_forward(10u64)
+        .query_weight(sender.to_string(), |res| {
+            let bonding_weight_response = res.unwrap().1;
+            assert_eq!(
+                bonding_weight_response.global_weight,
+                bonding_weight_response.weight
+            );
+        })
+        .query_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1572402239879305533u64),
+            |res| {
+                let bonding_weight_response = res.unwrap().1;
+                assert_eq!(
+                    bonding_weight_response.global_weight,
+                    bonding_weight_response.weight
+                );
+            },
+        );
+}
@@ -0,0 +1,191 @@
+use cosmwasm_std::{coins, Decimal, Timestamp, Uint128};
+
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::whale_lair::{BondedResponse, BondingWeightResponse, PenaltyRecipient};
+
+use crate::tests::robot::TestingRobot;
+use crate::ContractError;
+
+#[test]
+fn test_instant_unbond_successfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |_res| {},
+        )
+        .fast_forward(10u64)
+        .instant_unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(400u128),
+            },
+            |res| {
+                res.unwrap();
+            },
+        )
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(600u128),
+                bonded_assets: vec![Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    amount: Uint128::new(600u128),
+                }],
+            },
+        )
+        // the weight is slashed proportionally to the unbonded amount
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(6_000u128),
+                global_weight: Uint128::new(6_000u128),
+                share: Decimal::one(),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+                delegated_weight: Uint128::zero(),
+            },
+        )
+        // 10% penalty goes to the fee collector, the rest is returned to the sender right away
+        .assert_balance(
+            sender.to_string(),
+            "ampWHALE".to_string(),
+            Uint128::new(999_999_360u128),
+        )
+        .assert_balance(
+            "fee_collector".to_string(),
+            "ampWHALE".to_string(),
+            Uint128::new(40u128),
+        )
+        .update_config(
+            sender.clone(),
+            None,
+            None,
+            None,
+            Some(Decimal::percent(50)),
+            Some(PenaltyRecipient::Burn),
+            None,
+            |res| {
+                res.unwrap();
+            },
+        )
+        .instant_unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(600u128),
+            },
+            |res| {
+                res.unwrap();
+            },
+        )
+        // the penalty is burned, so the fee collector balance doesn't change
+        .assert_balance(
+            sender.to_string(),
+            "ampWHALE".to_string(),
+            Uint128::new(999_999_660u128),
+        )
+        .assert_balance(
+            "fee_collector".to_string(),
+            "ampWHALE".to_string(),
+            Uint128::new(40u128),
+        );
+}
+
+#[test]
+fn test_instant_unbond_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |_res| {},
+        )
+        .instant_unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::zero(),
+            },
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidUnbondingAmount {}.to_string()
+                );
+            },
+        )
+        .instant_unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(2_000u128),
+            },
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InsufficientBond {}.to_string()
+                );
+            },
+        )
+        .instant_unbond(
+            another_sender,
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::NothingToUnbond {}.to_string()
+                );
+            },
+        )
+        .update_config(
+            sender,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(101)),
+            None,
+            None,
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidInstantUnbondPenalty {}.to_string()
+                );
+            },
+        );
+}
@@ -2,7 +2,10 @@ use cosmwasm_std::{Addr, Decimal, Uint64};
 
 use crate::tests::robot::TestingRobot;
 use white_whale::pool_network::asset::AssetInfo;
-use white_whale::whale_lair::Config;
+use white_whale::whale_lair::{BondingAsset, Config, PenaltyRecipient};
+
+use crate::state::BONDING_ASSETS_LIMIT;
+use crate::ContractError;
 
 #[test]
 fn test_instantiate_successfully() {
@@ -13,11 +16,21 @@ fn test_instantiate_successfully() {
             Uint64::new(1_000u64),
             Decimal::one(),
             vec![
-                AssetInfo::NativeToken {
-                    denom: "ampWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
-                AssetInfo::NativeToken {
-                    denom: "bWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "bWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
             ],
             &vec![],
@@ -27,13 +40,28 @@ fn test_instantiate_successfully() {
             unbonding_period: Uint64::new(1_000u64),
             growth_rate: Decimal::one(),
             bonding_assets: vec![
-                AssetInfo::NativeToken {
-                    denom: "ampWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
-                AssetInfo::NativeToken {
-                    denom: "bWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "bWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
             ],
+            instant_unbond_penalty: Decimal::percent(10),
+            penalty_recipient: PenaltyRecipient::FeeCollector {
+                address: "fee_collector".to_string(),
+            },
+            compound_config: None,
         });
 }
 
@@ -45,44 +73,44 @@ fn test_instantiate_unsuccessfully() {
     robot.instantiate_err(
         Uint64::new(1_000u64),
         Decimal::one(),
-        vec![
-            AssetInfo::NativeToken {
-                denom: "ampWHALE".to_string(),
-            },
-            AssetInfo::NativeToken {
-                denom: "bWHALE".to_string(),
-            },
-            AssetInfo::NativeToken {
-                denom: "uwhale".to_string(),
-            },
-        ],
+        ["ampWHALE", "bWHALE", "uwhale", "uusdc", "uatom", "uluna"]
+            .iter()
+            .map(|denom| BondingAsset {
+                info: AssetInfo::NativeToken {
+                    denom: denom.to_string(),
+                },
+                weight_factor: Decimal::one(),
+                exchange_rate: Decimal::one(),
+                exchange_rate_oracle: None,
+            })
+            .collect(),
         &vec![],
         |error| {
-            println!("1 --{error:?}");
-            println!("2 --{:?}", error.root_cause());
-            //println!("3 --{:?}", error.root_cause().downcast_ref::<ContractError>());
-            // assert_eq!(
-            //     error.root_cause().downcast_ref::<ContractError>().unwrap(),
-            //     &ContractError::InvalidBondingAssetsLimit(BONDING_ASSETS_LIMIT, 3));
+            assert_eq!(
+                error.root_cause().to_string(),
+                ContractError::InvalidBondingAssetsLimit(BONDING_ASSETS_LIMIT, 6).to_string()
+            );
         },
     );
 
-    // invalid tokens
+    // invalid weight factor
     robot.instantiate_err(
         Uint64::new(1_000u64),
         Decimal::one(),
-        vec![AssetInfo::Token {
-            contract_addr: "contract123".to_string(),
+        vec![BondingAsset {
+            info: AssetInfo::Token {
+                contract_addr: "contract123".to_string(),
+            },
+            weight_factor: Decimal::zero(),
+            exchange_rate: Decimal::one(),
+            exchange_rate_oracle: None,
         }],
         &vec![],
         |error| {
-            println!("1 --{error:?}");
-            println!("2 --{:?}", error.root_cause());
-            //println!("3 --{:?}", error.root_cause().downcast_ref::<ContractError>());
-
-            // assert_eq!(
-            //    error.root_cause().downcast_mut::<ContractError>().unwrap(),
-            //    ContractError::InvalidBondingAsset {});
+            assert_eq!(
+                error.root_cause().to_string(),
+                ContractError::InvalidWeightFactor {}.to_string()
+            );
         },
     );
 }
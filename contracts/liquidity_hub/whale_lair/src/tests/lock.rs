@@ -0,0 +1,226 @@
+use cosmwasm_std::{coins, Decimal, Timestamp, Uint128, Uint64};
+
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::whale_lair::{BondedResponse, BondingWeightResponse, LockedBond, LocksResponse};
+
+use crate::state::{MAX_LOCK_DURATION, MIN_LOCK_DURATION};
+use crate::tests::robot::TestingRobot;
+use crate::ContractError;
+
+#[test]
+fn test_bond_locked_successfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    // 26 weeks, i.e. a 1.5x multiplier
+    let duration = Uint64::new(26 * MIN_LOCK_DURATION);
+
+    robot
+        .instantiate_default()
+        .bond_locked(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            duration,
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .bond(
+            another_sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .assert_locks_response(
+            sender.to_string(),
+            LocksResponse {
+                total_locked: Uint128::new(1_000u128),
+                locks: vec![LockedBond {
+                    id: 1,
+                    asset: Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "ampWHALE".to_string(),
+                        },
+                        amount: Uint128::new(1_000u128),
+                    },
+                    timestamp: Timestamp::from_nanos(1571797419879305533u64),
+                    weight: Uint128::zero(),
+                    multiplier: Decimal::percent(150),
+                    unlock_time: Timestamp::from_nanos(1587522219879305533u64),
+                }],
+            },
+        )
+        .fast_forward(10u64)
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(15_000u128),
+                global_weight: Uint128::new(25_000u128),
+                share: Decimal::from_ratio(15_000u128, 25_000u128),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+                delegated_weight: Uint128::zero(),
+            },
+        )
+        .assert_bonding_weight_response(
+            another_sender.to_string(),
+            BondingWeightResponse {
+                address: another_sender.to_string(),
+                weight: Uint128::new(10_000u128),
+                global_weight: Uint128::new(25_000u128),
+                share: Decimal::from_ratio(10_000u128, 25_000u128),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+                delegated_weight: Uint128::zero(),
+            },
+        )
+        .unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::NothingToUnbond {}.to_string()
+                );
+            },
+        )
+        .unlock(sender.clone(), 1, |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::LockNotExpired(Timestamp::from_nanos(1587522219879305533u64))
+                    .to_string()
+            );
+        })
+        // the lock expired 10 seconds ago, the boost only applies until the unlock time
+        .fast_forward(15_724_800u64)
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(23_587_210_000u128),
+                global_weight: Uint128::new(39_312_020_000u128),
+                share: Decimal::from_ratio(23_587_210_000u128, 39_312_020_000u128),
+                timestamp: Timestamp::from_nanos(1587522229879305533u64),
+                delegated_weight: Uint128::zero(),
+            },
+        )
+        .unlock(sender.clone(), 1, |res| {
+            res.unwrap();
+        })
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(23_587_210_000u128),
+                global_weight: Uint128::new(39_312_020_000u128),
+                share: Decimal::from_ratio(23_587_210_000u128, 39_312_020_000u128),
+                timestamp: Timestamp::from_nanos(1587522229879305533u64),
+                delegated_weight: Uint128::zero(),
+            },
+        )
+        .assert_locks_response(
+            sender.to_string(),
+            LocksResponse {
+                total_locked: Uint128::zero(),
+                locks: vec![],
+            },
+        )
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(1_000u128),
+                bonded_assets: vec![Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    amount: Uint128::new(1_000u128),
+                }],
+            },
+        );
+}
+
+#[test]
+fn test_bond_locked_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot
+        .instantiate_default()
+        .bond_locked(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            Uint64::new(MIN_LOCK_DURATION - 1),
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidLockDuration(MIN_LOCK_DURATION, MAX_LOCK_DURATION)
+                        .to_string()
+                );
+            },
+        )
+        .bond_locked(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            Uint64::new(MAX_LOCK_DURATION + 1),
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidLockDuration(MIN_LOCK_DURATION, MAX_LOCK_DURATION)
+                        .to_string()
+                );
+            },
+        )
+        .bond_locked(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "non_whitelisted_asset".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            Uint64::new(MIN_LOCK_DURATION),
+            &coins(1_000u128, "non_whitelisted_asset"),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::AssetMismatch {}.to_string()
+                );
+            },
+        )
+        .unlock(sender, 1, |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::LockNotFound {}.to_string()
+            );
+        });
+}
@@ -1,6 +1,15 @@
 mod bond;
+mod bonding_assets;
+mod compound;
+mod delegate;
+mod exchange_rate;
+mod instant_unbond;
 mod instantiate;
+mod lock;
+mod rebond;
 mod robot;
 mod unbond;
 mod update_config;
+mod voting_power;
+mod weight_at;
 mod withdraw;
@@ -0,0 +1,191 @@
+use cosmwasm_std::{coins, Decimal, Timestamp, Uint128};
+
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::whale_lair::{Bond, BondedResponse, BondingWeightResponse, UnbondingResponse};
+
+use crate::tests::robot::TestingRobot;
+use crate::ContractError;
+
+#[test]
+fn test_rebond_successfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |_res| {},
+        )
+        .fast_forward(10u64)
+        .unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(300u128),
+            },
+            |_res| {},
+        )
+        .fast_forward(10u64)
+        .unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(200u128),
+            },
+            |_res| {},
+        )
+        .fast_forward(10u64)
+        .rebond(
+            sender.clone(),
+            "ampWHALE".to_string(),
+            Some(vec![1571797429u64]),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .assert_unbonding_response(
+            sender.to_string(),
+            "ampWHALE".to_string(),
+            UnbondingResponse {
+                total_amount: Uint128::new(200u128),
+                unbonding_requests: vec![Bond {
+                    asset: Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "ampWHALE".to_string(),
+                        },
+                        amount: Uint128::new(200u128),
+                    },
+                    timestamp: Timestamp::from_nanos(1571797439879305533u64),
+                    weight: Uint128::zero(),
+                }],
+            },
+        )
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(800u128),
+                bonded_assets: vec![Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    amount: Uint128::new(800u128),
+                }],
+            },
+        )
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(15_000u128),
+                global_weight: Uint128::new(15_000u128),
+                share: Decimal::one(),
+                timestamp: Timestamp::from_nanos(1571797449879305533u64),
+                delegated_weight: Uint128::zero(),
+            },
+        )
+        .fast_forward(10u64)
+        // the rebonded tokens accrue weight again from the moment they were rebonded
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(23_000u128),
+                global_weight: Uint128::new(23_000u128),
+                share: Decimal::one(),
+                timestamp: Timestamp::from_nanos(1571797459879305533u64),
+                delegated_weight: Uint128::zero(),
+            },
+        )
+        .rebond(sender.clone(), "ampWHALE".to_string(), None, |res| {
+            res.unwrap();
+        })
+        .assert_unbonding_response(
+            sender.to_string(),
+            "ampWHALE".to_string(),
+            UnbondingResponse {
+                total_amount: Uint128::zero(),
+                unbonding_requests: vec![],
+            },
+        )
+        .query_total_bonded(|res| {
+            let bonded_response = res.unwrap().1;
+            assert_eq!(
+                bonded_response,
+                BondedResponse {
+                    total_bonded: Uint128::new(1_000u128),
+                    bonded_assets: vec![Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "ampWHALE".to_string(),
+                        },
+                        amount: Uint128::new(1_000u128),
+                    }],
+                }
+            )
+        });
+}
+
+#[test]
+fn test_rebond_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot
+        .instantiate_default()
+        .rebond(sender.clone(), "ampWHALE".to_string(), None, |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::NothingToRebond {}.to_string()
+            );
+        })
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |_res| {},
+        )
+        .fast_forward(10u64)
+        .unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(300u128),
+            },
+            |_res| {},
+        )
+        .rebond(
+            sender.clone(),
+            "ampWHALE".to_string(),
+            Some(vec![1571797419u64]),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::UnbondingNotFound(1571797419u64).to_string()
+                );
+            },
+        )
+        .rebond(sender, "bWHALE".to_string(), None, |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::NothingToRebond {}.to_string()
+            );
+        });
+}
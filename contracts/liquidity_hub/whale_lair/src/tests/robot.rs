@@ -1,10 +1,22 @@
-use cosmwasm_std::{coin, Addr, Coin, Decimal, StdResult, Uint64};
-use cw_multi_test::{App, AppResponse, Executor};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coin, to_binary, Addr, BankMsg, Binary, Coin, Decimal, Empty, Response, StdError, StdResult,
+    Timestamp, Uint128, Uint64,
+};
+use cw20::{Cw20Coin, Cw20ExecuteMsg};
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+use cw_storage_plus::Item;
 
+use white_whale::fee_distributor;
 use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::pool_network::router;
+use white_whale::pool_network::router::SwapOperation;
 use white_whale::whale_lair::{
-    BondedResponse, BondingWeightResponse, Config, ExecuteMsg, InstantiateMsg, QueryMsg,
-    UnbondingResponse, WithdrawableResponse,
+    AutoCompoundResponse, BondedResponse, BondingAsset, BondingWeightResponse, CallbackMsg,
+    CompoundConfig, Config, Cw20HookMsg, DelegationResponse, DelegationType, DelegationsResponse,
+    ExchangeRateOracleQueryMsg, ExecuteMsg, InfoResponse, InstantiateMsg, LocksResponse,
+    PenaltyRecipient, QueryMsg, TotalBondedByDenomResponse, TotalPowerAtHeightResponse,
+    TotalWeightResponse, UnbondingResponse, VotingPowerAtHeightResponse, WithdrawableResponse,
 };
 use white_whale_testing::integration::contracts::whale_lair_contract;
 use white_whale_testing::integration::integration_mocks::mock_app_with_balance;
@@ -13,7 +25,7 @@ pub struct TestingRobot {
     app: App,
     pub sender: Addr,
     pub another_sender: Addr,
-    whale_lair_addr: Addr,
+    pub whale_lair_addr: Addr,
 }
 
 /// instantiate / execute messages
@@ -54,6 +66,7 @@ impl TestingRobot {
     pub(crate) fn fast_forward(&mut self, seconds: u64) -> &mut Self {
         let mut block_info = self.app.block_info();
         block_info.time = block_info.time.plus_nanos(seconds * 1_000_000_000);
+        block_info.height += 1;
         self.app.set_block(block_info);
 
         self
@@ -64,11 +77,21 @@ impl TestingRobot {
             Uint64::new(1_000_000_000_000u64),
             Decimal::one(),
             vec![
-                AssetInfo::NativeToken {
-                    denom: "ampWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
-                AssetInfo::NativeToken {
-                    denom: "bWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "bWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
             ],
             &vec![],
@@ -79,7 +102,7 @@ impl TestingRobot {
         &mut self,
         unbonding_period: Uint64,
         growth_rate: Decimal,
-        bonding_assets: Vec<AssetInfo>,
+        bonding_assets: Vec<BondingAsset>,
         funds: &Vec<Coin>,
     ) -> &mut Self {
         let whale_lair_addr =
@@ -94,7 +117,7 @@ impl TestingRobot {
         &mut self,
         unbonding_period: Uint64,
         growth_rate: Decimal,
-        bonding_assets: Vec<AssetInfo>,
+        bonding_assets: Vec<BondingAsset>,
         funds: &Vec<Coin>,
         error: impl Fn(anyhow::Error),
     ) -> &mut Self {
@@ -106,6 +129,12 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn send_tokens(&mut self, from: Addr, to: Addr, amount: &[Coin]) -> &mut Self {
+        self.app.send_tokens(from, to, amount).unwrap();
+
+        self
+    }
+
     pub(crate) fn bond(
         &mut self,
         sender: Addr,
@@ -123,6 +152,220 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn bond_cw20(
+        &mut self,
+        sender: Addr,
+        token_addr: Addr,
+        amount: Uint128,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = Cw20ExecuteMsg::Send {
+            contract: self.whale_lair_addr.to_string(),
+            amount,
+            msg: to_binary(&Cw20HookMsg::Bond {}).unwrap(),
+        };
+
+        response(self.app.execute_contract(sender, token_addr, &msg, &[]));
+
+        self
+    }
+
+    pub(crate) fn bond_locked(
+        &mut self,
+        sender: Addr,
+        asset: Asset,
+        duration: Uint64,
+        funds: &[Coin],
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::BondLocked { asset, duration };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, funds),
+        );
+
+        self
+    }
+
+    pub(crate) fn unlock(
+        &mut self,
+        sender: Addr,
+        lock_id: u64,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::Unlock { lock_id };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn delegate(
+        &mut self,
+        sender: Addr,
+        delegate: String,
+        delegation_type: DelegationType,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::Delegate {
+            delegate,
+            delegation_type,
+        };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn undelegate(
+        &mut self,
+        sender: Addr,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::Undelegate {};
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn instant_unbond(
+        &mut self,
+        sender: Addr,
+        asset: Asset,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::InstantUnbond { asset };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn rebond(
+        &mut self,
+        sender: Addr,
+        denom: String,
+        unbonding_timestamps: Option<Vec<u64>>,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::Rebond {
+            denom,
+            unbonding_timestamps,
+        };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn set_auto_compound(
+        &mut self,
+        sender: Addr,
+        enabled: bool,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::SetAutoCompound { enabled };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn compound(
+        &mut self,
+        sender: Addr,
+        addresses: Vec<String>,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::Compound { addresses };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn update_exchange_rates(
+        &mut self,
+        sender: Addr,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::UpdateExchangeRates {};
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn update_bonding_assets(
+        &mut self,
+        sender: Addr,
+        bonding_assets: Vec<BondingAsset>,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::UpdateBondingAssets { bonding_assets };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn set_mock_exchange_rate(
+        &mut self,
+        oracle: Addr,
+        exchange_rate: Decimal,
+    ) -> &mut Self {
+        self.app
+            .execute_contract(self.sender.clone(), oracle, &exchange_rate, &[])
+            .unwrap();
+
+        self
+    }
+
+    pub(crate) fn callback(
+        &mut self,
+        sender: Addr,
+        callback: CallbackMsg,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::Callback(callback);
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
     pub(crate) fn unbond(
         &mut self,
         sender: Addr,
@@ -143,9 +386,10 @@ impl TestingRobot {
         &mut self,
         sender: Addr,
         denom: String,
+        limit: Option<u8>,
         response: impl Fn(Result<AppResponse, anyhow::Error>),
     ) -> &mut Self {
-        let msg = ExecuteMsg::Withdraw { denom };
+        let msg = ExecuteMsg::Withdraw { denom, limit };
 
         response(
             self.app
@@ -155,18 +399,25 @@ impl TestingRobot {
         self
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn update_config(
         &mut self,
         sender: Addr,
         owner: Option<String>,
         unbonding_period: Option<Uint64>,
         growth_rate: Option<Decimal>,
+        instant_unbond_penalty: Option<Decimal>,
+        penalty_recipient: Option<PenaltyRecipient>,
+        compound_config: Option<CompoundConfig>,
         response: impl Fn(Result<AppResponse, anyhow::Error>),
     ) -> &mut Self {
         let msg = ExecuteMsg::UpdateConfig {
             owner,
             unbonding_period,
             growth_rate,
+            instant_unbond_penalty,
+            penalty_recipient,
+            compound_config,
         };
 
         response(
@@ -178,17 +429,274 @@ impl TestingRobot {
     }
 }
 
+/// Instantiates a cw20 token, e.g. an LP token, with a balance for both senders.
+pub(crate) fn instantiate_cw20_token(robot: &mut TestingRobot, symbol: &str) -> Addr {
+    let cw20_id = robot.app.store_code(cw20_token_contract());
+    let msg = cw20_base::msg::InstantiateMsg {
+        name: symbol.to_string(),
+        symbol: symbol.to_string(),
+        decimals: 6,
+        initial_balances: vec![
+            Cw20Coin {
+                address: robot.sender.to_string(),
+                amount: Uint128::new(1_000_000_000u128),
+            },
+            Cw20Coin {
+                address: robot.another_sender.to_string(),
+                amount: Uint128::new(1_000_000_000u128),
+            },
+        ],
+        mint: None,
+        marketing: None,
+    };
+
+    robot
+        .app
+        .instantiate_contract(cw20_id, robot.sender.clone(), &msg, &[], symbol, None)
+        .unwrap()
+}
+
+fn cw20_token_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    );
+
+    Box::new(contract)
+}
+
+/// Instantiates a mock fee distributor, which pays the given rewards to whoever claims, holding
+/// enough funds for the given number of claims.
+pub(crate) fn instantiate_mock_fee_distributor(
+    robot: &mut TestingRobot,
+    rewards: Vec<Coin>,
+    claims: u128,
+) -> Addr {
+    let mock_id = robot.app.store_code(mock_fee_distributor_contract());
+    let mut funds: Vec<Coin> = rewards
+        .iter()
+        .map(|reward| coin(reward.amount.u128() * claims, reward.denom.clone()))
+        .collect();
+    funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+    robot
+        .app
+        .instantiate_contract(
+            mock_id,
+            robot.sender.clone(),
+            &rewards,
+            &funds,
+            "mock_fee_distributor",
+            None,
+        )
+        .unwrap()
+}
+
+/// Instantiates a mock router, which swaps and simulates the swaps to the ask asset at the given
+/// rate using the given funds.
+pub(crate) fn instantiate_mock_router(
+    robot: &mut TestingRobot,
+    funds: &[Coin],
+    swap_rate: Decimal,
+) -> Addr {
+    let mock_id = robot.app.store_code(mock_router_contract());
+    robot
+        .app
+        .instantiate_contract(
+            mock_id,
+            robot.sender.clone(),
+            &swap_rate,
+            funds,
+            "mock_router",
+            None,
+        )
+        .unwrap()
+}
+
+const MOCK_REWARDS: Item<Vec<Coin>> = Item::new("mock_rewards");
+
+fn mock_fee_distributor_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        |deps, _env, info, msg: fee_distributor::ExecuteMsg| -> StdResult<Response> {
+            match msg {
+                fee_distributor::ExecuteMsg::Claim { .. } => {
+                    let mut rewards = MOCK_REWARDS.load(deps.storage)?;
+                    rewards.sort_by(|a, b| a.denom.cmp(&b.denom));
+                    Ok(Response::new().add_message(BankMsg::Send {
+                        to_address: info.sender.to_string(),
+                        amount: rewards,
+                    }))
+                }
+                _ => Err(StdError::generic_err(
+                    "unexpected mock fee distributor message",
+                )),
+            }
+        },
+        |deps, _env, _info, rewards: Vec<Coin>| -> StdResult<Response> {
+            MOCK_REWARDS.save(deps.storage, &rewards)?;
+            Ok(Response::new())
+        },
+        |deps, _env, msg: fee_distributor::QueryMsg| -> StdResult<Binary> {
+            match msg {
+                fee_distributor::QueryMsg::Config {} => to_binary(&fee_distributor::Config {
+                    owner: Addr::unchecked("owner"),
+                    bonding_contract_addr: Addr::unchecked("bonding_contract"),
+                    fee_collector_addr: Addr::unchecked("fee_collector"),
+                    grace_period: Uint64::one(),
+                    epoch_config: fee_distributor::EpochConfig {
+                        duration: Uint64::one(),
+                        genesis_epoch: Uint64::zero(),
+                    },
+                    distribution_assets: MOCK_REWARDS
+                        .load(deps.storage)?
+                        .into_iter()
+                        .map(|reward| AssetInfo::NativeToken {
+                            denom: reward.denom,
+                        })
+                        .collect(),
+                    funding_whitelist: vec![],
+                }),
+                // everyone but the another_sender has something to claim
+                fee_distributor::QueryMsg::Claimable { address } => {
+                    to_binary(&fee_distributor::ClaimableEpochsResponse {
+                        epochs: if address == "random" {
+                            vec![]
+                        } else {
+                            vec![fee_distributor::Epoch::default()]
+                        },
+                    })
+                }
+                _ => Err(StdError::generic_err(
+                    "unexpected mock fee distributor query",
+                )),
+            }
+        },
+    );
+
+    Box::new(contract)
+}
+
+/// Instantiates a mock liquid staking hub, which reports the given exchange rate.
+pub(crate) fn instantiate_mock_exchange_rate_oracle(
+    robot: &mut TestingRobot,
+    exchange_rate: Decimal,
+) -> Addr {
+    let mock_id = robot.app.store_code(mock_exchange_rate_oracle_contract());
+    robot
+        .app
+        .instantiate_contract(
+            mock_id,
+            robot.sender.clone(),
+            &exchange_rate,
+            &[],
+            "mock_exchange_rate_oracle",
+            None,
+        )
+        .unwrap()
+}
+
+const MOCK_EXCHANGE_RATE: Item<Decimal> = Item::new("mock_exchange_rate");
+
+/// The state of a liquid staking hub, which has more fields than the exchange rate.
+#[cw_serde]
+struct MockHubState {
+    total_ustake: Uint128,
+    exchange_rate: Decimal,
+}
+
+fn mock_exchange_rate_oracle_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        |deps, _env, _info, exchange_rate: Decimal| -> StdResult<Response> {
+            MOCK_EXCHANGE_RATE.save(deps.storage, &exchange_rate)?;
+            Ok(Response::new())
+        },
+        |deps, _env, _info, exchange_rate: Decimal| -> StdResult<Response> {
+            MOCK_EXCHANGE_RATE.save(deps.storage, &exchange_rate)?;
+            Ok(Response::new())
+        },
+        |deps, _env, msg: ExchangeRateOracleQueryMsg| -> StdResult<Binary> {
+            match msg {
+                ExchangeRateOracleQueryMsg::State {} => to_binary(&MockHubState {
+                    total_ustake: Uint128::new(1_000_000u128),
+                    exchange_rate: MOCK_EXCHANGE_RATE.load(deps.storage)?,
+                }),
+            }
+        },
+    );
+
+    Box::new(contract)
+}
+
+const MOCK_SWAP_RATE: Item<Decimal> = Item::new("mock_swap_rate");
+
+fn mock_router_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        |deps, _env, info, msg: router::ExecuteMsg| -> StdResult<Response> {
+            match msg {
+                router::ExecuteMsg::ExecuteSwapOperations {
+                    operations,
+                    minimum_receive,
+                    ..
+                } => {
+                    let ask_asset_info = operations.last().unwrap().get_target_asset_info();
+                    let amount = info.funds[0].amount * MOCK_SWAP_RATE.load(deps.storage)?;
+                    if amount < minimum_receive.unwrap_or_default() {
+                        return Err(StdError::generic_err("minimum receive amount not reached"));
+                    }
+
+                    Ok(Response::new().add_message(
+                        Asset {
+                            info: ask_asset_info,
+                            amount,
+                        }
+                        .into_msg(info.sender)?,
+                    ))
+                }
+                _ => Err(StdError::generic_err("unexpected mock router message")),
+            }
+        },
+        |deps, _env, _info, swap_rate: Decimal| -> StdResult<Response> {
+            MOCK_SWAP_RATE.save(deps.storage, &swap_rate)?;
+            Ok(Response::new())
+        },
+        |deps, _env, msg: router::QueryMsg| -> StdResult<Binary> {
+            match msg {
+                router::QueryMsg::SimulateSwapOperations { offer_amount, .. } => {
+                    to_binary(&router::SimulateSwapOperationsResponse {
+                        amount: offer_amount * MOCK_SWAP_RATE.load(deps.storage)?,
+                    })
+                }
+                router::QueryMsg::SwapRoute {
+                    offer_asset_info,
+                    ask_asset_info,
+                } => to_binary(&vec![SwapOperation::TerraSwap {
+                    offer_asset_info,
+                    ask_asset_info,
+                }]),
+                _ => Err(StdError::generic_err("unexpected mock router query")),
+            }
+        },
+    );
+
+    Box::new(contract)
+}
+
 fn instantiate_contract(
     robot: &mut TestingRobot,
     unbonding_period: Uint64,
     growth_rate: Decimal,
-    bonding_assets: Vec<AssetInfo>,
+    bonding_assets: Vec<BondingAsset>,
     funds: &Vec<Coin>,
 ) -> anyhow::Result<Addr> {
     let msg = InstantiateMsg {
         unbonding_period,
         growth_rate,
         bonding_assets,
+        instant_unbond_penalty: Decimal::percent(10),
+        penalty_recipient: PenaltyRecipient::FeeCollector {
+            address: "fee_collector".to_string(),
+        },
     };
 
     let whale_lair_id = robot.app.store_code(whale_lair_contract());
@@ -235,6 +743,118 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn query_weight_at(
+        &mut self,
+        address: String,
+        timestamp: Timestamp,
+        response: impl Fn(StdResult<(&mut Self, BondingWeightResponse)>),
+    ) -> &mut Self {
+        let bonding_weight_response: StdResult<BondingWeightResponse> =
+            self.app.wrap().query_wasm_smart(
+                &self.whale_lair_addr,
+                &QueryMsg::WeightAt { address, timestamp },
+            );
+
+        response(bonding_weight_response.map(|res| (&mut *self, res)));
+
+        self
+    }
+
+    pub(crate) fn query_total_weight_at(
+        &mut self,
+        timestamp: Timestamp,
+        response: impl Fn(StdResult<(&mut Self, TotalWeightResponse)>),
+    ) -> &mut Self {
+        let total_weight_response: StdResult<TotalWeightResponse> =
+            self.app.wrap().query_wasm_smart(
+                &self.whale_lair_addr,
+                &QueryMsg::TotalWeightAt { timestamp },
+            );
+
+        response(total_weight_response.map(|res| (&mut *self, res)));
+
+        self
+    }
+
+    pub(crate) fn query_voting_power_at_height(
+        &mut self,
+        address: String,
+        height: Option<u64>,
+        response: impl Fn(StdResult<(&mut Self, VotingPowerAtHeightResponse)>),
+    ) -> &mut Self {
+        let voting_power_response: StdResult<VotingPowerAtHeightResponse> =
+            self.app.wrap().query_wasm_smart(
+                &self.whale_lair_addr,
+                &QueryMsg::VotingPowerAtHeight { address, height },
+            );
+
+        response(voting_power_response.map(|res| (&mut *self, res)));
+
+        self
+    }
+
+    pub(crate) fn query_total_power_at_height(
+        &mut self,
+        height: Option<u64>,
+        response: impl Fn(StdResult<(&mut Self, TotalPowerAtHeightResponse)>),
+    ) -> &mut Self {
+        let total_power_response: StdResult<TotalPowerAtHeightResponse> =
+            self.app.wrap().query_wasm_smart(
+                &self.whale_lair_addr,
+                &QueryMsg::TotalPowerAtHeight { height },
+            );
+
+        response(total_power_response.map(|res| (&mut *self, res)));
+
+        self
+    }
+
+    pub(crate) fn query_info(
+        &mut self,
+        response: impl Fn(StdResult<(&mut Self, InfoResponse)>),
+    ) -> &mut Self {
+        let info_response: InfoResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.whale_lair_addr, &QueryMsg::Info {})
+            .unwrap();
+
+        response(Ok((self, info_response)));
+
+        self
+    }
+
+    pub(crate) fn query_total_bonded_by_denom(
+        &mut self,
+        response: impl Fn(StdResult<(&mut Self, TotalBondedByDenomResponse)>),
+    ) -> &mut Self {
+        let total_bonded_response: TotalBondedByDenomResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.whale_lair_addr, &QueryMsg::TotalBondedByDenom {})
+            .unwrap();
+
+        response(Ok((self, total_bonded_response)));
+
+        self
+    }
+
+    pub(crate) fn query_auto_compound(
+        &mut self,
+        address: String,
+        response: impl Fn(StdResult<(&mut Self, AutoCompoundResponse)>),
+    ) -> &mut Self {
+        let auto_compound_response: AutoCompoundResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.whale_lair_addr, &QueryMsg::AutoCompound { address })
+            .unwrap();
+
+        response(Ok((self, auto_compound_response)));
+
+        self
+    }
+
     pub(crate) fn query_bonded(
         &mut self,
         address: String,
@@ -298,6 +918,72 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn query_locks(
+        &mut self,
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u8>,
+        response: impl Fn(StdResult<(&mut Self, LocksResponse)>),
+    ) -> &mut Self {
+        let locks_response: LocksResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.whale_lair_addr,
+                &QueryMsg::Locks {
+                    address,
+                    start_after,
+                    limit,
+                },
+            )
+            .unwrap();
+
+        response(Ok((self, locks_response)));
+
+        self
+    }
+
+    pub(crate) fn query_delegation(
+        &mut self,
+        delegator: String,
+        response: impl Fn(StdResult<(&mut Self, DelegationResponse)>),
+    ) -> &mut Self {
+        let delegation_response: DelegationResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.whale_lair_addr, &QueryMsg::Delegation { delegator })
+            .unwrap();
+
+        response(Ok((self, delegation_response)));
+
+        self
+    }
+
+    pub(crate) fn query_delegations(
+        &mut self,
+        delegate: String,
+        start_after: Option<String>,
+        limit: Option<u8>,
+        response: impl Fn(StdResult<(&mut Self, DelegationsResponse)>),
+    ) -> &mut Self {
+        let delegations_response: DelegationsResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.whale_lair_addr,
+                &QueryMsg::Delegations {
+                    delegate,
+                    start_after,
+                    limit,
+                },
+            )
+            .unwrap();
+
+        response(Ok((self, delegations_response)));
+
+        self
+    }
+
     pub(crate) fn query_total_bonded(
         &mut self,
         response: impl Fn(StdResult<(&mut Self, BondedResponse)>),
@@ -312,6 +998,19 @@ impl TestingRobot {
 
         self
     }
+
+    pub(crate) fn query_balance(
+        &mut self,
+        address: String,
+        denom: String,
+        response: impl Fn(StdResult<(&mut Self, Uint128)>),
+    ) -> &mut Self {
+        let balance = self.app.wrap().query_balance(address, denom).unwrap();
+
+        response(Ok((self, balance.amount)));
+
+        self
+    }
 }
 
 /// assertions
@@ -347,6 +1046,62 @@ impl TestingRobot {
         })
     }
 
+    pub(crate) fn assert_weight_at(
+        &mut self,
+        address: String,
+        timestamp: Timestamp,
+        expected: BondingWeightResponse,
+    ) -> &mut Self {
+        self.query_weight_at(address, timestamp, |res| {
+            let bonding_weight_response = res.unwrap().1;
+            assert_eq!(bonding_weight_response, expected);
+        })
+    }
+
+    pub(crate) fn assert_total_weight_at(
+        &mut self,
+        timestamp: Timestamp,
+        expected: Uint128,
+    ) -> &mut Self {
+        self.query_total_weight_at(timestamp, |res| {
+            let total_weight_response = res.unwrap().1;
+            assert_eq!(total_weight_response.weight, expected);
+        })
+    }
+
+    pub(crate) fn assert_locks_response(
+        &mut self,
+        address: String,
+        expected: LocksResponse,
+    ) -> &mut Self {
+        self.query_locks(address, None, None, |res| {
+            let locks_response = res.unwrap().1;
+            assert_eq!(locks_response, expected);
+        })
+    }
+
+    pub(crate) fn assert_delegation_response(
+        &mut self,
+        delegator: String,
+        expected: DelegationResponse,
+    ) -> &mut Self {
+        self.query_delegation(delegator, |res| {
+            let delegation_response = res.unwrap().1;
+            assert_eq!(delegation_response, expected);
+        })
+    }
+
+    pub(crate) fn assert_delegations_response(
+        &mut self,
+        delegate: String,
+        expected: DelegationsResponse,
+    ) -> &mut Self {
+        self.query_delegations(delegate, None, None, |res| {
+            let delegations_response = res.unwrap().1;
+            assert_eq!(delegations_response, expected);
+        })
+    }
+
     pub(crate) fn assert_unbonding_response(
         &mut self,
         address: String,
@@ -370,4 +1125,16 @@ impl TestingRobot {
             assert_eq!(withdrawable_response, expected);
         })
     }
+
+    pub(crate) fn assert_balance(
+        &mut self,
+        address: String,
+        denom: String,
+        expected: Uint128,
+    ) -> &mut Self {
+        self.query_balance(address, denom, |res| {
+            let balance = res.unwrap().1;
+            assert_eq!(balance, expected);
+        })
+    }
 }
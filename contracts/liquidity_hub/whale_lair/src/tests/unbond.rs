@@ -34,6 +34,7 @@ fn test_unbond_successfully() {
                 global_weight: Uint128::new(10_000u128),
                 share: Decimal::one(),
                 timestamp: Timestamp::from_nanos(1571797429879305533u64),
+                delegated_weight: Uint128::zero(),
             },
         )
         .unbond(
@@ -88,10 +89,11 @@ fn test_unbond_successfully() {
             sender.to_string(),
             BondingWeightResponse {
                 address: sender.to_string(),
-                weight: Uint128::new(17_000u128),
-                global_weight: Uint128::new(17_000u128),
+                weight: Uint128::new(14_000u128),
+                global_weight: Uint128::new(14_000u128),
                 share: Decimal::one(),
                 timestamp: Timestamp::from_nanos(1571797439879305533u64),
+                delegated_weight: Uint128::zero(),
             },
         )
         .fast_forward(10u64)
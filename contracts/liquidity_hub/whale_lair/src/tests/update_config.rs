@@ -1,7 +1,7 @@
 use cosmwasm_std::{Addr, Decimal, Uint128, Uint64};
 
 use white_whale::pool_network::asset::AssetInfo;
-use white_whale::whale_lair::Config;
+use white_whale::whale_lair::{BondingAsset, Config, PenaltyRecipient};
 
 use crate::tests::robot::TestingRobot;
 
@@ -17,13 +17,28 @@ fn test_update_config_successfully() {
             unbonding_period: Uint64::new(1_000_000_000_000u64),
             growth_rate: Decimal::one(),
             bonding_assets: vec![
-                AssetInfo::NativeToken {
-                    denom: "ampWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
-                AssetInfo::NativeToken {
-                    denom: "bWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "bWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
             ],
+            instant_unbond_penalty: Decimal::percent(10),
+            penalty_recipient: PenaltyRecipient::FeeCollector {
+                address: "fee_collector".to_string(),
+            },
+            compound_config: None,
         })
         .update_config(
             owner.clone(),
@@ -33,6 +48,9 @@ fn test_update_config_successfully() {
                 Uint128::new(1u128),
                 Uint128::new(2u128),
             )),
+            None,
+            None,
+            None,
             |_res| {},
         )
         .assert_config(Config {
@@ -40,19 +58,37 @@ fn test_update_config_successfully() {
             unbonding_period: Uint64::new(500u64),
             growth_rate: Decimal::from_ratio(Uint128::new(1u128), Uint128::new(2u128)),
             bonding_assets: vec![
-                AssetInfo::NativeToken {
-                    denom: "ampWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
-                AssetInfo::NativeToken {
-                    denom: "bWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "bWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
             ],
+            instant_unbond_penalty: Decimal::percent(10),
+            penalty_recipient: PenaltyRecipient::FeeCollector {
+                address: "fee_collector".to_string(),
+            },
+            compound_config: None,
         })
         .update_config(
             owner,
             Some("new_owner".to_string()),
             None,
             Some(Decimal::one()),
+            None,
+            None,
+            None,
             |_res| {},
         )
         .assert_config(Config {
@@ -60,13 +96,28 @@ fn test_update_config_successfully() {
             unbonding_period: Uint64::new(500u64),
             growth_rate: Decimal::one(),
             bonding_assets: vec![
-                AssetInfo::NativeToken {
-                    denom: "ampWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
-                AssetInfo::NativeToken {
-                    denom: "bWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "bWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
             ],
+            instant_unbond_penalty: Decimal::percent(10),
+            penalty_recipient: PenaltyRecipient::FeeCollector {
+                address: "fee_collector".to_string(),
+            },
+            compound_config: None,
         });
 }
 
@@ -81,13 +132,28 @@ fn test_update_config_unsuccessfully() {
             unbonding_period: Uint64::new(1_000_000_000_000u64),
             growth_rate: Decimal::one(),
             bonding_assets: vec![
-                AssetInfo::NativeToken {
-                    denom: "ampWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
-                AssetInfo::NativeToken {
-                    denom: "bWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "bWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
             ],
+            instant_unbond_penalty: Decimal::percent(10),
+            penalty_recipient: PenaltyRecipient::FeeCollector {
+                address: "fee_collector".to_string(),
+            },
+            compound_config: None,
         })
         .update_config(
             Addr::unchecked("unauthorized"),
@@ -97,6 +163,9 @@ fn test_update_config_unsuccessfully() {
                 Uint128::new(1u128),
                 Uint128::new(2u128),
             )),
+            None,
+            None,
+            None,
             |_res| {
                 //println!("{:?}", res.unwrap_err().root_cause());
                 // assert_eq!(
@@ -110,13 +179,28 @@ fn test_update_config_unsuccessfully() {
             unbonding_period: Uint64::new(1_000_000_000_000u64),
             growth_rate: Decimal::one(),
             bonding_assets: vec![
-                AssetInfo::NativeToken {
-                    denom: "ampWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
-                AssetInfo::NativeToken {
-                    denom: "bWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "bWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
             ],
+            instant_unbond_penalty: Decimal::percent(10),
+            penalty_recipient: PenaltyRecipient::FeeCollector {
+                address: "fee_collector".to_string(),
+            },
+            compound_config: None,
         })
         .update_config(
             Addr::unchecked("owner"),
@@ -126,6 +210,9 @@ fn test_update_config_unsuccessfully() {
                 Uint128::new(2u128),
                 Uint128::new(1u128),
             )),
+            None,
+            None,
+            None,
             |_res| {
                 //println!("{:?}", res.unwrap_err().root_cause());
                 // assert_eq!(
@@ -139,12 +226,27 @@ fn test_update_config_unsuccessfully() {
             unbonding_period: Uint64::new(1_000_000_000_000u64),
             growth_rate: Decimal::one(),
             bonding_assets: vec![
-                AssetInfo::NativeToken {
-                    denom: "ampWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
-                AssetInfo::NativeToken {
-                    denom: "bWHALE".to_string(),
+                BondingAsset {
+                    info: AssetInfo::NativeToken {
+                        denom: "bWHALE".to_string(),
+                    },
+                    weight_factor: Decimal::one(),
+                    exchange_rate: Decimal::one(),
+                    exchange_rate_oracle: None,
                 },
             ],
+            instant_unbond_penalty: Decimal::percent(10),
+            penalty_recipient: PenaltyRecipient::FeeCollector {
+                address: "fee_collector".to_string(),
+            },
+            compound_config: None,
         });
 }
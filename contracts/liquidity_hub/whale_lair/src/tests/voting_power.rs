@@ -0,0 +1,114 @@
+use cosmwasm_std::{coins, Uint128};
+
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::whale_lair::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse};
+
+use crate::tests::robot::TestingRobot;
+
+#[test]
+fn test_voting_power_at_height() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .query_voting_power_at_height(sender.to_string(), None, |res| {
+            assert_eq!(
+                res.unwrap().1,
+                VotingPowerAtHeightResponse {
+                    power: Uint128::zero(),
+                    height: 12345u64,
+                }
+            );
+        })
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |_res| {},
+        )
+        // the voting power is taken at the start of the block, so the bond doesn't count yet
+        .query_voting_power_at_height(sender.to_string(), Some(12345u64), |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::zero());
+        })
+        .fast_forward(10u64)
+        .query_voting_power_at_height(sender.to_string(), None, |res| {
+            assert_eq!(
+                res.unwrap().1,
+                VotingPowerAtHeightResponse {
+                    power: Uint128::zero(),
+                    height: 12346u64,
+                }
+            );
+        })
+        .bond(
+            another_sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "bWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "bWHALE"),
+            |_res| {},
+        )
+        .fast_forward(10u64)
+        // the voting power is the weight at the last execution before the block
+        .query_voting_power_at_height(sender.to_string(), None, |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(10_000u128));
+        })
+        .query_voting_power_at_height(another_sender.to_string(), None, |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::zero());
+        })
+        .query_total_power_at_height(None, |res| {
+            assert_eq!(
+                res.unwrap().1,
+                TotalPowerAtHeightResponse {
+                    power: Uint128::new(10_000u128),
+                    height: 12347u64,
+                }
+            );
+        })
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "bWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "bWHALE"),
+            |_res| {},
+        )
+        .fast_forward(10u64)
+        .query_voting_power_at_height(sender.to_string(), None, |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(20_000u128));
+        })
+        .query_voting_power_at_height(another_sender.to_string(), None, |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(10_000u128));
+        })
+        .query_total_power_at_height(None, |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(30_000u128));
+        })
+        // bonding later on doesn't change the voting power at past heights
+        .query_voting_power_at_height(sender.to_string(), Some(12347u64), |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(10_000u128));
+        })
+        .query_total_power_at_height(Some(12347u64), |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(10_000u128));
+        })
+        .query_total_power_at_height(Some(12349u64), |res| {
+            assert!(res.is_err());
+        })
+        .query_info(|res| {
+            let info = res.unwrap().1.info;
+            assert_eq!(info.contract, "white_whale-whale_lair");
+            assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        });
+}
@@ -0,0 +1,287 @@
+use cosmwasm_std::testing::mock_dependencies;
+use cosmwasm_std::{coins, Addr, Decimal, OwnedDeps, Timestamp, Uint128, Uint64};
+
+use white_whale::pool_network::asset::{Asset, AssetInfo};
+use white_whale::whale_lair::{
+    Bond, BondingAsset, BondingWeightResponse, Config, PenaltyRecipient,
+};
+
+use crate::state::{
+    get_weight_at, snapshot_weight, update_local_weight, BOND, CONFIG, MIN_LOCK_DURATION,
+    WEIGHT_HISTORY_START,
+};
+use crate::tests::robot::TestingRobot;
+
+#[test]
+fn test_weight_at_past_timestamps() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |_res| {},
+        )
+        .fast_forward(10u64)
+        .bond(
+            another_sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "bWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "bWHALE"),
+            |_res| {},
+        )
+        .fast_forward(10u64)
+        .unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(500u128),
+            },
+            |_res| {},
+        )
+        .fast_forward(10u64)
+        // before anything was bonded
+        .assert_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1571797418879305533u64),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::zero(),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::zero(),
+                share: Decimal::zero(),
+                timestamp: Timestamp::from_nanos(1571797418879305533u64),
+            },
+        )
+        .assert_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1571797424879305533u64),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(5_000u128),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(5_000u128),
+                share: Decimal::one(),
+                timestamp: Timestamp::from_nanos(1571797424879305533u64),
+            },
+        )
+        .assert_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1571797434879305533u64),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(15_000u128),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(20_000u128),
+                share: Decimal::percent(75),
+                timestamp: Timestamp::from_nanos(1571797434879305533u64),
+            },
+        )
+        .assert_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1571797444879305533u64),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(12_500u128),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(27_500u128),
+                share: Decimal::from_ratio(5u128, 11u128),
+                timestamp: Timestamp::from_nanos(1571797444879305533u64),
+            },
+        )
+        .assert_weight_at(
+            another_sender.to_string(),
+            Timestamp::from_nanos(1571797444879305533u64),
+            BondingWeightResponse {
+                address: another_sender.to_string(),
+                weight: Uint128::new(15_000u128),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(27_500u128),
+                share: Decimal::from_ratio(6u128, 11u128),
+                timestamp: Timestamp::from_nanos(1571797444879305533u64),
+            },
+        )
+        // the weight at the current timestamp matches the Weight query
+        .assert_total_weight_at(
+            Timestamp::from_nanos(1571797449879305533u64),
+            Uint128::new(35_000u128),
+        )
+        .query_weight(sender.to_string(), |res| {
+            let bonding_weight_response = res.unwrap().1;
+            assert_eq!(
+                bonding_weight_response.global_weight,
+                Uint128::new(35_000u128)
+            );
+        })
+        .query_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1571797450879305533u64),
+            |res| {
+                assert!(res.is_err());
+            },
+        )
+        .query_total_weight_at(Timestamp::from_nanos(1571797450879305533u64), |res| {
+            assert!(res.is_err());
+        });
+}
+
+#[test]
+fn test_weight_at_with_expired_locks() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot
+        .instantiate_default()
+        .bond_locked(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            Uint64::new(MIN_LOCK_DURATION),
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(604_900u64)
+        // the lock boosts the weight of the 1_000 tokens to 1_019 per second while it lasts
+        .assert_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1571797519879305533u64),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(101_900u128),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(101_900u128),
+                share: Decimal::one(),
+                timestamp: Timestamp::from_nanos(1571797519879305533u64),
+            },
+        )
+        .assert_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1572402269879305533u64),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(616_341_200u128),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(616_341_200u128),
+                share: Decimal::one(),
+                timestamp: Timestamp::from_nanos(1572402269879305533u64),
+            },
+        )
+        .unlock(sender.clone(), 1u64, |res| {
+            res.unwrap();
+        })
+        .fast_forward(10u64)
+        // unlocking doesn't change the weight history
+        .assert_total_weight_at(
+            Timestamp::from_nanos(1572402269879305533u64),
+            Uint128::new(616_341_200u128),
+        )
+        .assert_weight_at(
+            sender.to_string(),
+            Timestamp::from_nanos(1572402329879305533u64),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(616_401_200u128),
+                delegated_weight: Uint128::zero(),
+                global_weight: Uint128::new(616_401_200u128),
+                share: Decimal::one(),
+                timestamp: Timestamp::from_nanos(1572402329879305533u64),
+            },
+        );
+}
+
+#[test]
+fn test_weight_at_of_bonders_before_the_weight_history() {
+    let mut deps = mock_dependencies();
+    let config = Config {
+        owner: Addr::unchecked("owner"),
+        unbonding_period: Uint64::new(1_000_000_000_000u64),
+        growth_rate: Decimal::one(),
+        bonding_assets: vec![BondingAsset {
+            info: AssetInfo::NativeToken {
+                denom: "ampWHALE".to_string(),
+            },
+            weight_factor: Decimal::one(),
+            exchange_rate: Decimal::one(),
+            exchange_rate_oracle: None,
+        }],
+        instant_unbond_penalty: Decimal::one(),
+        penalty_recipient: PenaltyRecipient::Burn,
+        compound_config: None,
+    };
+    let bonder = Addr::unchecked("bonder");
+    let asset = Asset {
+        info: AssetInfo::NativeToken {
+            denom: "ampWHALE".to_string(),
+        },
+        amount: Uint128::new(1_000u128),
+    };
+
+    // the bond was made before the weight history started, without any snapshot
+    CONFIG.save(&mut deps.storage, &config).unwrap();
+    BOND.save(
+        &mut deps.storage,
+        (&bonder, "ampWHALE"),
+        &Bond {
+            asset: asset.clone(),
+            timestamp: Timestamp::from_seconds(100u64),
+            weight: Uint128::zero(),
+        },
+    )
+    .unwrap();
+    WEIGHT_HISTORY_START
+        .save(&mut deps.storage, &Timestamp::from_seconds(110u64))
+        .unwrap();
+
+    let weight_at = |deps: &OwnedDeps<_, _, _>, seconds: u64| {
+        get_weight_at(
+            &deps.storage,
+            &config,
+            &bonder,
+            Timestamp::from_seconds(seconds),
+        )
+        .unwrap()
+    };
+
+    // nothing is recorded before the weight history started
+    assert_eq!(weight_at(&deps, 105u64), Uint128::zero());
+    assert_eq!(weight_at(&deps, 120u64), Uint128::new(20_000u128));
+
+    // once the bond changes, the weight before the change is kept
+    let mut bond = BOND.load(&deps.storage, (&bonder, "ampWHALE")).unwrap();
+    bond = update_local_weight(
+        &mut deps.as_mut(),
+        bonder.clone(),
+        Timestamp::from_seconds(130u64),
+        bond,
+    )
+    .unwrap();
+    bond.asset.amount = bond.asset.amount.checked_add(asset.amount).unwrap();
+    BOND.save(&mut deps.storage, (&bonder, "ampWHALE"), &bond)
+        .unwrap();
+    snapshot_weight(&mut deps.storage, &bonder, Timestamp::from_seconds(130u64)).unwrap();
+
+    assert_eq!(weight_at(&deps, 105u64), Uint128::zero());
+    assert_eq!(weight_at(&deps, 120u64), Uint128::new(20_000u128));
+    assert_eq!(weight_at(&deps, 140u64), Uint128::new(50_000u128));
+}
@@ -1,9 +1,10 @@
-use cosmwasm_std::{coins, Event, Uint128};
+use cosmwasm_std::{coins, Event, Timestamp, Uint128};
 
 use white_whale::pool_network::asset::{Asset, AssetInfo};
-use white_whale::whale_lair::WithdrawableResponse;
+use white_whale::whale_lair::{Bond, UnbondingResponse, WithdrawableResponse};
 
 use crate::tests::robot::TestingRobot;
+use crate::ContractError;
 
 #[test]
 fn test_withdraw_successfully() {
@@ -50,7 +51,7 @@ fn test_withdraw_successfully() {
                 withdrawable_amount: Uint128::zero(),
             },
         )
-        .withdraw(sender.clone(), "ampWHALE".to_string(), |res| {
+        .withdraw(sender.clone(), "ampWHALE".to_string(), None, |res| {
             let events = res.unwrap().events;
             let transfer_event = events.last().unwrap().clone();
             assert_eq!(
@@ -72,7 +73,7 @@ fn test_withdraw_unsuccessfully() {
 
     robot
         .instantiate_default()
-        .withdraw(sender.clone(), "ampWHALE".to_string(), |res| {
+        .withdraw(sender.clone(), "ampWHALE".to_string(), None, |res| {
             println!("{:?}", res.unwrap_err().root_cause());
             //assert error is NothingToWithdraw
         })
@@ -98,22 +99,153 @@ fn test_withdraw_unsuccessfully() {
             },
             |_res| {},
         )
-        .withdraw(sender.clone(), "ampWHALE".to_string(), |res| {
+        .withdraw(sender.clone(), "ampWHALE".to_string(), None, |res| {
             println!("{:?}", res.unwrap_err().root_cause());
             //assert error is NothingToWithdraw
         })
         .fast_forward(999u64) //unbonding period is 1000
-        .withdraw(sender.clone(), "ampWHALE".to_string(), |res| {
+        .withdraw(sender.clone(), "ampWHALE".to_string(), None, |res| {
             println!("{:?}", res.unwrap_err().root_cause());
             //assert error is NothingToWithdraw
         })
         .fast_forward(999u64) //unbonding period is 1000
-        .withdraw(sender.clone(), "bWHALE".to_string(), |res| {
+        .withdraw(sender.clone(), "bWHALE".to_string(), None, |res| {
             println!("{:?}", res.unwrap_err().root_cause());
             //assert error is NothingToWithdraw
         })
-        .withdraw(another_sender, "ampWHALE".to_string(), |res| {
+        .withdraw(another_sender, "ampWHALE".to_string(), None, |res| {
             println!("{:?}", res.unwrap_err().root_cause());
             //assert error is NothingToWithdraw
         });
 }
+
+#[test]
+fn test_withdraw_many_unbondings_in_batches() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot.instantiate_default().bond(
+        sender.clone(),
+        Asset {
+            info: AssetInfo::NativeToken {
+                denom: "ampWHALE".to_string(),
+            },
+            amount: Uint128::new(1_000u128),
+        },
+        &coins(1_000u128, "ampWHALE"),
+        |_res| {},
+    );
+
+    // more unbondings than the max page limit
+    for _ in 0..35 {
+        robot.fast_forward(1u64).unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(10u128),
+            },
+            |res| {
+                res.unwrap();
+            },
+        );
+    }
+
+    robot
+        .fast_forward(1_000u64)
+        // the unbondings made within the same second are merged
+        .unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(10u128),
+            },
+            |_res| {},
+        )
+        .unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(20u128),
+            },
+            |_res| {},
+        )
+        .query_unbonding(
+            sender.to_string(),
+            "ampWHALE".to_string(),
+            Some(1571797454u64),
+            None,
+            |res| {
+                let unbonding_response = res.unwrap().1;
+                assert_eq!(unbonding_response.unbonding_requests.len(), 1);
+                assert_eq!(unbonding_response.total_amount, Uint128::new(30u128));
+            },
+        )
+        .assert_withdrawable_response(
+            sender.to_string(),
+            "ampWHALE".to_string(),
+            WithdrawableResponse {
+                withdrawable_amount: Uint128::new(350u128),
+            },
+        )
+        .withdraw(sender.clone(), "ampWHALE".to_string(), Some(10u8), |res| {
+            let events = res.unwrap().events;
+            let transfer_event = events.last().unwrap().clone();
+            assert_eq!(
+                transfer_event,
+                Event::new("transfer").add_attributes(vec![
+                    ("recipient", sender.to_string()),
+                    ("sender", "contract0".to_string()),
+                    ("amount", "100ampWHALE".to_string()),
+                ])
+            );
+        })
+        .assert_withdrawable_response(
+            sender.to_string(),
+            "ampWHALE".to_string(),
+            WithdrawableResponse {
+                withdrawable_amount: Uint128::new(250u128),
+            },
+        )
+        .withdraw(sender.clone(), "ampWHALE".to_string(), None, |res| {
+            let events = res.unwrap().events;
+            let transfer_event = events.last().unwrap().clone();
+            assert_eq!(
+                transfer_event,
+                Event::new("transfer").add_attributes(vec![
+                    ("recipient", sender.to_string()),
+                    ("sender", "contract0".to_string()),
+                    ("amount", "250ampWHALE".to_string()),
+                ])
+            );
+        })
+        // the unbonding that hasn't matured yet can't be withdrawn
+        .withdraw(sender.clone(), "ampWHALE".to_string(), None, |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::NothingToWithdraw {}.to_string()
+            );
+        })
+        .assert_unbonding_response(
+            sender.to_string(),
+            "ampWHALE".to_string(),
+            UnbondingResponse {
+                total_amount: Uint128::new(30u128),
+                unbonding_requests: vec![Bond {
+                    asset: Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "ampWHALE".to_string(),
+                        },
+                        amount: Uint128::new(30u128),
+                    },
+                    timestamp: Timestamp::from_nanos(1571798454879305533u64),
+                    weight: Uint128::zero(),
+                }],
+            },
+        );
+}
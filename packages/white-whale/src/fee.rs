@@ -24,6 +24,11 @@ impl Fee {
         }
         Ok(())
     }
+
+    /// Checks whether the [Fee] is within the given inclusive bounds
+    pub fn is_within(&self, min: &Fee, max: &Fee) -> bool {
+        min.share <= self.share && self.share <= max.share
+    }
 }
 
 /// Fees used by the flashloan vaults on the liquidity hub
@@ -104,6 +109,27 @@ mod tests {
         assert_eq!(fee.is_valid(), Err(StdError::generic_err("Invalid fee")));
     }
 
+    #[test]
+    fn fee_within_bounds() {
+        let min = Fee {
+            share: Decimal::percent(1),
+        };
+        let max = Fee {
+            share: Decimal::percent(5),
+        };
+
+        assert!(min.is_within(&min, &max));
+        assert!(max.is_within(&min, &max));
+        assert!(!Fee {
+            share: Decimal::zero()
+        }
+        .is_within(&min, &max));
+        assert!(!Fee {
+            share: Decimal::percent(6)
+        }
+        .is_within(&min, &max));
+    }
+
     #[test]
     fn vault_fee() {
         let vault_fee = VaultFee {
@@ -17,9 +17,10 @@ pub enum ExecuteMsg {
         aggregate_fees_for: FeesFor,
     },
     /// Forward fees to the fee distributor. This will collect and aggregate the fees, to send them back to the fee distributor.
+    /// Fees in any of the given assets are forwarded as they are, the rest are swapped to the first one.
     ForwardFees {
         epoch: Epoch,
-        forward_fees_as: AssetInfo,
+        forward_fees_as: Vec<AssetInfo>,
     },
     /// Updates the config
     UpdateConfig {
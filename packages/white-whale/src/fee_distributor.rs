@@ -1,6 +1,7 @@
 use crate::pool_network::asset::{Asset, AssetInfo};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Timestamp, Uint64};
+use cw20::Cw20ReceiveMsg;
 use std::fmt;
 use std::fmt::Display;
 
@@ -11,7 +12,9 @@ pub struct Config {
     pub fee_collector_addr: Addr,
     pub grace_period: Uint64,
     pub epoch_config: EpochConfig,
-    pub distribution_asset: AssetInfo,
+    pub distribution_assets: Vec<AssetInfo>,
+    /// The addresses that can fund epochs. Anyone can fund epochs if empty.
+    pub funding_whitelist: Vec<Addr>,
 }
 
 #[cw_serde]
@@ -68,19 +71,30 @@ pub struct InstantiateMsg {
     pub grace_period: Uint64,
     /// Configuration for the epoch.
     pub epoch_config: EpochConfig,
-    /// The asset that is going to be distributed by the contracdt.
-    pub distribution_asset: AssetInfo,
+    /// The assets that are going to be distributed by the contract. Fees in other assets are
+    /// swapped to the first one.
+    pub distribution_assets: Vec<AssetInfo>,
+    /// The addresses that can fund epochs. Anyone can fund epochs if empty.
+    pub funding_whitelist: Vec<String>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
+    /// Used to fund epochs with cw20 tokens via the [Cw20HookMsg] messages.
+    Receive(Cw20ReceiveMsg),
+
     /// Creates a new epoch, forwarding available tokens from epochs that are past the grace period.
     /// Can only be executed by the fee collector.
     NewEpoch {},
 
     /// Claims tokens from the current epoch and all epochs that are in the grace period.
-    /// Sends all tokens to the sender.
-    Claim {},
+    /// Each epoch is shared by the bonding weights recorded when it was created, which include the
+    /// weight delegated to each address at the time.
+    /// Sends all tokens to the sender, or to the delegate if the claim rights were delegated in the
+    /// bonding contract. A delegate can claim on behalf of a delegator by providing its address.
+    /// The bonding contract can claim on behalf of any bonder to auto-compound its rewards, in
+    /// which case the tokens are sent to the bonding contract.
+    Claim { delegator: Option<String> },
 
     /// Updates the [Config] of the contract.
     UpdateConfig {
@@ -88,8 +102,21 @@ pub enum ExecuteMsg {
         bonding_contract_addr: Option<String>,
         fee_collector_addr: Option<String>,
         grace_period: Option<Uint64>,
-        distribution_asset: Option<AssetInfo>,
+        distribution_assets: Option<Vec<AssetInfo>>,
+        funding_whitelist: Option<Vec<String>>,
     },
+
+    /// Adds the native tokens sent to the fees of the given epoch, or of the current one if none is
+    /// provided. Only the current epoch and the next 30 ones can be funded, with distribution
+    /// assets. The current epoch can only be funded until it's claimed for the first time.
+    FundEpoch { epoch_id: Option<Uint64> },
+}
+
+#[cw_serde]
+pub enum Cw20HookMsg {
+    /// Adds the cw20 tokens sent to the fees of the given epoch, or of the current one if none is
+    /// provided.
+    FundEpoch { epoch_id: Option<Uint64> },
 }
 
 #[cw_serde]
@@ -114,6 +141,10 @@ pub enum QueryMsg {
     /// Returns the [Epoch]s that can be claimed by an address.
     #[returns(ClaimableEpochsResponse)]
     Claimable { address: String },
+
+    /// Returns the funds sponsored for the epoch with the given id, which are part of its fees.
+    #[returns(SponsoredFundsResponse)]
+    SponsoredFunds { epoch_id: Uint64 },
 }
 
 #[cw_serde]
@@ -126,5 +157,11 @@ pub struct ClaimableEpochsResponse {
     pub epochs: Vec<Epoch>,
 }
 
+#[cw_serde]
+pub struct SponsoredFundsResponse {
+    pub epoch_id: Uint64,
+    pub funds: Vec<Asset>,
+}
+
 #[cw_serde]
 pub struct MigrateMsg {}
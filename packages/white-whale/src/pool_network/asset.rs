@@ -3,8 +3,8 @@ use std::fmt::{Display, Formatter};
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    coins, to_binary, Addr, Api, BankMsg, CanonicalAddr, Coin, CosmosMsg, Deps, MessageInfo,
-    QuerierWrapper, StdError, StdResult, SubMsg, Uint128, WasmMsg,
+    coins, to_binary, Addr, Api, BankMsg, BlockInfo, CanonicalAddr, Coin, CosmosMsg, Deps,
+    MessageInfo, QuerierWrapper, StdError, StdResult, SubMsg, Timestamp, Uint128, WasmMsg,
 };
 use cw20::Cw20ExecuteMsg;
 
@@ -428,6 +428,8 @@ pub struct PairInfo {
     pub liquidity_token: AssetInfo,
     pub asset_decimals: [u8; 2],
     pub pair_type: PairType,
+    /// The block the pair was created at. `None` for the pairs created before it was recorded.
+    pub created_at: Option<CreationBlock>,
 }
 
 #[cw_serde]
@@ -437,6 +439,23 @@ pub struct PairInfoRaw {
     pub liquidity_token: AssetInfoRaw,
     pub asset_decimals: [u8; 2],
     pub pair_type: PairType,
+    pub created_at: Option<CreationBlock>,
+}
+
+/// The height and time of the block something was created at
+#[cw_serde]
+pub struct CreationBlock {
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+impl From<&BlockInfo> for CreationBlock {
+    fn from(block: &BlockInfo) -> Self {
+        CreationBlock {
+            height: block.height,
+            time: block.time,
+        }
+    }
 }
 
 impl PairInfoRaw {
@@ -450,6 +469,7 @@ impl PairInfoRaw {
             ],
             asset_decimals: self.asset_decimals,
             pair_type: self.pair_type.to_owned(),
+            created_at: self.created_at.to_owned(),
         })
     }
 
@@ -1,6 +1,6 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 
-use crate::pool_network::asset::{AssetInfo, PairInfo, PairType};
+use crate::pool_network::asset::{Asset, AssetInfo, PairInfo, PairType};
 use crate::pool_network::pair::{FeatureToggle, PoolFee};
 
 #[cw_serde]
@@ -28,11 +28,14 @@ pub enum ExecuteMsg {
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
     },
-    /// Instantiates pair contract
+    /// Instantiates pair contract. Anyone can create a pair once a pair creation fee is set, in
+    /// which case the fee has to be paid, the pair type must be allowed and the pool fees must be
+    /// within the factory's pool fee bounds.
     CreatePair {
         /// Asset infos
         asset_infos: [AssetInfo; 2],
-        pool_fees: PoolFee,
+        /// The fees of the pool. If not provided, the default pool fees for the pair type are used.
+        pool_fees: Option<PoolFee>,
         /// The variant of pair to create
         pair_type: PairType,
         /// If true, the pair will use the token factory to create the LP token. If false, it will
@@ -41,13 +44,60 @@ pub enum ExecuteMsg {
     },
     /// Adds native token info to the contract so it can instantiate pair contracts that include it
     AddNativeTokenDecimals { denom: String, decimals: u8 },
-    /// Migrates a pair contract to a given code_id
+    /// Migrates a pair contract to a given code_id. The liquidity token of the pair is updated once
+    /// it's migrated, as it can change when migrating.
     MigratePair {
         contract: String,
         code_id: Option<u64>,
     },
-    /// Removes pair contract given asset infos
+    /// Migrates the pairs created by the factory to a given code_id, one page at a time. Pages are
+    /// the same as the ones of [QueryMsg::Pairs], and the pairs already on the code_id are skipped.
+    /// If no code_id is provided, the pair code_id of the factory is used.
+    /// The pairs created before v1.3.0 are indexed by asset and pair type, and get their code_id
+    /// recorded, as their pages are scanned. The asset infos of the last pair scanned are returned
+    /// as data, to be passed as `start_after` for the next page.
+    MigratePairs {
+        code_id: Option<u64>,
+        start_after: Option<[AssetInfo; 2]>,
+        limit: Option<u32>,
+    },
+    /// Deprecates the pair with the given asset infos, disabling deposits and swaps on it while
+    /// keeping withdrawals open so liquidity providers can exit. This is the first step for
+    /// removing a pair, see [ExecuteMsg::RemovePair].
+    DeprecatePair { asset_infos: [AssetInfo; 2] },
+    /// Removes pair contract given asset infos. The pair must have been deprecated first, i.e. its
+    /// deposits and swaps must be disabled. The removed pair is recorded as deprecated, and can be
+    /// re-added with [ExecuteMsg::RegisterExistingPair].
     RemovePair { asset_infos: [AssetInfo; 2] },
+    /// Registers an existing pair contract on the factory, e.g. a pair that was removed from it.
+    /// A removed pair gets its deposits and swaps enabled again.
+    RegisterExistingPair { pair_address: String },
+    /// Migrates the cw20 LP token of the pair with the given asset infos to a token factory LP,
+    /// updating the liquidity token of the pair on the factory accordingly
+    MigrateToTokenFactoryLp { asset_infos: [AssetInfo; 2] },
+    /// Sets the default pool fees of the pairs of the given type, used when creating a pair without
+    /// specifying its fees. StableSwap pairs share their defaults regardless of their amplification.
+    /// If `None`, the default pool fees of the pair type are removed.
+    UpdateDefaultPoolFees {
+        pair_type: PairType,
+        pool_fees: Option<PoolFee>,
+    },
+    /// Updates the pool fees of the pairs matching the filter, one page at a time. Pages are the
+    /// ones of [QueryMsg::PairsByAsset] if an asset is given in the filter, [QueryMsg::PairsByType]
+    /// if only a pair type is, and [QueryMsg::Pairs] otherwise.
+    BulkUpdatePoolFees {
+        filter: PairsFilter,
+        pool_fees: PoolFee,
+        start_after: Option<[AssetInfo; 2]>,
+        limit: Option<u32>,
+    },
+    /// Updates the settings for permissionless pair creation. Unlike
+    /// [ExecuteMsg::UpdateConfig], the fields that are not specified are removed.
+    UpdatePairCreationConfig {
+        pair_creation_fee: Option<Asset>,
+        pool_fee_bounds: Option<PoolFeeBounds>,
+        allowed_pair_types: Option<Vec<PairType>>,
+    },
 }
 
 #[cw_serde]
@@ -67,6 +117,40 @@ pub enum QueryMsg {
         start_after: Option<[AssetInfo; 2]>,
         limit: Option<u32>,
     },
+    /// Retrieves the pairs containing the given asset, with the same pagination as
+    /// [QueryMsg::Pairs].
+    #[returns(PairsResponse)]
+    PairsByAsset {
+        asset_info: AssetInfo,
+        start_after: Option<[AssetInfo; 2]>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the pairs of the given type, with the same pagination as [QueryMsg::Pairs].
+    /// StableSwap pairs are matched regardless of their amplification.
+    #[returns(PairsResponse)]
+    PairsByType {
+        pair_type: PairType,
+        start_after: Option<[AssetInfo; 2]>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the code_id each pair created by the factory is running, with the same pagination
+    /// as [QueryMsg::Pairs]. If `outdated_only` is true, only the pairs that are not on the pair
+    /// code_id of the factory are returned.
+    #[returns(PairCodeIdsResponse)]
+    PairCodeIds {
+        start_after: Option<[AssetInfo; 2]>,
+        limit: Option<u32>,
+        outdated_only: Option<bool>,
+    },
+    /// Retrieves the pairs removed from the factory, paginated by pair contract address.
+    #[returns(PairsResponse)]
+    DeprecatedPairs {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the default pool fees of each pair type.
+    #[returns(DefaultPoolFeesResponse)]
+    DefaultPoolFees {},
     /// Retrieves the decimals for the given native or ibc denom.
     #[returns(NativeTokenDecimalsResponse)]
     NativeTokenDecimals { denom: String },
@@ -79,6 +163,40 @@ pub struct ConfigResponse {
     pub fee_collector_addr: String,
     pub pair_code_id: u64,
     pub token_code_id: u64,
+    pub pair_creation_fee: Option<Asset>,
+    pub pool_fee_bounds: Option<PoolFeeBounds>,
+    pub allowed_pair_types: Option<Vec<PairType>>,
+}
+
+/// Filter for the pairs to update in bulk
+#[cw_serde]
+pub struct PairsFilter {
+    /// Only match the pairs containing the given asset
+    pub asset_info: Option<AssetInfo>,
+    /// Only match the pairs of the given type
+    pub pair_type: Option<PairType>,
+}
+
+/// Inclusive bounds for each of the fees of a pool
+#[cw_serde]
+pub struct PoolFeeBounds {
+    pub min: PoolFee,
+    pub max: PoolFee,
+}
+
+impl PoolFeeBounds {
+    /// Checks whether each of the given fees is within the bounds
+    pub fn contains(&self, pool_fees: &PoolFee) -> bool {
+        pool_fees
+            .protocol_fee
+            .is_within(&self.min.protocol_fee, &self.max.protocol_fee)
+            && pool_fees
+                .swap_fee
+                .is_within(&self.min.swap_fee, &self.max.swap_fee)
+            && pool_fees
+                .burn_fee
+                .is_within(&self.min.burn_fee, &self.max.burn_fee)
+    }
 }
 
 /// We currently take no arguments for migrations
@@ -91,6 +209,33 @@ pub struct PairsResponse {
     pub pairs: Vec<PairInfo>,
 }
 
+#[cw_serde]
+pub struct PairCodeIdsResponse {
+    /// The pair code_id of the factory
+    pub pair_code_id: u64,
+    pub pairs: Vec<PairCodeId>,
+}
+
+#[cw_serde]
+pub struct PairCodeId {
+    pub asset_infos: [AssetInfo; 2],
+    pub contract_addr: String,
+    /// The code_id the pair is running. `None` if it's unknown to the factory.
+    pub code_id: Option<u64>,
+}
+
+#[cw_serde]
+pub struct DefaultPoolFeesResponse {
+    pub default_pool_fees: Vec<DefaultPoolFees>,
+}
+
+#[cw_serde]
+pub struct DefaultPoolFees {
+    /// The label of the pair type, i.e. `ConstantProduct` or `StableSwap`
+    pub pair_type: String,
+    pub pool_fees: PoolFee,
+}
+
 #[cw_serde]
 pub struct NativeTokenDecimalsResponse {
     pub decimals: u8,
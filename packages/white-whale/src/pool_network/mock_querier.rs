@@ -5,15 +5,19 @@ use std::panic;
 
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    from_binary, from_slice, to_binary, Coin, ContractInfoResponse, ContractResult, Empty,
-    OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
+    from_binary, from_slice, to_binary, Addr, Coin, ContractInfoResponse, ContractResult, Decimal,
+    Empty, OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError, SystemResult, Uint128,
+    WasmQuery,
 };
 use cw20::{BalanceResponse as Cw20BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
 
+use crate::fee::Fee;
 use crate::pool_network::asset::{AssetInfo, PairInfo, PairType};
 use crate::pool_network::factory::{NativeTokenDecimalsResponse, QueryMsg as FactoryQueryMsg};
 use crate::pool_network::pair::QueryMsg as PairQueryMsg;
-use crate::pool_network::pair::{ReverseSimulationResponse, SimulationResponse};
+use crate::pool_network::pair::{
+    Config as PairConfig, FeatureToggle, PoolFee, ReverseSimulationResponse, SimulationResponse,
+};
 
 /// mock_dependencies is a drop-in replacement for cosmwasm_std::testing::mock_dependencies
 /// this uses our CustomQuerier.
@@ -35,6 +39,7 @@ pub struct WasmMockQuerier {
     base: MockQuerier,
     token_querier: TokenQuerier,
     pool_factory_querier: PoolFactoryQuerier,
+    pair_feature_toggles: HashMap<String, FeatureToggle>,
 }
 
 #[derive(Clone, Default)]
@@ -168,6 +173,35 @@ impl WasmMockQuerier {
                                     contract_addr: "liquidity0000".to_string(),
                                 },
                                 pair_type: PairType::ConstantProduct,
+                                created_at: None,
+                            })))
+                        }
+                        Ok(PairQueryMsg::Config {}) => {
+                            let feature_toggle = self
+                                .pair_feature_toggles
+                                .get(contract_addr)
+                                .cloned()
+                                .unwrap_or(FeatureToggle {
+                                    withdrawals_enabled: true,
+                                    deposits_enabled: true,
+                                    swaps_enabled: true,
+                                });
+
+                            SystemResult::Ok(ContractResult::from(to_binary(&PairConfig {
+                                owner: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                                fee_collector_addr: Addr::unchecked("collector"),
+                                pool_fees: PoolFee {
+                                    protocol_fee: Fee {
+                                        share: Decimal::zero(),
+                                    },
+                                    swap_fee: Fee {
+                                        share: Decimal::zero(),
+                                    },
+                                    burn_fee: Fee {
+                                        share: Decimal::zero(),
+                                    },
+                                },
+                                feature_toggle,
                             })))
                         }
                         Ok(PairQueryMsg::Simulation { offer_asset }) => {
@@ -274,6 +308,7 @@ impl WasmMockQuerier {
             base,
             token_querier: TokenQuerier::default(),
             pool_factory_querier: PoolFactoryQuerier::default(),
+            pair_feature_toggles: HashMap::new(),
         }
     }
 
@@ -291,6 +326,12 @@ impl WasmMockQuerier {
         self.pool_factory_querier = PoolFactoryQuerier::new(pairs, native_token_decimals);
     }
 
+    // configure the feature toggle of a pair, all features are enabled by default
+    pub fn with_pair_feature_toggle(&mut self, pair_addr: &str, feature_toggle: FeatureToggle) {
+        self.pair_feature_toggles
+            .insert(pair_addr.to_string(), feature_toggle);
+    }
+
     pub fn with_balance(&mut self, balances: &[(&String, Vec<Coin>)]) {
         for (addr, balance) in balances {
             self.base.update_balance(addr.to_string(), balance.clone());
@@ -49,6 +49,11 @@ pub enum ExecuteMsg {
     },
     /// Collects the Protocol fees accrued by the pool
     CollectProtocolFees {},
+    /// Migrates the LP of a pair using a cw20 LP token to a token factory LP denom. The holders of
+    /// the cw20 LP token can then convert it 1:1 into the new denom with
+    /// [Cw20HookMsg::ConvertToTokenFactoryLp]. Only the owner can do this. Pairs still using a cw20
+    /// LP token are also migrated when the contract is migrated to v1.4.0.
+    MigrateToTokenFactoryLp {},
 }
 
 #[cw_serde]
@@ -59,8 +64,11 @@ pub enum Cw20HookMsg {
         max_spread: Option<Decimal>,
         to: Option<String>,
     },
-    /// Withdraws liquidity
+    /// Withdraws liquidity. Also accepted from the cw20 LP token a pair was migrated from, so its
+    /// holders can withdraw without converting it first.
     WithdrawLiquidity {},
+    /// Converts the cw20 LP tokens of a pair migrated to a token factory LP into the new denom, 1:1
+    ConvertToTokenFactoryLp {},
 }
 
 #[cw_serde]
@@ -114,7 +114,8 @@ pub enum QueryMsg {
         ask_amount: Uint128,
         operations: Vec<SwapOperation>,
     },
-    /// Gets the swap route for the given offer and ask assets.
+    /// Gets the swap route for the given offer and ask assets. Fails if the route goes through a
+    /// pair that was removed from the factory.
     #[returns(Vec<SwapOperation>)]
     SwapRoute {
         offer_asset_info: AssetInfo,
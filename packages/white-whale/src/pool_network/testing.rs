@@ -397,6 +397,7 @@ fn query_terraswap_pair_contract() {
                 },
                 asset_decimals: [6u8, 6u8],
                 pair_type: PairType::ConstantProduct,
+                created_at: None,
             },
         )],
         &[("uusd".to_string(), 6u8)],
@@ -1,7 +1,7 @@
 use crate::fee::VaultFee;
 use crate::pool_network::asset::{Asset, AssetInfo};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -24,12 +24,20 @@ pub enum CallbackMsg {
         old_balance: Uint128,
         loan_amount: Uint128,
     },
+    /// Checks the strategy returned the funds withdrawn from it, i.e. the vault balance is at least
+    /// `expected_balance`.
+    AfterStrategyWithdraw { expected_balance: Uint128 },
 }
 
 #[cw_serde]
 pub enum Cw20HookMsg {
     /// Withdraws a given amount from the vault.
-    Withdraw {},
+    Withdraw {
+        /// The address receiving the withdrawn assets. Defaults to the sender of the lp tokens.
+        receiver: Option<String>,
+        /// The minimum amount of assets to receive, otherwise the withdrawal fails.
+        min_asset_out: Option<Uint128>,
+    },
 }
 
 #[cw_serde]
@@ -60,6 +68,10 @@ pub enum ExecuteMsg {
     /// Deposits a given amount into the vault.
     Deposit {
         amount: Uint128,
+        /// The address receiving the minted lp tokens. Defaults to the sender.
+        receiver: Option<String>,
+        /// The minimum amount of lp tokens to mint, otherwise the deposit fails.
+        min_lp_out: Option<Uint128>,
     },
     /// Flash-loans a given amount from the vault.
     FlashLoan {
@@ -71,6 +83,14 @@ pub enum ExecuteMsg {
     /// Updates the configuration of the contract.
     /// If a field is not specified, it will not be modified.
     UpdateConfig(UpdateConfigParams),
+    /// Sets the strategy idle funds are deployed to. If `None`, the current strategy is removed.
+    /// Funds deployed to a strategy being replaced or removed are withdrawn back to the vault.
+    UpdateStrategy {
+        strategy: Option<StrategyParams>,
+    },
+    /// Deploys idle funds to, or withdraws funds from, the strategy so that the deployed amount
+    /// matches the configured `deploy_ratio` of the vault deposits. Can only be executed by the owner.
+    Rebalance {},
     Receive(Cw20ReceiveMsg),
     Callback(CallbackMsg),
 }
@@ -93,6 +113,18 @@ pub enum QueryMsg {
     /// Retrieves the [`Uint128`] amount that must be sent back to the contract to pay off a loan taken out.
     #[returns(PaybackAmountResponse)]
     GetPaybackAmount { amount: Uint128 },
+    /// Retrieves the [`Uint128`] amount of lp tokens that would be minted when depositing `amount` into the vault.
+    #[returns(Uint128)]
+    PreviewDeposit { amount: Uint128 },
+    /// Retrieves the [`Uint128`] amount of assets that would be returned when withdrawing `lp_amount` of lp tokens.
+    #[returns(Uint128)]
+    PreviewWithdraw { lp_amount: Uint128 },
+    /// Retrieves the amount of assets backing each lp token of the vault.
+    #[returns(ExchangeRateResponse)]
+    ExchangeRate {},
+    /// Retrieves the strategy of the vault, along with the amount of assets deployed to it.
+    #[returns(StrategyResponse)]
+    Strategy {},
 }
 
 #[cw_serde]
@@ -126,6 +158,65 @@ pub struct Config {
     pub fees: VaultFee,
 }
 
+#[cw_serde]
+pub struct StrategyParams {
+    /// The address of the strategy contract.
+    pub contract_addr: String,
+    /// The share of the vault deposits to deploy to the strategy. The rest is kept in the vault as
+    /// a liquid buffer for flash-loans and withdrawals.
+    pub deploy_ratio: Decimal,
+}
+
+#[cw_serde]
+pub struct Strategy {
+    /// The address of the strategy contract.
+    pub contract_addr: Addr,
+    /// The share of the vault deposits to deploy to the strategy.
+    pub deploy_ratio: Decimal,
+}
+
+#[cw_serde]
+pub struct StrategyResponse {
+    /// The strategy of the vault, if any.
+    pub strategy: Option<Strategy>,
+    /// The amount of assets the vault is entitled to in the strategy.
+    pub deployed_amount: Uint128,
+}
+
+/// The messages a strategy contract must handle. They are only sent by the vault that uses the strategy.
+#[cw_serde]
+pub enum StrategyExecuteMsg {
+    /// Deploys `amount` of the vault asset to the strategy. Native tokens are sent along with the
+    /// message, while cw20 tokens are transferred to the strategy right before it.
+    Deploy { amount: Uint128 },
+    /// Sends `amount` of the vault asset back to the vault. The funds must be returned within the
+    /// same transaction, as the vault uses them right away to pay out withdrawals and flash-loans,
+    /// and reverts otherwise. Thus, only liquid strategies are supported. A native staking strategy
+    /// has to serve withdrawals from a liquid reserve of its own, as undelegations are subject to an
+    /// unbonding period.
+    Withdraw { amount: Uint128 },
+}
+
+/// The queries a strategy contract must handle.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum StrategyQueryMsg {
+    /// Retrieves the [`Uint128`] amount of the vault asset the vault is entitled to in the strategy,
+    /// including any yield generated.
+    #[returns(Uint128)]
+    Value {},
+}
+
+#[cw_serde]
+pub struct ExchangeRateResponse {
+    /// The amount of assets held by the vault on behalf of the lp holders, excluding uncollected protocol fees.
+    pub total_deposits: Uint128,
+    /// The total supply of the lp token.
+    pub total_lp_supply: Uint128,
+    /// The amount of assets each lp token is entitled to. Defaults to 1 when no lp tokens have been minted.
+    pub exchange_rate: Decimal,
+}
+
 #[cw_serde]
 pub struct PaybackAmountResponse {
     /// The total amount that must be returned. Equivalent to `amount` + `protocol_fee` + `flash_loan_fee`+ `burn_fee`.
@@ -1,5 +1,5 @@
 use crate::fee::VaultFee;
-use crate::pool_network::asset::AssetInfo;
+use crate::pool_network::asset::{Asset, AssetInfo};
 use crate::vault_network::vault;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
@@ -21,10 +21,22 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Creates a new vault given the asset info the vault should manage deposits and withdrawals
-    /// for and the fees
+    /// for and the fees. If no fees are provided, the factory's default vault fees are used.
+    ///
+    /// Anyone can create a vault once a vault creation fee is set, in which case the fee has to be
+    /// paid and the vault fees must be within the factory's vault fee bounds.
     CreateVault {
         asset_info: AssetInfo,
-        fees: VaultFee,
+        fees: Option<VaultFee>,
+        metadata: Option<VaultMetadata>,
+    },
+    /// Creates multiple vaults at once. Only the owner can create vaults in batch.
+    CreateVaults { vaults: Vec<CreateVaultParams> },
+    /// Sets the metadata of the vault managing the given [AssetInfo]. If `None`, the metadata is
+    /// removed.
+    UpdateVaultMetadata {
+        asset_info: AssetInfo,
+        metadata: Option<VaultMetadata>,
     },
     /// Migrates vaults to the given code_id. If a [vault_addr] is provided, then migrates only that
     /// vault.
@@ -47,6 +59,13 @@ pub enum ExecuteMsg {
         vault_id: Option<u64>,
         token_id: Option<u64>,
     },
+    /// Updates the settings for the creation of vaults. Unlike [ExecuteMsg::UpdateConfig], the
+    /// fields that are not specified are removed.
+    UpdateVaultCreationConfig {
+        vault_creation_fee: Option<VaultCreationFee>,
+        default_vault_fees: Option<VaultFee>,
+        vault_fee_bounds: Option<VaultFeeBounds>,
+    },
 }
 
 /// The query message
@@ -59,14 +78,49 @@ pub enum QueryMsg {
     /// Retrieves the address of a given vault.
     #[returns(Option<String>)]
     Vault { asset_info: AssetInfo },
-    /// Retrieves the addresses for all the vaults.
+    /// Retrieves the addresses for all the vaults. If a `filter` is provided, only the vaults
+    /// matching it are returned.
     #[returns(VaultsResponse)]
     Vaults {
         start_after: Option<Vec<u8>>,
         limit: Option<u32>,
+        filter: Option<VaultsFilter>,
     },
 }
 
+/// The parameters to create a vault with
+#[cw_serde]
+pub struct CreateVaultParams {
+    pub asset_info: AssetInfo,
+    pub fees: Option<VaultFee>,
+    pub metadata: Option<VaultMetadata>,
+}
+
+/// Descriptive information about a vault, stored by the factory
+#[cw_serde]
+pub struct VaultMetadata {
+    /// The name of the vault to display in frontends
+    pub display_name: String,
+    /// A short description of the vault
+    pub description: Option<String>,
+    /// The risk tier of the vault
+    pub risk_tier: Option<RiskTier>,
+}
+
+#[cw_serde]
+pub enum RiskTier {
+    Low,
+    Medium,
+    High,
+}
+
+/// Filter for the vaults query
+#[cw_serde]
+pub struct VaultsFilter {
+    /// Only return the vaults with the given risk tier
+    pub risk_tier: Option<RiskTier>,
+}
+
 /// The migrate message
 #[cw_serde]
 pub struct MigrateMsg {}
@@ -80,6 +134,49 @@ pub struct Config {
     pub vault_id: u64,
     pub token_id: u64,
     pub fee_collector_addr: Addr,
+    /// The fee non-owners pay to create a vault. If `None`, vault creation is restricted to the
+    /// owner.
+    pub vault_creation_fee: Option<VaultCreationFee>,
+    /// The fees used by the vaults created without specifying any fees.
+    pub default_vault_fees: Option<VaultFee>,
+    /// The range each fee of a vault created by a non-owner must fall in.
+    pub vault_fee_bounds: Option<VaultFeeBounds>,
+}
+
+/// The fee to pay to create a vault, and what happens to it
+#[cw_serde]
+pub struct VaultCreationFee {
+    pub fee: Asset,
+    pub destination: CreationFeeDestination,
+}
+
+#[cw_serde]
+pub enum CreationFeeDestination {
+    /// The fee is sent to the fee collector
+    FeeCollector,
+    /// The fee is burned
+    Burn,
+}
+
+/// Inclusive bounds for each of the fees of a vault
+#[cw_serde]
+pub struct VaultFeeBounds {
+    pub min: VaultFee,
+    pub max: VaultFee,
+}
+
+impl VaultFeeBounds {
+    /// Checks whether each of the given fees is within the bounds
+    pub fn contains(&self, fees: &VaultFee) -> bool {
+        fees.protocol_fee
+            .is_within(&self.min.protocol_fee, &self.max.protocol_fee)
+            && fees
+                .flash_loan_fee
+                .is_within(&self.min.flash_loan_fee, &self.max.flash_loan_fee)
+            && fees
+                .burn_fee
+                .is_within(&self.min.burn_fee, &self.max.burn_fee)
+    }
 }
 
 /// Response for the vaults query
@@ -94,4 +191,5 @@ pub struct VaultInfo {
     pub vault: String,
     pub asset_info: AssetInfo,
     pub asset_info_reference: Vec<u8>,
+    pub metadata: Option<VaultMetadata>,
 }
@@ -1,6 +1,12 @@
 use crate::pool_network::asset::{Asset, AssetInfo};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128, Uint64};
+use cw2::ContractVersion;
+use cw20::Cw20ReceiveMsg;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::{Display, Formatter};
 
 #[cw_serde]
 pub struct Config {
@@ -11,8 +17,72 @@ pub struct Config {
     /// A fraction that controls the effect of time on the weight of a bond. If the growth rate is set
     /// to zero, time will have no impact on the weight.
     pub growth_rate: Decimal,
-    /// Denom of the asset to be bonded. Can't only be set at instantiation.
-    pub bonding_assets: Vec<AssetInfo>,
+    /// Assets that can be bonded, with their weight factors and exchange rates. Updated through
+    /// [ExecuteMsg::UpdateBondingAssets].
+    pub bonding_assets: Vec<BondingAsset>,
+    /// The fraction of the unbonded amount kept as a penalty when instant unbonding. Needs to be
+    /// between 0 and 1.
+    pub instant_unbond_penalty: Decimal,
+    /// Where the instant unbonding penalties go.
+    pub penalty_recipient: PenaltyRecipient,
+    /// The configuration used to auto-compound the rewards of the bonders who opted in. Set once the
+    /// fee distributor is instantiated.
+    pub compound_config: Option<CompoundConfig>,
+}
+
+/// The configuration used to auto-compound the rewards of the bonders who opted in.
+#[cw_serde]
+pub struct CompoundConfig {
+    /// The fee distributor the rewards are claimed from.
+    pub fee_distributor_addr: String,
+    /// The pool router used to swap the rewards to the first bonding asset, when they are not a
+    /// bonding asset already.
+    pub router_addr: String,
+    /// The fraction of the compounded rewards paid to the keeper triggering the compounding. Needs
+    /// to be between 0 and 1.
+    pub keeper_tip: Decimal,
+    /// The maximum spread accepted when swapping the rewards, i.e. how much less than the amount
+    /// simulated through the router the swap can return. Needs to be between 0 and 1.
+    pub max_spread: Decimal,
+    /// The addresses allowed to trigger the compounding. As the rewards are swapped at the price of
+    /// the pools at the time, only trusted keepers can compound so nobody can move the pools around
+    /// the swaps to take the rewards.
+    pub keepers: Vec<String>,
+}
+
+/// The destination of the instant unbonding penalties.
+#[cw_serde]
+pub enum PenaltyRecipient {
+    /// The penalty is burned.
+    Burn,
+    /// The penalty is sent to the fee collector. When the penalty is in the distribution asset of the
+    /// fee distributor, it's forwarded to the remaining bonders with the fees of the next epoch.
+    FeeCollector { address: String },
+}
+
+impl Display for PenaltyRecipient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PenaltyRecipient::Burn => write!(f, "burn"),
+            PenaltyRecipient::FeeCollector { address } => write!(f, "fee_collector:{address}"),
+        }
+    }
+}
+
+/// An asset that can be bonded in the contract.
+#[cw_serde]
+pub struct BondingAsset {
+    /// The [AssetInfo] of the asset, either a native denom or a cw20 token such as an LP token.
+    pub info: AssetInfo,
+    /// The factor applied to the bonded amount of the asset when computing its weight, i.e. a bond of
+    /// an asset with a weight factor of 2 grows twice as fast as the same bond with a factor of 1.
+    pub weight_factor: Decimal,
+    /// The amount of the underlying token, i.e. WHALE, one unit of the asset is worth. Applied along
+    /// with the weight factor, so liquid staking derivatives are weighted by their underlying value.
+    pub exchange_rate: Decimal,
+    /// The liquid staking contract the exchange rate of the asset is queried from, if any. When
+    /// set, the exchange rate is refreshed from it through [ExecuteMsg::UpdateExchangeRates].
+    pub exchange_rate_oracle: Option<String>,
 }
 
 #[cw_serde]
@@ -40,6 +110,56 @@ impl Default for Bond {
     }
 }
 
+/// A bond locked for a fixed duration, which can't be unbonded before its unlock time. The weight
+/// of a locked bond grows faster than a regular one, boosted by a multiplier that depends on the
+/// lock duration.
+#[cw_serde]
+pub struct LockedBond {
+    /// The id of the lock.
+    pub id: u64,
+    /// The amount of locked tokens.
+    pub asset: Asset,
+    /// The timestamp at which the weight of the lock was last updated.
+    pub timestamp: Timestamp,
+    /// The weight of the lock at the given timestamp.
+    pub weight: Uint128,
+    /// The multiplier applied to the weight growth of the lock until its unlock time.
+    pub multiplier: Decimal,
+    /// The timestamp after which the lock can be unlocked.
+    pub unlock_time: Timestamp,
+}
+
+/// What a bonder delegates to another address.
+#[cw_serde]
+pub enum DelegationType {
+    /// The whole bonding weight of the delegator, which is then reported as the delegate's weight,
+    /// including by the past weight and voting power queries. The delegate earns the rewards of
+    /// that weight as its own.
+    Weight,
+    /// Only the right to claim the rewards of the delegator, which keeps its weight but gets its
+    /// rewards paid to the delegate.
+    Claim,
+}
+
+impl Display for DelegationType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DelegationType::Weight => write!(f, "weight"),
+            DelegationType::Claim => write!(f, "claim"),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct Delegation {
+    /// The address delegating.
+    pub delegator: Addr,
+    /// The address delegated to.
+    pub delegate: Addr,
+    /// What is being delegated.
+    pub delegation_type: DelegationType,
+}
+
 #[cw_serde]
 #[derive(Default)]
 pub struct GlobalIndex {
@@ -59,26 +179,102 @@ pub struct InstantiateMsg {
     pub unbonding_period: Uint64,
     /// Weight grow rate. Needs to be between 0 and 1.
     pub growth_rate: Decimal,
-    /// [AssetInfo] of the assets that can be bonded.
-    pub bonding_assets: Vec<AssetInfo>,
+    /// The assets that can be bonded, with their weight factors.
+    pub bonding_assets: Vec<BondingAsset>,
+    /// The fraction of the unbonded amount kept as a penalty when instant unbonding. Needs to be
+    /// between 0 and 1.
+    pub instant_unbond_penalty: Decimal,
+    /// Where the instant unbonding penalties go.
+    pub penalty_recipient: PenaltyRecipient,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
+    /// Used to bond cw20 tokens via the [Cw20HookMsg] messages.
+    Receive(Cw20ReceiveMsg),
     /// Bonds the specified [Asset].
     Bond { asset: Asset },
+    /// Bonds the specified [Asset], locking it for the given duration in nanoseconds in exchange
+    /// for a weight multiplier.
+    BondLocked { asset: Asset, duration: Uint64 },
     /// Unbonds the specified [Asset].
     Unbond { asset: Asset },
-    /// Sends withdrawable unbonded tokens to the user.
-    Withdraw { denom: String },
+    /// Unbonds the specified [Asset] without waiting for the unbonding period, paying out the
+    /// amount minus the instant unbonding penalty right away.
+    InstantUnbond { asset: Asset },
+    /// Moves pending unbondings of the given denom back into the bond. The unbondings are
+    /// identified by their timestamp in seconds, all of them are rebonded if none is provided.
+    /// The rebonded tokens start accruing weight from scratch.
+    Rebond {
+        denom: String,
+        unbonding_timestamps: Option<Vec<u64>>,
+    },
+    /// Turns an expired lock into a regular bond, keeping the weight accrued by the lock.
+    Unlock { lock_id: u64 },
+    /// Delegates the bonding weight or the reward-claim rights of the sender to the given address,
+    /// replacing any previous delegation. Only bonders can delegate, and an address can receive a
+    /// limited amount of delegations.
+    Delegate {
+        delegate: String,
+        delegation_type: DelegationType,
+    },
+    /// Removes the delegation of the sender.
+    Undelegate {},
+    /// Sends withdrawable unbonded tokens to the user, oldest first. For cw20 tokens, the denom is
+    /// the token contract address. The limit caps the number of unbondings withdrawn at once,
+    /// all of them are withdrawn if none is provided.
+    Withdraw { denom: String, limit: Option<u8> },
+    /// Opts the sender in or out of the auto-compounding of its rewards.
+    SetAutoCompound { enabled: bool },
+    /// Claims the rewards of the given addresses that opted in to auto-compounding and bonds them
+    /// again on their behalf, swapping them to a bonding asset first if needed. The sender gets the
+    /// keeper tip out of the compounded rewards. Only the keepers of the [CompoundConfig] can do
+    /// this.
+    Compound { addresses: Vec<String> },
+    /// Refreshes the exchange rates of the bonding assets that have an exchange rate oracle. The new
+    /// rates apply to the weight accrued from then on.
+    UpdateExchangeRates {},
+    /// Adds new bonding assets, or updates the weight factors, exchange rates and exchange rate
+    /// oracles of the existing ones. The exchange rate is queried from the oracle when one is set.
+    /// The new weight factors and exchange rates apply to the weight accrued from then on. Only the
+    /// owner can do this.
+    UpdateBondingAssets { bonding_assets: Vec<BondingAsset> },
     /// Updates the [Config] of the contract.
     UpdateConfig {
         owner: Option<String>,
         unbonding_period: Option<Uint64>,
         growth_rate: Option<Decimal>,
+        instant_unbond_penalty: Option<Decimal>,
+        penalty_recipient: Option<PenaltyRecipient>,
+        compound_config: Option<CompoundConfig>,
+    },
+    /// Callback messages, only callable by the contract itself.
+    Callback(CallbackMsg),
+}
+
+/// The callback messages available. Only callable by the whale lair contract itself.
+#[cw_serde]
+pub enum CallbackMsg {
+    /// Claims the rewards of the given address from the fee distributor, to compound them.
+    ClaimRewards { address: Addr, keeper: Addr },
+    /// Compounds the rewards received since the balance of the asset was `prev_balance`, swapping
+    /// them first if the asset is not a bonding asset.
+    CompoundRewards {
+        address: Addr,
+        keeper: Addr,
+        asset_info: AssetInfo,
+        prev_balance: Uint128,
     },
 }
 
+#[cw_serde]
+pub enum Cw20HookMsg {
+    /// Bonds the cw20 tokens sent.
+    Bond {},
+    /// Bonds the cw20 tokens sent, locking them for the given duration in nanoseconds.
+    BondLocked { duration: Uint64 },
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -105,13 +301,75 @@ pub enum QueryMsg {
     #[returns(WithdrawableResponse)]
     Withdrawable { address: String, denom: String },
 
-    /// Returns the weight of the address.
+    /// Returns the locked bonds of the specified address. Allows pagination with start_after and
+    /// limit, using the lock id.
+    #[returns(LocksResponse)]
+    Locks {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+
+    /// Returns the delegation of the given delegator, if any.
+    #[returns(DelegationResponse)]
+    Delegation { delegator: String },
+
+    /// Returns the delegations made to the given delegate. Allows pagination with start_after and
+    /// limit, using the delegator address.
+    #[returns(DelegationsResponse)]
+    Delegations {
+        delegate: String,
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+
+    /// Returns the weight of the address, including the boosted weight of its locked bonds and the
+    /// weight delegated to it. The weight of an address that delegated its weight is zero.
     #[returns(BondingWeightResponse)]
     Weight { address: String },
 
+    /// Returns the weight of the address at the given past timestamp, including the boosted weight
+    /// of its locked bonds and the weight delegated to it at the time.
+    #[returns(BondingWeightResponse)]
+    WeightAt {
+        address: String,
+        timestamp: Timestamp,
+    },
+
+    /// Returns the global weight at the given past timestamp.
+    #[returns(TotalWeightResponse)]
+    TotalWeightAt { timestamp: Timestamp },
+
     /// Returns the total amount of assets that have been bonded to the contract.
     #[returns(BondedResponse)]
     TotalBonded {},
+
+    /// Returns the total amount bonded of each bonding asset, along with its value in the underlying
+    /// token.
+    #[returns(TotalBondedByDenomResponse)]
+    TotalBondedByDenom {},
+
+    /// Returns whether the address opted in to auto-compounding.
+    #[returns(AutoCompoundResponse)]
+    AutoCompound { address: String },
+
+    /// Returns the voting power of the address at the given block height, or at the current one if
+    /// none is provided, including the weight delegated to it at the time. Compatible with the DAO
+    /// DAO voting module interface.
+    #[returns(VotingPowerAtHeightResponse)]
+    VotingPowerAtHeight {
+        address: String,
+        height: Option<u64>,
+    },
+
+    /// Returns the total voting power at the given block height, or at the current one if none is
+    /// provided. Compatible with the DAO DAO voting module interface.
+    #[returns(TotalPowerAtHeightResponse)]
+    TotalPowerAtHeight { height: Option<u64> },
+
+    /// Returns the contract name and version. Compatible with the DAO DAO voting module interface.
+    #[returns(InfoResponse)]
+    Info {},
 }
 
 #[cw_serde]
@@ -131,6 +389,25 @@ pub struct UnbondingResponse {
     pub unbonding_requests: Vec<Bond>,
 }
 
+/// Response for the Locks query
+#[cw_serde]
+pub struct LocksResponse {
+    pub total_locked: Uint128,
+    pub locks: Vec<LockedBond>,
+}
+
+/// Response for the Delegation query
+#[cw_serde]
+pub struct DelegationResponse {
+    pub delegation: Option<Delegation>,
+}
+
+/// Response for the Delegations query
+#[cw_serde]
+pub struct DelegationsResponse {
+    pub delegations: Vec<Delegation>,
+}
+
 /// Response for the Withdrawable query
 #[cw_serde]
 pub struct WithdrawableResponse {
@@ -142,7 +419,74 @@ pub struct WithdrawableResponse {
 pub struct BondingWeightResponse {
     pub address: String,
     pub weight: Uint128,
+    /// The part of the weight delegated to the address by other bonders.
+    pub delegated_weight: Uint128,
     pub global_weight: Uint128,
     pub share: Decimal,
     pub timestamp: Timestamp,
 }
+
+/// Response for the AutoCompound query.
+#[cw_serde]
+pub struct AutoCompoundResponse {
+    pub enabled: bool,
+}
+
+/// Response for the TotalWeightAt query.
+#[cw_serde]
+pub struct TotalWeightResponse {
+    pub weight: Uint128,
+    pub timestamp: Timestamp,
+}
+
+/// Response for the TotalBondedByDenom query.
+#[cw_serde]
+pub struct TotalBondedByDenomResponse {
+    /// The amount bonded of each bonding asset.
+    pub bonded: Vec<BondedDenom>,
+    /// The value of all the bonded assets in the underlying token.
+    pub total_underlying_amount: Uint128,
+}
+
+/// The total amount bonded of a bonding asset.
+#[cw_serde]
+pub struct BondedDenom {
+    pub asset: Asset,
+    pub exchange_rate: Decimal,
+    /// The value of the bonded amount in the underlying token.
+    pub underlying_amount: Uint128,
+}
+
+/// The query the liquid staking contracts used as exchange rate oracles need to support, as the
+/// Eris Protocol and Backbone hubs do.
+#[cw_serde]
+pub enum ExchangeRateOracleQueryMsg {
+    State {},
+}
+
+/// The part of the state of a liquid staking contract the exchange rate is read from. Doesn't deny
+/// unknown fields, so the full state response of the hub can be parsed into it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExchangeRateOracleResponse {
+    pub exchange_rate: Decimal,
+}
+
+/// Response for the VotingPowerAtHeight query.
+#[cw_serde]
+pub struct VotingPowerAtHeightResponse {
+    pub power: Uint128,
+    pub height: u64,
+}
+
+/// Response for the TotalPowerAtHeight query.
+#[cw_serde]
+pub struct TotalPowerAtHeightResponse {
+    pub power: Uint128,
+    pub height: u64,
+}
+
+/// Response for the Info query.
+#[cw_serde]
+pub struct InfoResponse {
+    pub info: ContractVersion,
+}